@@ -0,0 +1,110 @@
+use std::collections::HashSet;
+use chrono::{NaiveDate, NaiveDateTime, Datelike, Weekday, Duration};
+use criterion::{criterion_group, criterion_main, Criterion, black_box};
+
+extern crate rs_workdays;
+
+use rs_workdays::{WorkCalendar, Closed};
+use rs_workdays::business_ordinal::Resolution;
+
+// 10年間(2016年から2025年)
+fn range_dates() -> (NaiveDate, NaiveDate) {
+    (NaiveDate::from_ymd(2016, 1, 1), NaiveDate::from_ymd(2025, 12, 31))
+}
+
+/// 索引を用いない従来の営業日の判定(祝日のHashSetを毎回作成する)
+fn legacy_check_workday(holidays: &[NaiveDate], holiday_weekdays: &HashSet<Weekday>, select_date: NaiveDate) -> bool {
+    let holidays_set: HashSet<NaiveDate> = holidays.iter().cloned().collect();
+    !holidays_set.contains(&select_date) & !holiday_weekdays.contains(&select_date.weekday())
+}
+
+/// 索引を用いない従来の営業日の取得(HashSetの差集合による)
+fn legacy_get_workdays(holidays: &[NaiveDate], holiday_weekdays: &HashSet<Weekday>, start_date: NaiveDate, end_date: NaiveDate) -> Vec<NaiveDate> {
+    let all_day_set: HashSet<NaiveDate> = start_date.iter_days()
+    .take_while(|x| {x<=&end_date}).collect();
+    let holiday_weekdays_set: HashSet<NaiveDate> = start_date.iter_days()
+    .take_while(|x| {x<=&end_date}).filter(|x|{holiday_weekdays.contains(&x.weekday())}).collect();
+    let holidays_set: HashSet<NaiveDate> = holidays.iter().cloned().collect();
+    let all_holidays_set: HashSet<NaiveDate> = holidays_set.union(&holiday_weekdays_set).cloned().collect();
+
+    let mut workdays_vec: Vec<NaiveDate> = all_day_set.difference(&all_holidays_set).cloned().collect();
+    workdays_vec.sort();
+    workdays_vec
+}
+
+fn bench_check_workday(c: &mut Criterion) {
+    let calendar = WorkCalendar::default();
+    let holidays = calendar.range_holidays().to_vec();
+    let holiday_weekdays = calendar.holiday_weekdays().clone();
+    let (start_date, end_date) = range_dates();
+    let all_days: Vec<NaiveDate> = start_date.iter_days().take_while(|x|{x<=&end_date}).collect();
+
+    let mut group = c.benchmark_group("check_workday_10_years");
+    group.bench_function("legacy", |b|{
+        b.iter(||{all_days.iter().filter(|x|{legacy_check_workday(&holidays, &holiday_weekdays, **x)}).count()})
+    });
+    group.bench_function("index", |b|{
+        b.iter(||{all_days.iter().filter(|x|{calendar.check_workday(**x)}).count()})
+    });
+    group.finish();
+}
+
+fn bench_workdays(c: &mut Criterion) {
+    let calendar = WorkCalendar::default();
+    let holidays = calendar.range_holidays().to_vec();
+    let holiday_weekdays = calendar.holiday_weekdays().clone();
+    let (start_date, end_date) = range_dates();
+
+    let mut group = c.benchmark_group("get_workdays_10_years");
+    group.bench_function("legacy", |b|{
+        b.iter(||{legacy_get_workdays(&holidays, &holiday_weekdays, black_box(start_date), black_box(end_date))})
+    });
+    group.bench_function("index", |b|{
+        b.iter(||{calendar.get_workdays(black_box(start_date), black_box(end_date), Closed::Both)})
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("count_workdays_10_years");
+    group.bench_function("legacy", |b|{
+        b.iter(||{legacy_get_workdays(&holidays, &holiday_weekdays, black_box(start_date), black_box(end_date)).len()})
+    });
+    group.bench_function("index", |b|{
+        b.iter(||{calendar.count_workdays(black_box(start_date), black_box(end_date), Closed::Both)})
+    });
+    group.finish();
+}
+
+fn bench_extract(c: &mut Criterion) {
+    let calendar = WorkCalendar::default();
+    let (start_date, end_date) = range_dates();
+    let start_datetime = start_date.and_hms(0, 0, 0);
+    let minutes = (end_date - start_date).num_minutes();
+    let datetime_vec: Vec<NaiveDateTime> = (0..minutes).step_by(5).map(|i|{start_datetime + Duration::minutes(i)}).collect();
+
+    c.bench_function("extract_workdays_bool_10_years", |b|{
+        b.iter(||{calendar.extract_workdays_bool(black_box(&datetime_vec))})
+    });
+}
+
+fn bench_business_ordinal(c: &mut Criterion) {
+    let calendar = WorkCalendar::default();
+    let (start_date, end_date) = range_dates();
+    let start_datetime = start_date.and_hms(9, 0, 0);
+    let end_datetime = end_date.and_hms(15, 0, 0);
+    let end_ordinal = calendar.to_business_ordinal(end_datetime, Resolution::Second);
+
+    let mut group = c.benchmark_group("business_ordinal_10_years");
+    group.bench_function("timedelta", |b|{
+        b.iter(||{calendar.get_timedelta_workdays_intraday(black_box(start_datetime), black_box(end_datetime))})
+    });
+    group.bench_function("to_business_ordinal", |b|{
+        b.iter(||{calendar.to_business_ordinal(black_box(end_datetime), Resolution::Second)})
+    });
+    group.bench_function("from_business_ordinal", |b|{
+        b.iter(||{calendar.from_business_ordinal(black_box(end_ordinal), Resolution::Second)})
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_check_workday, bench_workdays, bench_extract, bench_business_ordinal);
+criterion_main!(benches);
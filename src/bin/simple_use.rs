@@ -6,7 +6,7 @@ extern crate rs_workdays;
 use rs_workdays::workdays::{get_workdays, check_workday, get_next_workday, get_previous_workday, Closed};
 use rs_workdays::workdays::{get_near_workday, get_next_workdays_number, get_previous_workdays_number, get_workdays_number};
 
-use rs_workdays::intraday::{check_workday_intraday, get_next_border_workday_intraday, get_previous_border_workday_intraday, get_near_workday_intraday};
+use rs_workdays::intraday::{check_workday_intraday, get_next_border, get_previous_border, get_near_border};
 use rs_workdays::intraday::{add_workday_intraday_datetime, get_timedelta_workdays_intraday};
 use rs_workdays::extract::{extract_workdays_bool, extract_intraday_bool, extract_workdays_intraday_bool};
 
@@ -72,36 +72,36 @@ fn main() {
     let is_workday_intraday = check_workday_intraday(select_datetime);
     println!("{:?} is workday and intraday: {:?}", select_datetime, is_workday_intraday);
 
-    // get_next_border_workday_intraday
+    // get_next_border
     let select_datetime = NaiveDate::from_ymd(2021,1,1).and_hms(0,0,0);
-    let (next_border_datetime, border_symbol) = get_next_border_workday_intraday(select_datetime);
-    println!("next_border_datetime: {:?}, border_symbol: {:?}", next_border_datetime, border_symbol);
+    let (next_border_datetime, border_kind) = get_next_border(select_datetime);
+    println!("next_border_datetime: {:?}, border_kind: {:?}", next_border_datetime, border_kind);
 
-    // get_previous_border_workday_intraday
+    // get_previous_border
     let select_datetime = NaiveDate::from_ymd(2021,1,1).and_hms(0,0,0);
-    let (previous_border_datetime, border_symbol) = get_previous_border_workday_intraday(select_datetime, false);
-    println!("previous_border_datetime: {:?}, border_symbol: {:?}", previous_border_datetime, border_symbol);
+    let (previous_border_datetime, border_kind) = get_previous_border(select_datetime, false);
+    println!("previous_border_datetime: {:?}, border_kind: {:?}", previous_border_datetime, border_kind);
 
     let select_datetime = NaiveDate::from_ymd(2021,1,4).and_hms(15,0,0);
-    let (previous_border_datetime, border_symbol) = get_previous_border_workday_intraday(select_datetime, false);
-    println!("previous_border_datetime: {:?}, border_symbol: {:?}", previous_border_datetime, border_symbol);
+    let (previous_border_datetime, border_kind) = get_previous_border(select_datetime, false);
+    println!("previous_border_datetime: {:?}, border_kind: {:?}", previous_border_datetime, border_kind);
 
     let select_datetime = NaiveDate::from_ymd(2021,1,4).and_hms(15,0,0);
-    let (previous_border_datetime, border_symbol) = get_previous_border_workday_intraday(select_datetime, true);
-    println!("previous_border_datetime: {:?}, border_symbol: {:?}", previous_border_datetime, border_symbol);
+    let (previous_border_datetime, border_kind) = get_previous_border(select_datetime, true);
+    println!("previous_border_datetime: {:?}, border_kind: {:?}", previous_border_datetime, border_kind);
 
     // get_near_workday_intraday
     let select_datetime = NaiveDate::from_ymd(2021,1,1).and_hms(0,0,0);
-    let (near_workday_intraday_datetime, border_symbol) = get_near_workday_intraday(select_datetime, true);
-    println!("near_workday_intraday_datetime: {:?}, border_symbol: {:?}", near_workday_intraday_datetime, border_symbol);
+    let (near_workday_intraday_datetime, border_kind) = get_near_border(select_datetime, true);
+    println!("near_workday_intraday_datetime: {:?}, border_kind: {:?}", near_workday_intraday_datetime, border_kind);
 
     let select_datetime = NaiveDate::from_ymd(2021,1,4).and_hms(10,0,0);
-    let (near_workday_intraday_datetime, border_symbol) = get_near_workday_intraday(select_datetime, true);
-    println!("near_workday_intraday_datetime: {:?}, border_symbol: {:?}", near_workday_intraday_datetime, border_symbol);  
+    let (near_workday_intraday_datetime, border_kind) = get_near_border(select_datetime, true);
+    println!("near_workday_intraday_datetime: {:?}, border_kind: {:?}", near_workday_intraday_datetime, border_kind);  
 
     let select_datetime = NaiveDate::from_ymd(2021,1,1).and_hms(0,0,0);
-    let (near_workday_intraday_datetime, border_symbol) = get_near_workday_intraday(select_datetime, false);
-    println!("near_workday_intraday_datetime: {:?}, border_symbol: {:?}", near_workday_intraday_datetime, border_symbol);  
+    let (near_workday_intraday_datetime, border_kind) = get_near_border(select_datetime, false);
+    println!("near_workday_intraday_datetime: {:?}, border_kind: {:?}", near_workday_intraday_datetime, border_kind);  
 
     // add_workday_intraday_datetime
     let select_datetime = NaiveDate::from_ymd(2021,1,1).and_hms(0,0,0);
@@ -0,0 +1,100 @@
+use chrono::{NaiveDate, Datelike, Duration};
+
+/// 営業時間の累積の索引．
+/// 祝日・営業時間の指定のある日の範囲について，各日に開始する営業時間の累積(ミリ秒)をもつ．
+/// 範囲外の日は曜日ごとの営業時間が毎週繰り返すものとして計算する．
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BusinessTimeIndex {
+    /// 索引の開始日
+    start_date: NaiveDate,
+    /// 開始日から各日の前日までに開始した営業時間の合計(長さは日数+1)
+    cumulative_millis: Vec<i64>,
+    /// 範囲外の曜日ごとの一日の営業時間(月曜日を0とする)
+    weekday_millis: [i64; 7]
+}
+
+impl BusinessTimeIndex {
+    /// 各日の営業時間と範囲外の曜日ごとの営業時間から索引を作成
+    /// Argments
+    /// - start_date: 索引の開始日
+    /// - day_millis: 開始日から各日に開始する営業時間(ミリ秒)
+    /// - weekday_millis: 範囲外の曜日ごとの一日の営業時間(ミリ秒，月曜日を0とする)
+    pub(crate) fn new(start_date: NaiveDate, day_millis: &[i64], weekday_millis: [i64; 7]) -> Self {
+        let mut cumulative_millis: Vec<i64> = Vec::with_capacity(day_millis.len() + 1);
+        cumulative_millis.push(0);
+        for one_millis in day_millis.iter() {
+            cumulative_millis.push(cumulative_millis.last().unwrap() + one_millis);
+        }
+        BusinessTimeIndex {start_date, cumulative_millis, weekday_millis}
+    }
+
+    /// 索引の終了日(含まない)
+    fn end_date(&self) -> NaiveDate {
+        self.start_date + Duration::days(self.cumulative_millis.len() as i64 - 1)
+    }
+
+    /// 一週間の営業時間
+    fn week_millis(&self) -> i64 {
+        self.weekday_millis.iter().sum()
+    }
+
+    /// 範囲外のstart_date(含む)からend_date(含まない)までの営業時間
+    fn periodic_millis(&self, start_date: NaiveDate, end_date: NaiveDate) -> i64 {
+        let all_days = (end_date - start_date).num_days();
+        let start_weekday_number = start_date.weekday().num_days_from_monday() as i64;
+        let remainder_millis: i64 = (0..(all_days % 7)).map(|i|{self.weekday_millis[((start_weekday_number + i) % 7) as usize]}).sum();
+        (all_days / 7) * self.week_millis() + remainder_millis
+    }
+
+    /// 索引の開始日からselect_dateの前日までに開始した営業時間の合計．
+    /// select_dateが開始日より前の場合は負の値となる．
+    /// Argment
+    /// - select_date: 指定する日
+    pub(crate) fn millis_before(&self, select_date: NaiveDate) -> i64 {
+        let end_date = self.end_date();
+        if select_date < self.start_date {
+            return -self.periodic_millis(select_date, self.start_date);
+        }
+        if select_date > end_date {
+            return self.cumulative_millis.last().unwrap() + self.periodic_millis(end_date, select_date);
+        }
+        self.cumulative_millis[(select_date - self.start_date).num_days() as usize]
+    }
+
+    /// その日までの営業時間の合計がtarget_millisを超える(is_inclusiveの場合は以上となる)最初の日．
+    /// 営業時間が無く見つからない場合・日付の範囲外となる場合はNone
+    /// Argments
+    /// - target_millis: 索引の開始日からの営業時間
+    /// - is_inclusive: 以上とするかどうか
+    pub(crate) fn first_day_reaching(&self, target_millis: i64, is_inclusive: bool) -> Option<NaiveDate> {
+        let is_reached = |one_date: NaiveDate|{
+            let reached_millis = self.millis_before(one_date.succ_opt()?);
+            Some(if is_inclusive {reached_millis >= target_millis} else {reached_millis > target_millis})
+        };
+        let end_date = self.end_date();
+        let week_millis = self.week_millis();
+
+        let mut one_date = if !is_reached(end_date)? {  // 索引の範囲より後
+            if week_millis == 0 {
+                return None;
+            }
+            let weeks = ((target_millis - self.cumulative_millis.last().unwrap()) / week_millis - 2).max(0);
+            end_date.checked_add_signed(Duration::weeks(weeks))?
+        } else if is_reached(self.start_date.pred_opt()?)? {  // 索引の範囲より前
+            if week_millis == 0 {
+                return None;
+            }
+            let weeks = (-target_millis) / week_millis + 2;
+            self.start_date.checked_sub_signed(Duration::weeks(weeks))?
+        } else {  // 索引の範囲内
+            let day_count = self.cumulative_millis[1..].partition_point(|reached_millis|{
+                if is_inclusive {*reached_millis < target_millis} else {*reached_millis <= target_millis}
+            });
+            return Some(self.start_date + Duration::days(day_count as i64));
+        };
+        while !is_reached(one_date)? {
+            one_date = one_date.succ_opt()?;
+        }
+        Some(one_date)
+    }
+}
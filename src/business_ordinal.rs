@@ -0,0 +1,234 @@
+use chrono::{NaiveDate, NaiveDateTime, Datelike, Duration};
+
+use crate::calendar::{WorkCalendar, SnapPolicy, CoveragePolicy};
+use crate::business_index::BusinessTimeIndex;
+use crate::error::Error;
+use crate::global::{read_work_calendar, try_read_work_calendar};
+
+/// 営業時間の序数の単位
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Resolution {
+    /// ミリ秒
+    Millisecond,
+    /// 秒
+    Second,
+    /// 分
+    Minute
+}
+
+impl Resolution {
+    /// 一単位のミリ秒
+    fn millis(&self) -> i64 {
+        match self {
+            Resolution::Millisecond => 1,
+            Resolution::Second => 1_000,
+            Resolution::Minute => 60_000
+        }
+    }
+}
+
+/// 営業時間の序数の基準日時(1970年1月1日0時)
+fn business_epoch() -> NaiveDateTime {
+    NaiveDate::from_ymd(1970, 1, 1).and_hms(0, 0, 0)
+}
+
+impl WorkCalendar {
+    /// 祝日・休日曜日・営業時間の変更に合わせて営業時間の累積の索引を作り直す．
+    /// 索引の範囲は祝日と日付・適用期間の指定のある日を一週間越えるようにとる
+    pub(crate) fn sync_business_index(&mut self) {
+        let mut first_date = self.holidays.first().cloned();
+        let mut last_date = self.holidays.last().cloned();
+        if let Some((first_boundary, last_boundary)) = self.intraday_schedule.finite_boundary_dates() {
+            first_date = Some(first_date.map_or(first_boundary, |x|{x.min(first_boundary)}));
+            last_date = Some(last_date.map_or(last_boundary, |x|{x.max(last_boundary)}));
+        }
+        let epoch_date = business_epoch().date();
+        let start_date = first_date.unwrap_or(epoch_date).checked_sub_signed(Duration::weeks(1)).unwrap_or(first_date.unwrap_or(epoch_date));
+        let end_date = last_date.unwrap_or(epoch_date).checked_add_signed(Duration::weeks(1)).unwrap_or(last_date.unwrap_or(epoch_date));
+
+        let one_day_millis = |one_date: NaiveDate|{
+            self.sessions_on_date(one_date, true).map(|session|{(session.end - session.start).num_milliseconds()}).sum::<i64>()
+        };
+        let day_millis: Vec<i64> = start_date.iter_days().take_while(|x|{x < &end_date}).map(one_day_millis).collect();
+
+        // 範囲外は曜日のみで決まるため，範囲の直後(日付の限界の場合は直前)の一週間から求める
+        let mut weekday_millis = [0_i64; 7];
+        let week_start_date = if end_date.checked_add_signed(Duration::weeks(1)).is_some() {end_date} else {start_date - Duration::weeks(1)};
+        for one_date in week_start_date.iter_days().take(7) {
+            weekday_millis[one_date.weekday().num_days_from_monday() as usize] = one_day_millis(one_date);
+        }
+        self.business_index = BusinessTimeIndex::new(start_date, &day_millis, weekday_millis);
+    }
+
+    /// 索引の開始日からselect_datetimeまでの営業時間(ミリ秒)
+    pub(crate) fn business_millis_before(&self, select_datetime: NaiveDateTime) -> i64 {
+        let select_date = select_datetime.date();
+        let mut all_millis = self.business_index.millis_before(select_date);
+        for session in select_date.pred_opt().into_iter().flat_map(|one_date|{self.sessions_on_date(one_date, true)}) {
+            if session.end > select_datetime {  // 前日に開始した営業時間のうちselect_datetime以降の分
+                all_millis -= (session.end - session.start.max(select_datetime)).num_milliseconds();
+            }
+        }
+        for session in self.sessions_on_date(select_date, true) {
+            if session.start < select_datetime {
+                all_millis += (session.end.min(select_datetime) - session.start).num_milliseconds();
+            }
+        }
+        all_millis
+    }
+
+    /// 日時をcoverage_policyに従って営業時間の序数に変換する
+    fn _to_business_ordinal(&self, select_datetime: NaiveDateTime, resolution: Resolution, coverage_policy: CoveragePolicy) -> Result<i64, Error> {
+        self.check_intraday_borders()?;
+        self._check_coverage(select_datetime.date(), coverage_policy)?;
+        if (self.snap_policy == SnapPolicy::Error) & self.session_containing(select_datetime, true).is_none() {
+            return Err(Error::OutsideSession{datetime_str: select_datetime.to_string()});
+        }
+        let business_millis = self.business_millis_before(select_datetime) - self.business_millis_before(business_epoch());
+        Ok(business_millis.div_euclid(resolution.millis()))
+    }
+
+    /// 日時を営業時間の序数(基準日時の1970年1月1日0時からの営業時間)に変換する．
+    /// 営業時間外の日時は方針(`SnapPolicy`)に従い，NextとPreviousでは前後の境界の序数となる
+    /// Argments
+    /// - select_datetime: 指定する日時
+    /// - resolution: 序数の単位(端数は切り捨て)
+    ///
+    /// Return
+    /// 営業時間の序数
+    ///
+    /// 営業時間の境界が空の場合・方針がErrorで営業時間外の場合・方針により祝日データの範囲外となる場合はエラー
+    pub fn try_to_business_ordinal(&self, select_datetime: NaiveDateTime, resolution: Resolution) -> Result<i64, Error> {
+        self._to_business_ordinal(select_datetime, resolution, self.coverage_policy)
+    }
+
+    /// 日時を営業時間の序数(基準日時の1970年1月1日0時からの営業時間)に変換する．
+    /// 営業時間外の日時は方針(`SnapPolicy`)に従い，NextとPreviousでは前後の境界の序数となる
+    /// Argments
+    /// - select_datetime: 指定する日時
+    /// - resolution: 序数の単位(端数は切り捨て)
+    ///
+    /// Return
+    /// 営業時間の序数
+    pub fn to_business_ordinal(&self, select_datetime: NaiveDateTime, resolution: Resolution) -> i64 {
+        self._to_business_ordinal(select_datetime, resolution, CoveragePolicy::Ignore).unwrap()
+    }
+
+    /// 営業時間の序数をcoverage_policyに従って日時に変換する
+    fn _from_business_ordinal(&self, business_ordinal: i64, resolution: Resolution, coverage_policy: CoveragePolicy) -> Result<NaiveDateTime, Error> {
+        self.check_intraday_borders()?;
+        let out_of_range_error = ||{Error::OutOfRange{date_str: format!("business ordinal {}", business_ordinal)}};
+        let target_millis = business_ordinal.checked_mul(resolution.millis())
+            .and_then(|x|{x.checked_add(self.business_millis_before(business_epoch()))})
+            .ok_or_else(out_of_range_error)?;
+
+        let is_previous = self.snap_policy == SnapPolicy::Previous;
+        let out_date = self.business_index.first_day_reaching(target_millis, is_previous).ok_or_else(out_of_range_error)?;
+        let mut remaining_millis = target_millis - self.business_index.millis_before(out_date);
+        for session in self.sessions_on_date(out_date, true) {
+            let session_millis = (session.end - session.start).num_milliseconds();
+            if (remaining_millis < session_millis) | (is_previous & (remaining_millis == session_millis)) {
+                let out_datetime = session.start + Duration::milliseconds(remaining_millis);
+                self._check_coverage(out_datetime.date(), coverage_policy)?;
+                return Ok(out_datetime);
+            }
+            remaining_millis -= session_millis;
+        }
+        Err(out_of_range_error())
+    }
+
+    /// 営業時間の序数を日時に変換する．
+    /// 営業時間の境界にあたる序数は方針(`SnapPolicy`)がPreviousの場合は前の営業時間の終了，それ以外は次の営業時間の開始とする
+    /// Argments
+    /// - business_ordinal: 営業時間の序数
+    /// - resolution: 序数の単位
+    ///
+    /// Return
+    /// 日時
+    ///
+    /// 営業時間の境界が空の場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラー
+    pub fn try_from_business_ordinal(&self, business_ordinal: i64, resolution: Resolution) -> Result<NaiveDateTime, Error> {
+        self._from_business_ordinal(business_ordinal, resolution, self.coverage_policy)
+    }
+
+    /// 営業時間の序数を日時に変換する．
+    /// 営業時間の境界にあたる序数は方針(`SnapPolicy`)がPreviousの場合は前の営業時間の終了，それ以外は次の営業時間の開始とする
+    /// Argments
+    /// - business_ordinal: 営業時間の序数
+    /// - resolution: 序数の単位
+    ///
+    /// Return
+    /// 日時
+    pub fn from_business_ordinal(&self, business_ordinal: i64, resolution: Resolution) -> NaiveDateTime {
+        self._from_business_ordinal(business_ordinal, resolution, CoveragePolicy::Ignore).unwrap()
+    }
+}
+
+
+/// 日時を営業時間の序数(基準日時の1970年1月1日0時からの営業時間)に変換する．
+/// 営業時間外の日時は方針(`SnapPolicy`)に従い，NextとPreviousでは前後の境界の序数となる
+/// Argments
+/// - select_datetime: 指定する日時
+/// - resolution: 序数の単位(端数は切り捨て)
+///
+/// Return
+/// 営業時間の序数
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::business_ordinal::*;
+/// let morning_ordinal = to_business_ordinal(NaiveDate::from_ymd(2021,1,4).and_hms(11,0,0), Resolution::Minute);
+/// let afternoon_ordinal = to_business_ordinal(NaiveDate::from_ymd(2021,1,4).and_hms(13,0,0), Resolution::Minute);
+/// assert_eq!(afternoon_ordinal - morning_ordinal, 60);
+/// // 営業時間外は次の営業時間の開始と同じ序数
+/// let holiday_ordinal = to_business_ordinal(NaiveDate::from_ymd(2021,1,2).and_hms(10,0,0), Resolution::Minute);
+/// assert_eq!(holiday_ordinal, to_business_ordinal(NaiveDate::from_ymd(2021,1,4).and_hms(9,0,0), Resolution::Minute));
+/// ~~~~
+pub fn to_business_ordinal(select_datetime: NaiveDateTime, resolution: Resolution) -> i64 {
+    read_work_calendar().to_business_ordinal(select_datetime, resolution)
+}
+
+/// 日時を営業時間の序数に変換する．営業時間の境界が空の場合・方針がErrorで営業時間外の場合・方針により祝日データの範囲外となる場合はエラーを返す
+/// Argments
+/// - select_datetime: 指定する日時
+/// - resolution: 序数の単位(端数は切り捨て)
+///
+/// Return
+/// 営業時間の序数．デフォルトのカレンダーのロックがポイズンされている場合もエラー
+pub fn try_to_business_ordinal(select_datetime: NaiveDateTime, resolution: Resolution) -> Result<i64, Error> {
+    try_read_work_calendar()?.try_to_business_ordinal(select_datetime, resolution)
+}
+
+/// 営業時間の序数を日時に変換する．
+/// 営業時間の境界にあたる序数は方針(`SnapPolicy`)がPreviousの場合は前の営業時間の終了，それ以外は次の営業時間の開始とする
+/// Argments
+/// - business_ordinal: 営業時間の序数
+/// - resolution: 序数の単位
+///
+/// Return
+/// 日時
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::business_ordinal::*;
+/// let select_datetime = NaiveDate::from_ymd(2021,1,4).and_hms(10,15,0);
+/// let business_ordinal = to_business_ordinal(select_datetime, Resolution::Second);
+/// assert_eq!(from_business_ordinal(business_ordinal, Resolution::Second), select_datetime);
+/// assert_eq!(from_business_ordinal(business_ordinal + 75 * 60, Resolution::Second), NaiveDate::from_ymd(2021,1,4).and_hms(12,30,0));
+/// ~~~~
+pub fn from_business_ordinal(business_ordinal: i64, resolution: Resolution) -> NaiveDateTime {
+    read_work_calendar().from_business_ordinal(business_ordinal, resolution)
+}
+
+/// 営業時間の序数を日時に変換する．営業時間の境界が空の場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラーを返す
+/// Argments
+/// - business_ordinal: 営業時間の序数
+/// - resolution: 序数の単位
+///
+/// Return
+/// 日時．デフォルトのカレンダーのロックがポイズンされている場合もエラー
+pub fn try_from_business_ordinal(business_ordinal: i64, resolution: Resolution) -> Result<NaiveDateTime, Error> {
+    try_read_work_calendar()?.try_from_business_ordinal(business_ordinal, resolution)
+}
@@ -0,0 +1,598 @@
+use std::path::Path;
+use std::collections::{HashSet, BTreeMap};
+use std::sync::Arc;
+use chrono::{NaiveDate, Datelike, Weekday, NaiveTime, Duration};
+
+#[cfg(feature = "source")]
+use anyhow::Context;
+
+use crate::error::Error;
+use crate::holiday_info::HolidayInfo;
+use crate::workday_index::WorkdayIndex;
+use crate::business_index::BusinessTimeIndex;
+use crate::intraday_schedule::IntradaySchedule;
+#[cfg(feature = "source")]
+use crate::holiday_info::make_holiday_infos;
+
+/// 営業時間の境界．終了時間が開始時間以前の場合は翌日に終了する(夜間取引など日付をまたぐ営業時間)
+/// Fields
+/// - start: 開始時間
+/// - end: 終了時間
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TimeBorder {
+    pub start: NaiveTime,
+    pub end: NaiveTime
+}
+
+impl TimeBorder {
+    /// 日付をまたぐ(翌日に終了する)かどうか
+    pub fn is_overnight(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// 営業時間の長さ
+    pub fn duration(&self) -> Duration {
+        if self.is_overnight() {
+            self.end - self.start + Duration::days(1)
+        } else {
+            self.end - self.start
+        }
+    }
+}
+
+/// 取引セッションの種類
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SessionKind {
+    /// 注文を受け付けるが約定しないプレオープン
+    PreOpen,
+    /// ザラバ(連続取引)
+    Continuous,
+    /// 引けのクロージング・オークション
+    ClosingAuction,
+    /// その他
+    Other
+}
+
+/// 名前付きの取引セッション．終了時間が開始時間以前の場合は翌日に終了する
+/// Fields
+/// - name: 名前(前場・後場など)
+/// - start: 開始時間
+/// - end: 終了時間
+/// - kind: セッションの種類
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Session {
+    pub name: String,
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+    pub kind: SessionKind
+}
+
+impl Session {
+    /// 名前・開始時間・終了時間・種類からセッションを作成
+    pub fn new(name: &str, start: NaiveTime, end: NaiveTime, kind: SessionKind) -> Self {
+        Session {name: name.to_string(), start, end, kind}
+    }
+
+    /// セッションの時間の境界
+    pub fn border(&self) -> TimeBorder {
+        TimeBorder {start: self.start, end: self.end}
+    }
+}
+
+/// 日付をまたぐ営業時間の属する取引日の規則
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SessionAttribution {
+    /// 営業時間の開始日に属する
+    StartDate,
+    /// 日付をまたぐ営業時間(夜間取引)は開始日の次の営業日に属する
+    NextWorkday
+}
+
+/// 営業時間外の日時と営業時間の序数の対応の方針
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SnapPolicy {
+    /// 営業時間外の日時は次の営業時間の開始とし，境界の序数は次の営業時間の開始に戻す
+    Next,
+    /// 営業時間外の日時は前の営業時間の終了とし，境界の序数は前の営業時間の終了に戻す
+    Previous,
+    /// 営業時間外の日時はエラーとする(境界の序数は次の営業時間の開始に戻す)
+    Error
+}
+
+/// 祝日データが網羅する年の範囲(両端を含む)
+/// Fields
+/// - start_year: 開始年(その年の1月1日から)
+/// - end_year: 終了年(その年の12月31日まで)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HolidayCoverage {
+    pub start_year: i32,
+    pub end_year: i32
+}
+
+impl HolidayCoverage {
+    /// select_dateが範囲内であるかどうか
+    /// Argment
+    /// - select_date: 指定する日
+    pub fn contains(&self, select_date: NaiveDate) -> bool {
+        (self.start_year <= select_date.year()) & (self.end_year >= select_date.year())
+    }
+
+    /// 二つの範囲を含む範囲
+    fn merge(&self, other: &HolidayCoverage) -> HolidayCoverage {
+        HolidayCoverage {
+            start_year: self.start_year.min(other.start_year),
+            end_year: self.end_year.max(other.end_year)
+        }
+    }
+}
+
+/// 祝日データの範囲外の日付を扱う場合の方針
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CoveragePolicy {
+    /// 何もしない
+    Ignore,
+    /// 警告のコールバック(`WorkCalendar::set_coverage_warning_handler`)を呼ぶ
+    Warn,
+    /// エラーを返す
+    Error
+}
+
+/// 祝日データの範囲外の日付を扱った場合に警告を受け取るコールバック
+#[derive(Clone)]
+pub(crate) struct CoverageWarningHandler(Arc<dyn Fn(&Error) + Send + Sync>);
+
+impl std::fmt::Debug for CoverageWarningHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CoverageWarningHandler")
+    }
+}
+
+impl PartialEq for CoverageWarningHandler {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for CoverageWarningHandler {}
+
+/// csvを読み込んで祝日のVecにする．2列目があれば祝日の名前とする．
+/// Argment
+/// - path_str: csvファイルのパス
+#[cfg(feature = "source")]
+pub(crate) fn read_csv<P:AsRef<Path>>(source_path: P) -> Result<Vec<HolidayInfo>, Error> {
+    let source_path: &Path = source_path.as_ref();
+    let source_path_str = source_path.to_str().context("cannot convert source path to string")?;
+
+    let parse_from_str = NaiveDate::parse_from_str;
+    let mut holidays_with_names: Vec<(NaiveDate, String)> = Vec::new();
+
+    let mut rdr = csv::ReaderBuilder::new().has_headers(false).flexible(true).from_path(source_path)
+        .map_err(|_|{Error::ReadCsvError{path_str: source_path_str.to_string()}})?;
+    for result in rdr.records() {
+        let record = result
+            .map_err(|_|{Error::ReadCsvError{path_str: source_path_str.to_string()}})?;
+        holidays_with_names.push((
+            parse_from_str(&record[0], "%Y-%m-%d")
+            .map_err(|_|{Error::ParseDateError{date_str: record[0].into()}})?,
+            record.get(1).unwrap_or("").to_string()
+        ));
+    }
+    Ok(make_holiday_infos(holidays_with_names))
+}
+
+#[cfg(not(feature = "source"))]
+pub(crate) fn read_csv<P:AsRef<Path>>(_: P) -> Result<Vec<HolidayInfo>, Error> {
+    Ok([].to_vec())
+}
+
+/// 祝日のうちstart_yearからend_yearまでのものを重複なしで日付順のマップにする．
+/// 同じ日付の祝日は先にあるものを優先する．
+fn filter_range_holidays<I: IntoIterator<Item=HolidayInfo>>(holiday_infos: I, start_year: i32, end_year: i32) -> BTreeMap<NaiveDate, HolidayInfo> {
+    let mut range_holidays_map: BTreeMap<NaiveDate, HolidayInfo> = BTreeMap::new();
+    for holiday_info in holiday_infos.into_iter().filter(|holiday_info| {
+        (start_year <= holiday_info.date.year()) & (end_year >= holiday_info.date.year())
+    }) {
+        range_holidays_map.entry(holiday_info.date).or_insert(holiday_info);
+    }
+    range_holidays_map
+}
+
+/// 祝日のマップのうちstart_yearからend_yearまでで実際に祝日が存在する年の範囲．祝日が無い場合はNone
+fn make_coverage(holiday_infos: &BTreeMap<NaiveDate, HolidayInfo>, start_year: i32, end_year: i32) -> Option<HolidayCoverage> {
+    match (holiday_infos.keys().next(), holiday_infos.keys().next_back()) {
+        (Some(first_holiday), Some(last_holiday)) => Some(HolidayCoverage {
+            start_year: start_year.max(first_holiday.year()),
+            end_year: end_year.min(last_holiday.year())
+        }),
+        _ => None
+    }
+}
+
+/// 祝日・休日曜日・営業時間の境界をまとめた営業日カレンダー．
+///
+/// 各営業日・営業時間の関数はメソッドとしても利用でき，グローバルな設定を共有せずに複数のカレンダーを同時に扱える．
+/// `workdays`・`intraday`・`extract`のフリー関数はグローバルなデフォルトカレンダーに対するラッパーである．
+///
+/// # Examples
+/// ~~~~
+/// use std::collections::HashSet;
+/// use chrono::{NaiveDate, NaiveTime, Weekday};
+/// use rs_workdays::{WorkCalendar, TimeBorder};
+///
+/// let holidays = vec![NaiveDate::from_ymd(2021, 1, 1), NaiveDate::from_ymd(2021, 1, 4)];
+/// let holiday_weekdays: HashSet<Weekday> = [Weekday::Sat, Weekday::Sun].iter().cloned().collect();
+/// let intraday_borders = vec![TimeBorder {start: NaiveTime::from_hms(9,0,0), end: NaiveTime::from_hms(17,0,0)}];
+/// let company_calendar = WorkCalendar::new(holidays, holiday_weekdays, intraday_borders);
+///
+/// assert!(!company_calendar.check_workday(NaiveDate::from_ymd(2021, 1, 4)));
+/// assert_eq!(company_calendar.get_next_workday(NaiveDate::from_ymd(2021, 1, 1), 1), NaiveDate::from_ymd(2021, 1, 5));
+/// ~~~~
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkCalendar {
+    pub(crate) holidays: Vec<NaiveDate>,
+    pub(crate) holiday_infos: BTreeMap<NaiveDate, HolidayInfo>,
+    pub(crate) holiday_weekdays: HashSet<Weekday>,
+    pub(crate) intraday_borders: Vec<TimeBorder>,
+    pub(crate) intraday_schedule: IntradaySchedule,
+    pub(crate) sessions: Vec<Session>,
+    pub(crate) workday_index: Arc<WorkdayIndex>,
+    pub(crate) business_index: BusinessTimeIndex,
+    pub(crate) snap_policy: SnapPolicy,
+    pub(crate) coverage: Option<HolidayCoverage>,
+    pub(crate) coverage_policy: CoveragePolicy,
+    pub(crate) coverage_warning_handler: Option<CoverageWarningHandler>,
+    pub(crate) session_attribution: SessionAttribution,
+    #[cfg(feature="tz")]
+    pub(crate) time_zone: chrono_tz::Tz
+}
+
+impl WorkCalendar {
+    /// 祝日・休日曜日・営業時間の境界からカレンダーを作成
+    /// Argments
+    /// - holidays: 祝日のベクター
+    /// - holiday_weekdays: 休日曜日のセット
+    /// - intraday_borders: 営業時間境界のベクター
+    pub fn new(holidays: Vec<NaiveDate>, holiday_weekdays: HashSet<Weekday>, intraday_borders: Vec<TimeBorder>) -> Self {
+        let mut intraday_borders = intraday_borders;
+        intraday_borders.sort();
+
+        let mut work_calendar = WorkCalendar {
+            holidays: Vec::new(),
+            holiday_infos: BTreeMap::new(),
+            holiday_weekdays,
+            intraday_borders,
+            intraday_schedule: IntradaySchedule::default(),
+            sessions: Vec::new(),
+            workday_index: Arc::new(WorkdayIndex::new(&[], &HashSet::new())),
+            business_index: BusinessTimeIndex::new(NaiveDate::from_ymd(1970, 1, 1), &[], [0; 7]),
+            snap_policy: SnapPolicy::Next,
+            coverage: None,
+            coverage_policy: CoveragePolicy::Ignore,
+            coverage_warning_handler: None,
+            session_attribution: SessionAttribution::StartDate,
+            #[cfg(feature="tz")]
+            time_zone: chrono_tz::Tz::Asia__Tokyo
+        };
+        work_calendar.replace_range_holidays(filter_range_holidays(holidays.into_iter().map(HolidayInfo::unnamed), i32::MIN, i32::MAX), i32::MIN, i32::MAX);
+        work_calendar
+    }
+
+    /// 祝日のマップと祝日データの範囲を更新し，祝日のベクターと営業日の索引を同期する
+    pub(crate) fn update_holidays(&mut self, holiday_infos: BTreeMap<NaiveDate, HolidayInfo>, coverage: Option<HolidayCoverage>) {
+        // 計算が終わってから代入する
+        let holidays: Vec<NaiveDate> = holiday_infos.keys().cloned().collect();
+        let workday_index = Arc::new(WorkdayIndex::new(&holidays, &self.holiday_weekdays));
+        self.holidays = holidays;
+        self.holiday_infos = holiday_infos;
+        self.workday_index = workday_index;
+        self.coverage = coverage;
+        self.sync_business_index();
+    }
+
+    /// 範囲を絞った祝日のマップで祝日を置き換える
+    fn replace_range_holidays(&mut self, holiday_infos: BTreeMap<NaiveDate, HolidayInfo>, start_year: i32, end_year: i32) {
+        let coverage = make_coverage(&holiday_infos, start_year, end_year);
+        self.update_holidays(holiday_infos, coverage);
+    }
+
+    /// csvを読み込んで利用できる祝日の更新をする
+    /// Argments
+    /// - path_str_vec: csvのパス
+    /// - start_year: 利用する開始年(その年の1月1日から)
+    /// - end_year: 利用する終了年(その年の12月31日まで)
+    pub fn set_holidays_csvs(&mut self, path_str_vec: &[String], start_year: i32, end_year: i32) -> Result<(), Error> {
+        let mut all_holiday_infos: Vec<HolidayInfo> = Vec::new();
+        for path_str in path_str_vec.iter() {
+            all_holiday_infos.extend(read_csv(path_str)?);
+        }
+
+        self.replace_range_holidays(filter_range_holidays(all_holiday_infos, start_year, end_year), start_year, end_year);
+        Ok(())
+    }
+
+    /// 祝日のvecから祝日の更新をする
+    /// Argments
+    /// - holidays_vec: 休日のベクター
+    /// - start_year: 利用する開始年(その年の1月1日から)
+    /// - end_year: 利用する終了年(その年の12月31日まで)
+    pub fn set_range_holidays(&mut self, holidays_vec: &[NaiveDate], start_year: i32, end_year: i32) {
+        let holiday_infos: Vec<HolidayInfo> = holidays_vec.iter().cloned().map(HolidayInfo::unnamed).collect();
+        self.set_range_holiday_infos(&holiday_infos, start_year, end_year);
+    }
+
+    /// 名前をもつ祝日のvecから祝日の更新をする
+    /// Argments
+    /// - holiday_infos: 休日の情報のベクター
+    /// - start_year: 利用する開始年(その年の1月1日から)
+    /// - end_year: 利用する終了年(その年の12月31日まで)
+    pub fn set_range_holiday_infos(&mut self, holiday_infos: &[HolidayInfo], start_year: i32, end_year: i32) {
+        self.replace_range_holidays(filter_range_holidays(holiday_infos.iter().cloned(), start_year, end_year), start_year, end_year);
+    }
+
+    /// 祝日のvecから祝日の追加をする
+    /// Argments
+    /// - holidays_vec: 休日のベクター
+    /// - start_year: 利用する開始年(その年の1月1日から)
+    /// - end_year: 利用する終了年(その年の12月31日まで)
+    pub fn add_range_holidays(&mut self, holidays_vec: &[NaiveDate], start_year: i32, end_year: i32) {
+        let holiday_infos: Vec<HolidayInfo> = holidays_vec.iter().cloned().map(HolidayInfo::unnamed).collect();
+        self.add_range_holiday_infos(&holiday_infos, start_year, end_year);
+    }
+
+    /// 名前をもつ祝日のvecから祝日の追加をする．既に登録されている日付は既存のものを優先する．
+    /// 祝日データの範囲は既存の範囲と追加した範囲を含むものとなる．
+    /// Argments
+    /// - holiday_infos: 休日の情報のベクター
+    /// - start_year: 利用する開始年(その年の1月1日から)
+    /// - end_year: 利用する終了年(その年の12月31日まで)
+    pub fn add_range_holiday_infos(&mut self, holiday_infos: &[HolidayInfo], start_year: i32, end_year: i32) {
+        let added_holiday_infos = filter_range_holidays(holiday_infos.iter().cloned(), start_year, end_year);
+        let coverage = match (self.coverage, make_coverage(&added_holiday_infos, start_year, end_year)) {
+            (Some(coverage), Some(added_coverage)) => Some(coverage.merge(&added_coverage)),
+            (coverage, added_coverage) => coverage.or(added_coverage)
+        };
+        let mut all_holiday_infos = self.holiday_infos.clone();  // 既存の祝日は範囲で絞らない
+        for (date, holiday_info) in added_holiday_infos.into_iter() {
+            all_holiday_infos.entry(date).or_insert(holiday_info);
+        }
+        self.update_holidays(all_holiday_infos, coverage);
+    }
+
+    /// 休日曜日の更新
+    /// Argment
+    /// - new_holiday_weekdays: 休日曜日のセット
+    pub fn set_holiday_weekdays(&mut self, new_holiday_weekdays: &HashSet<Weekday>) {
+        let workday_index = Arc::new(WorkdayIndex::new(&self.holidays, new_holiday_weekdays));
+        self.holiday_weekdays = new_holiday_weekdays.clone();
+        self.workday_index = workday_index;
+        self.sync_business_index();
+    }
+
+    /// 営業時間境界の更新
+    /// Argment
+    /// - new_intrada_borders: 営業時間境界のベクター
+    pub fn set_intraday_borders(&mut self, new_intraday_borders: &[TimeBorder]) {
+        let mut intraday_borders = new_intraday_borders.to_vec();
+        intraday_borders.sort();
+        self.intraday_borders = intraday_borders;
+        self.sync_business_index();
+    }
+
+    /// 曜日ごとの営業時間境界の更新．曜日を指定しない適用期間の指定より優先する
+    /// Argments
+    /// - weekday: 曜日
+    /// - new_intraday_borders: その曜日の営業時間境界のベクター(空の場合はその曜日の営業時間は無い)
+    pub fn set_weekday_intraday_borders(&mut self, weekday: Weekday, new_intraday_borders: &[TimeBorder]) {
+        self.intraday_schedule.set_weekday_borders(weekday, new_intraday_borders);
+        self.sync_business_index();
+    }
+
+    /// 日付ごとの営業時間境界の更新(大発会・大納会など)．曜日・適用期間の指定より優先する
+    /// Argments
+    /// - select_date: 日付
+    /// - new_intraday_borders: その日の営業時間境界のベクター(空の場合はその日の営業時間は無い)
+    pub fn set_date_intraday_borders(&mut self, select_date: NaiveDate, new_intraday_borders: &[TimeBorder]) {
+        self.intraday_schedule.set_date_borders(select_date, new_intraday_borders);
+        self.sync_business_index();
+    }
+
+    /// 適用期間の営業時間境界の追加(取引時間の変更など)．期間が重なる場合は後から追加したものを優先する
+    /// Argments
+    /// - start_date: 適用開始日(含む)
+    /// - end_date: 適用終了日(含む)
+    /// - new_intraday_borders: その期間の営業時間境界のベクター
+    pub fn add_range_intraday_borders(&mut self, start_date: NaiveDate, end_date: NaiveDate, new_intraday_borders: &[TimeBorder]) {
+        self.intraday_schedule.add_range_borders(start_date, end_date, new_intraday_borders);
+        self.sync_business_index();
+    }
+
+    /// 適用期間のうち指定した曜日の営業時間境界の追加(短縮取引の曜日の取引時間の変更など)．
+    /// 曜日の指定・曜日を指定しない適用期間の指定より優先し，期間が重なる場合は後から追加したものを優先する
+    /// Argments
+    /// - start_date: 適用開始日(含む)
+    /// - end_date: 適用終了日(含む)
+    /// - weekday: 曜日
+    /// - new_intraday_borders: その期間のその曜日の営業時間境界のベクター(空の場合はその曜日の営業時間は無い)
+    pub fn add_range_weekday_intraday_borders(&mut self, start_date: NaiveDate, end_date: NaiveDate, weekday: Weekday, new_intraday_borders: &[TimeBorder]) {
+        self.intraday_schedule.add_range_weekday_borders(start_date, end_date, weekday, new_intraday_borders);
+        self.sync_business_index();
+    }
+
+    /// 曜日・日付・適用期間ごとの営業時間境界の削除
+    pub fn clear_intraday_schedule(&mut self) {
+        self.intraday_schedule = IntradaySchedule::default();
+        self.sync_business_index();
+    }
+
+    /// 名前付きの取引セッションの更新．空の場合は各日の営業時間境界から連続取引のセッションを作る
+    /// Argment
+    /// - new_sessions: 取引セッションのスライス
+    pub fn set_sessions(&mut self, new_sessions: &[Session]) {
+        let mut sessions = new_sessions.to_vec();
+        sessions.sort_by_key(|session|{(session.start, session.end)});
+        self.sessions = sessions;
+    }
+
+    /// 日付をまたぐ営業時間の属する取引日の規則の更新
+    /// Argment
+    /// - new_session_attribution: 取引日の規則
+    pub fn set_session_attribution(&mut self, new_session_attribution: SessionAttribution) {
+        self.session_attribution = new_session_attribution;
+    }
+
+    /// 営業時間外の日時と営業時間の序数の対応の方針の更新
+    /// Argment
+    /// - new_snap_policy: 営業時間外の日時の方針
+    pub fn set_snap_policy(&mut self, new_snap_policy: SnapPolicy) {
+        self.snap_policy = new_snap_policy;
+    }
+
+    /// 祝日データの範囲外の日付を扱う場合の方針の更新
+    /// Argment
+    /// - new_coverage_policy: 範囲外の日付を扱う場合の方針
+    pub fn set_coverage_policy(&mut self, new_coverage_policy: CoveragePolicy) {
+        self.coverage_policy = new_coverage_policy;
+    }
+
+    /// 方針がWarnで祝日データの範囲外の日付を扱った場合に呼ばれるコールバックの更新
+    /// Argment
+    /// - handler: 範囲外であることを示すエラーを受け取るコールバック
+    pub fn set_coverage_warning_handler<F: Fn(&Error) + Send + Sync + 'static>(&mut self, handler: F) {
+        self.coverage_warning_handler = Some(CoverageWarningHandler(Arc::new(handler)));
+    }
+
+    /// 祝日データの取得
+    /// Return
+    /// - 祝日のスライス(ソート済み)
+    pub fn range_holidays(&self) -> &[NaiveDate] {
+        &self.holidays
+    }
+
+    /// 休日曜日データの取得
+    /// Return
+    /// - 休日曜日のset
+    pub fn holiday_weekdays(&self) -> &HashSet<Weekday> {
+        &self.holiday_weekdays
+    }
+
+    /// 営業時間境界の取得
+    /// Return
+    /// - 営業時間境界のスライス(ソート済み)
+    pub fn intraday_borders(&self) -> &[TimeBorder] {
+        &self.intraday_borders
+    }
+
+    /// select_dateの営業時間境界の取得．曜日・日付・適用期間の指定を考慮する
+    /// Argment
+    /// - select_date: 指定する日
+    ///
+    /// Return
+    /// - 営業時間境界のスライス(ソート済み)
+    pub fn intraday_borders_on(&self, select_date: NaiveDate) -> &[TimeBorder] {
+        self.intraday_schedule.borders_on(select_date).unwrap_or(&self.intraday_borders)
+    }
+
+    /// 名前付きの取引セッションの取得
+    /// Return
+    /// - 取引セッションのスライス(開始時間順)
+    pub fn sessions(&self) -> &[Session] {
+        &self.sessions
+    }
+
+    /// 営業時間外の日時と営業時間の序数の対応の方針の取得
+    /// Return
+    /// - 営業時間外の日時の方針
+    pub fn snap_policy(&self) -> SnapPolicy {
+        self.snap_policy
+    }
+
+    /// 日付をまたぐ営業時間の属する取引日の規則の取得
+    /// Return
+    /// - 取引日の規則
+    pub fn session_attribution(&self) -> SessionAttribution {
+        self.session_attribution
+    }
+
+    /// 祝日データが網羅する年の範囲の取得
+    /// Return
+    /// - 祝日データの範囲．祝日データが無い場合はNone
+    pub fn coverage(&self) -> Option<HolidayCoverage> {
+        self.coverage
+    }
+
+    /// 祝日データの範囲外の日付を扱う場合の方針の取得
+    /// Return
+    /// - 範囲外の日付を扱う場合の方針
+    pub fn coverage_policy(&self) -> CoveragePolicy {
+        self.coverage_policy
+    }
+
+    /// select_dateが祝日データの範囲内であるか方針に従って確認する．
+    /// `try_`から始まるメソッドは指定した日と結果の日についてこの確認を行う．`try_`から始まらないメソッドは確認を行わない．
+    /// Argment
+    /// - select_date: 指定する日
+    ///
+    /// Return
+    /// 方針がErrorで範囲外の場合はエラー．Warnの場合は警告のコールバックを呼んでOk
+    pub fn check_coverage(&self, select_date: NaiveDate) -> Result<(), Error> {
+        self._check_coverage(select_date, self.coverage_policy)
+    }
+
+    /// select_dateが祝日データの範囲内であるかcoverage_policyに従って確認する．
+    /// `try_`から始まらないメソッドはIgnoreを指定して確認を省く
+    pub(crate) fn _check_coverage(&self, select_date: NaiveDate, coverage_policy: CoveragePolicy) -> Result<(), Error> {
+        if coverage_policy == CoveragePolicy::Ignore || matches!(self.coverage, Some(coverage) if coverage.contains(select_date)) {
+            return Ok(());
+        }
+
+        let err = Error::OutsideHolidayCoverage{
+            date_str: select_date.to_string(),
+            coverage_str: self.coverage.map_or("none".to_string(), |coverage|{format!("{}-{}", coverage.start_year, coverage.end_year)})
+        };
+        match coverage_policy {
+            CoveragePolicy::Warn => {
+                if let Some(handler) = &self.coverage_warning_handler {
+                    (handler.0)(&err);
+                }
+                Ok(())
+            },
+            _ => Err(err)
+        }
+    }
+}
+
+/// デフォルト設定(source/holidays.csvの国民の祝日，土日，東京証券取引所の取引時間の9時～11時30分・12時30分～15時)．
+/// 祝日は2016年から2025年のうちcsvに含まれる年(現在は2016年から2022年，`coverage()`を参照)のみで，それ以降の祝日は営業日となる．
+/// csvのパスは作業ディレクトリからの相対パスであり，読み込めない場合は祝日の無いカレンダーとなる．
+/// 東京証券取引所の年末年始の休業日は含まない(`WorkCalendar::tse()`を参照)
+impl Default for WorkCalendar {
+    fn default() -> Self {
+        let start_year = 2016_i32;
+        let end_year = 2025_i32;
+
+        let all_holiday_infos = read_csv("source/holidays.csv").unwrap_or_default();
+
+        let mut work_calendar = WorkCalendar {
+            holidays: Vec::new(),
+            holiday_infos: BTreeMap::new(),
+            holiday_weekdays: [Weekday::Sat, Weekday::Sun].iter().cloned().collect(),
+            intraday_borders: [
+                TimeBorder {start: NaiveTime::from_hms(9,0,0), end: NaiveTime::from_hms(11,30,0)},
+                TimeBorder {start: NaiveTime::from_hms(12,30,0), end: NaiveTime::from_hms(15,0,0)},
+            ].to_vec(),
+            intraday_schedule: IntradaySchedule::default(),
+            sessions: Vec::new(),
+            workday_index: Arc::new(WorkdayIndex::new(&[], &HashSet::new())),
+            business_index: BusinessTimeIndex::new(NaiveDate::from_ymd(1970, 1, 1), &[], [0; 7]),
+            snap_policy: SnapPolicy::Next,
+            coverage: None,
+            coverage_policy: CoveragePolicy::Ignore,
+            coverage_warning_handler: None,
+            session_attribution: SessionAttribution::StartDate,
+            #[cfg(feature="tz")]
+            time_zone: chrono_tz::Tz::Asia__Tokyo
+        };
+        work_calendar.replace_range_holidays(filter_range_holidays(all_holiday_infos, start_year, end_year), start_year, end_year);
+        work_calendar
+    }
+}
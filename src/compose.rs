@@ -0,0 +1,275 @@
+use std::collections::{HashMap, HashSet, BTreeMap};
+use chrono::{NaiveDate, NaiveTime, Timelike, Weekday, Datelike};
+
+use crate::calendar::{WorkCalendar, TimeBorder, HolidayCoverage};
+use crate::error::Error;
+use crate::holiday_info::HolidayInfo;
+use crate::intraday_schedule::IntradaySchedule;
+
+/// 一日のナノ秒
+const DAY_NANOS: i64 = 86_400_000_000_000;
+
+/// 営業時間境界の合成の方法
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum BorderOp {
+    /// 両方の営業時間に含まれる時間
+    Intersection,
+    /// どちらかの営業時間に含まれる時間
+    Union
+}
+
+/// 時間の0時からのナノ秒
+fn time_nanos(time: NaiveTime) -> i64 {
+    time.num_seconds_from_midnight() as i64 * 1_000_000_000 + time.nanosecond() as i64
+}
+
+/// 0時からのナノ秒の時間(一日を越える分は翌日とする)
+fn nanos_time(nanos: i64) -> NaiveTime {
+    let nanos = nanos.rem_euclid(DAY_NANOS);
+    NaiveTime::from_num_seconds_from_midnight((nanos / 1_000_000_000) as u32, (nanos % 1_000_000_000) as u32)
+}
+
+/// 営業時間境界を開始日の0時からのナノ秒の区間にする
+fn border_intervals(intraday_borders: &[TimeBorder]) -> Vec<(i64, i64)> {
+    intraday_borders.iter().map(|border|{
+        let start = time_nanos(border.start);
+        (start, start + border.duration().num_nanoseconds().unwrap_or(DAY_NANOS))
+    }).collect()
+}
+
+/// 区間をソートして重なるもの・接するものをまとめる．一日より長い区間は一日とする
+fn merge_intervals(mut intervals: Vec<(i64, i64)>) -> Vec<(i64, i64)> {
+    intervals.retain(|(start, end)|{start < end});
+    intervals.sort();
+    let mut merged_intervals: Vec<(i64, i64)> = Vec::new();
+    for (start, end) in intervals.into_iter() {
+        match merged_intervals.last_mut() {
+            Some(last_interval) if start <= last_interval.1 => {last_interval.1 = last_interval.1.max(end);},
+            _ => {merged_intervals.push((start, end));}
+        }
+    }
+    for interval in merged_intervals.iter_mut() {
+        interval.1 = interval.1.min(interval.0 + DAY_NANOS);
+    }
+    merged_intervals
+}
+
+/// 二つの営業時間境界を開始日ごとの区間として合成する
+/// Argments
+/// - intraday_borders: 営業時間境界
+/// - other_intraday_borders: もう一方の営業時間境界
+/// - op: 合成の方法
+fn combine_borders(intraday_borders: &[TimeBorder], other_intraday_borders: &[TimeBorder], op: BorderOp) -> Vec<TimeBorder> {
+    let intervals = border_intervals(intraday_borders);
+    let other_intervals = border_intervals(other_intraday_borders);
+    let combined_intervals: Vec<(i64, i64)> = match op {
+        BorderOp::Intersection => intervals.iter().flat_map(|(start, end)|{
+            other_intervals.iter().map(move |(other_start, other_end)|{(*start.max(other_start), *end.min(other_end))})
+        }).collect(),
+        BorderOp::Union => intervals.into_iter().chain(other_intervals).collect()
+    };
+    let mut combined_borders: Vec<TimeBorder> = merge_intervals(combined_intervals).into_iter()
+        .map(|(start, end)|{TimeBorder {start: nanos_time(start), end: nanos_time(end)}}).collect();
+    combined_borders.sort();
+    combined_borders
+}
+
+/// 二つのカレンダーの日ごとの営業時間境界を合成した営業時間境界と曜日・日付・適用期間ごとの指定
+/// Argments
+/// - calendar: カレンダー
+/// - other: もう一方のカレンダー
+/// - op: 合成の方法
+fn combine_schedules(calendar: &WorkCalendar, other: &WorkCalendar, op: BorderOp) -> (Vec<TimeBorder>, IntradaySchedule) {
+    let intraday_borders = combine_borders(&calendar.intraday_borders, &other.intraday_borders, op);
+    let mut intraday_schedule = IntradaySchedule::default();
+
+    // 和をとる場合は一方のみが営業日の日にその営業時間のみとなるよう，営業日でない日の営業時間は無いものとする
+    let is_workdays_only = op == BorderOp::Union;
+    let borders_on = |one_calendar: &'_ WorkCalendar, one_date: NaiveDate| -> Vec<TimeBorder> {
+        if is_workdays_only && !one_calendar.check_workday(one_date) {
+            Vec::new()
+        } else {
+            one_calendar.intraday_borders_on(one_date).to_vec()
+        }
+    };
+
+    // 変わりうる日で区切った期間の中では，どちらのカレンダーも営業時間境界(と営業日かどうか)は曜日のみで決まる
+    let mut change_dates = calendar.intraday_schedule.change_dates();
+    change_dates.extend(other.intraday_schedule.change_dates());
+    if is_workdays_only {
+        change_dates.extend(calendar.holidays.iter().chain(other.holidays.iter()).flat_map(|one_date|{vec![Some(*one_date), one_date.succ_opt()]}).flatten());
+    }
+    change_dates.push(NaiveDate::MIN);
+    change_dates.sort();
+    change_dates.dedup();
+
+    let mut segment_borders: Vec<(NaiveDate, NaiveDate, Weekday, Vec<TimeBorder>)> = Vec::new();  // 二日以上の期間の曜日ごとの営業時間境界
+    for (i, start_date) in change_dates.iter().enumerate() {
+        let end_date = change_dates.get(i + 1).and_then(|x|{x.pred_opt()}).unwrap_or(NaiveDate::MAX);
+        for one_date in start_date.iter_days().take(7).take_while(|x|{*x <= end_date}) {
+            let combined_borders = combine_borders(&borders_on(calendar, one_date), &borders_on(other, one_date), op);
+            if *start_date == end_date {  // 曜日の指定より優先させるため常に日付の指定とする
+                intraday_schedule.set_date_borders(one_date, &combined_borders);
+            } else {
+                segment_borders.push((*start_date, end_date, one_date.weekday(), combined_borders));
+            }
+        }
+    }
+
+    // 全ての期間で同じ曜日は曜日の指定，そうでない曜日は期間と曜日の指定とする
+    let mut weekday_borders: HashMap<Weekday, Option<&Vec<TimeBorder>>> = HashMap::new();
+    for (_, _, weekday, combined_borders) in segment_borders.iter() {
+        let uniform_borders = weekday_borders.entry(*weekday).or_insert(Some(combined_borders));
+        if *uniform_borders != Some(combined_borders) {
+            *uniform_borders = None;
+        }
+    }
+    for (weekday, uniform_borders) in weekday_borders.iter() {
+        if let Some(combined_borders) = uniform_borders {
+            if **combined_borders != intraday_borders {
+                intraday_schedule.set_weekday_borders(*weekday, combined_borders);
+            }
+        }
+    }
+    for (start_date, end_date, weekday, combined_borders) in segment_borders.iter() {
+        if weekday_borders[weekday].is_none() {
+            intraday_schedule.add_range_weekday_borders(*start_date, *end_date, *weekday, combined_borders);
+        }
+    }
+    (intraday_borders, intraday_schedule)
+}
+
+/// 二つの祝日データの範囲の共通部分．一方が無い場合はもう一方とする
+fn intersect_coverage(coverage: Option<HolidayCoverage>, other_coverage: Option<HolidayCoverage>) -> Option<HolidayCoverage> {
+    match (coverage, other_coverage) {
+        (Some(coverage), Some(other_coverage)) => {
+            let start_year = coverage.start_year.max(other_coverage.start_year);
+            let end_year = coverage.end_year.min(other_coverage.end_year);
+            if start_year <= end_year {Some(HolidayCoverage {start_year, end_year})} else {None}
+        },
+        (coverage, other_coverage) => coverage.or(other_coverage)
+    }
+}
+
+impl WorkCalendar {
+    /// 合成する二つのカレンダーのタイムゾーンが同じであるか確認する
+    #[cfg(feature="tz")]
+    fn check_same_time_zone(&self, other: &WorkCalendar) -> Result<(), Error> {
+        if self.time_zone == other.time_zone {
+            Ok(())
+        } else {
+            Err(Error::TimeZoneMismatch{time_zone_str: self.time_zone.name().to_string(), other_time_zone_str: other.time_zone.name().to_string()})
+        }
+    }
+
+    #[cfg(not(feature="tz"))]
+    fn check_same_time_zone(&self, _: &WorkCalendar) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// 祝日・休日曜日・営業時間境界を置き換えたカレンダーを作成する．その他の設定はselfのものを用いる
+    fn with_composed(&self, holiday_infos: BTreeMap<NaiveDate, HolidayInfo>, coverage: Option<HolidayCoverage>, holiday_weekdays: HashSet<Weekday>, intraday_borders: Vec<TimeBorder>, intraday_schedule: IntradaySchedule) -> WorkCalendar {
+        let mut composed_calendar = self.clone();
+        composed_calendar.holiday_weekdays = holiday_weekdays;
+        composed_calendar.intraday_borders = intraday_borders;
+        composed_calendar.intraday_schedule = intraday_schedule;
+        composed_calendar.update_holidays(holiday_infos, coverage);  // 営業日・営業時間の索引もここで同期する
+        composed_calendar
+    }
+
+    /// 祝日・休日曜日の和集合をとったカレンダー(両方の営業日のみ営業日となる)．
+    /// 営業時間は日ごとに両方の営業時間に含まれる時間とし，名前付きの取引セッションは除く．
+    /// 日付をまたぐ営業時間は同じ日に開始する営業時間とのみ合成し，翌日に開始する営業時間とは合成しない．
+    /// 祝日データの範囲は両方の範囲の共通部分とし，その他の設定はselfのものを用いる
+    /// Argment
+    /// - other: 合成するカレンダー
+    ///
+    /// Return
+    /// 合成したカレンダー．タイムゾーンが異なる場合はエラー
+    pub fn try_union(&self, other: &WorkCalendar) -> Result<WorkCalendar, Error> {
+        self.check_same_time_zone(other)?;
+        let mut holiday_infos = self.holiday_infos.clone();
+        for (date, holiday_info) in other.holiday_infos.iter() {
+            holiday_infos.entry(*date).or_insert_with(||{holiday_info.clone()});
+        }
+        let holiday_weekdays: HashSet<Weekday> = self.holiday_weekdays.union(&other.holiday_weekdays).cloned().collect();
+        let (intraday_borders, intraday_schedule) = combine_schedules(self, other, BorderOp::Intersection);
+        let mut composed_calendar = self.with_composed(holiday_infos, intersect_coverage(self.coverage, other.coverage), holiday_weekdays, intraday_borders, intraday_schedule);
+        composed_calendar.sessions = Vec::new();
+        Ok(composed_calendar)
+    }
+
+    /// 祝日・休日曜日の和集合をとったカレンダー(両方の営業日のみ営業日となる)．
+    /// 営業時間は日ごとに両方の営業時間に含まれる時間とし，名前付きの取引セッションは除く．
+    /// 日付をまたぐ営業時間は同じ日に開始する営業時間とのみ合成し，翌日に開始する営業時間とは合成しない．
+    /// 祝日データの範囲は両方の範囲の共通部分とし，その他の設定はselfのものを用いる
+    /// Argment
+    /// - other: 合成するカレンダー
+    ///
+    /// Return
+    /// 合成したカレンダー(タイムゾーンが異なる場合はパニックする)
+    pub fn union(&self, other: &WorkCalendar) -> WorkCalendar {
+        self.try_union(other).unwrap()
+    }
+
+    /// 祝日・休日曜日の共通部分をとったカレンダー(どちらかの営業日が営業日となる)．
+    /// 営業時間は日ごとにその日が営業日であるカレンダーの営業時間のどちらかに含まれる時間とし，名前付きの取引セッションは除く．
+    /// 日付をまたぐ営業時間は同じ日に開始する営業時間とのみ合成し，翌日に開始する営業時間とは合成しない．
+    /// 祝日データの範囲は両方の範囲の共通部分とし，その他の設定はselfのものを用いる
+    /// Argment
+    /// - other: 合成するカレンダー
+    ///
+    /// Return
+    /// 合成したカレンダー．タイムゾーンが異なる場合はエラー
+    pub fn try_intersection(&self, other: &WorkCalendar) -> Result<WorkCalendar, Error> {
+        self.check_same_time_zone(other)?;
+        let holiday_infos: BTreeMap<NaiveDate, HolidayInfo> = self.holiday_infos.iter()
+            .filter(|(date, _)|{other.holiday_infos.contains_key(date)})
+            .map(|(date, holiday_info)|{(*date, holiday_info.clone())}).collect();
+        let holiday_weekdays: HashSet<Weekday> = self.holiday_weekdays.intersection(&other.holiday_weekdays).cloned().collect();
+        let (intraday_borders, intraday_schedule) = combine_schedules(self, other, BorderOp::Union);
+        let mut composed_calendar = self.with_composed(holiday_infos, intersect_coverage(self.coverage, other.coverage), holiday_weekdays, intraday_borders, intraday_schedule);
+        composed_calendar.sessions = Vec::new();
+        Ok(composed_calendar)
+    }
+
+    /// 祝日・休日曜日の共通部分をとったカレンダー(どちらかの営業日が営業日となる)．
+    /// 営業時間は日ごとにその日が営業日であるカレンダーの営業時間のどちらかに含まれる時間とし，名前付きの取引セッションは除く．
+    /// 日付をまたぐ営業時間は同じ日に開始する営業時間とのみ合成し，翌日に開始する営業時間とは合成しない．
+    /// 祝日データの範囲は両方の範囲の共通部分とし，その他の設定はselfのものを用いる
+    /// Argment
+    /// - other: 合成するカレンダー
+    ///
+    /// Return
+    /// 合成したカレンダー(タイムゾーンが異なる場合はパニックする)
+    pub fn intersection(&self, other: &WorkCalendar) -> WorkCalendar {
+        self.try_intersection(other).unwrap()
+    }
+
+    /// otherの祝日・休日曜日を除いたカレンダー．
+    /// 営業時間・名前付きの取引セッション・祝日データの範囲などその他の設定はselfのものを用いる
+    /// Argment
+    /// - other: 除く祝日・休日曜日をもつカレンダー
+    ///
+    /// Return
+    /// 合成したカレンダー．タイムゾーンが異なる場合はエラー
+    pub fn try_except(&self, other: &WorkCalendar) -> Result<WorkCalendar, Error> {
+        self.check_same_time_zone(other)?;
+        let holiday_infos: BTreeMap<NaiveDate, HolidayInfo> = self.holiday_infos.iter()
+            .filter(|(date, _)|{!other.holiday_infos.contains_key(date)})
+            .map(|(date, holiday_info)|{(*date, holiday_info.clone())}).collect();
+        let holiday_weekdays: HashSet<Weekday> = self.holiday_weekdays.difference(&other.holiday_weekdays).cloned().collect();
+        Ok(self.with_composed(holiday_infos, self.coverage, holiday_weekdays, self.intraday_borders.clone(), self.intraday_schedule.clone()))
+    }
+
+    /// otherの祝日・休日曜日を除いたカレンダー．
+    /// 営業時間・名前付きの取引セッション・祝日データの範囲などその他の設定はselfのものを用いる
+    /// Argment
+    /// - other: 除く祝日・休日曜日をもつカレンダー
+    ///
+    /// Return
+    /// 合成したカレンダー(タイムゾーンが異なる場合はパニックする)
+    pub fn except(&self, other: &WorkCalendar) -> WorkCalendar {
+        self.try_except(other).unwrap()
+    }
+}
@@ -0,0 +1,136 @@
+use chrono::{NaiveDate, Datelike};
+
+use crate::calendar::WorkCalendar;
+use crate::global::read_work_calendar;
+
+// BUS/252の一年の営業日数
+static BUSINESS_DAYS_PER_YEAR: f64 = 252.0;
+
+/// 日数計算の規約(Day Count Convention)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayCount {
+    /// 実日数/365
+    Act365Fixed,
+    /// 実日数/360
+    Act360,
+    /// 実日数/実日数(ISDA)．閏年とそれ以外の年の日数を分けて計算する
+    ActActIsda,
+    /// 30/360(Bond Basis)．開始日が30日以降の場合のみ終了日の31日を30日とする
+    Thirty360BondBasis,
+    /// 30E/360(Eurobond Basis)．31日を30日とする
+    Thirty360European,
+    /// 30E/360(ISDA)．月末を30日とする(終了日が満期日の2月末である場合の例外は扱わない)
+    Thirty360EuropeanIsda,
+    /// 営業日数/252．開始日を含み終了日を含まない営業日数を用いる
+    Bus252
+}
+
+/// 閏年であるかどうか
+fn is_leap_year(year: i32) -> bool {
+    NaiveDate::from_ymd_opt(year, 2, 29).is_some()
+}
+
+/// 月末であるかどうか
+fn is_end_of_month(select_date: NaiveDate) -> bool {
+    select_date.succ().month() != select_date.month()
+}
+
+/// 30/360系の規約による年率
+/// Argments
+/// - start_date: 開始日
+/// - end_date: 終了日
+/// - start_day: 調整後の開始日の日
+/// - end_day: 調整後の終了日の日
+fn thirty_360_fraction(start_date: NaiveDate, end_date: NaiveDate, start_day: u32, end_day: u32) -> f64 {
+    let days = 360 * (end_date.year() - start_date.year())
+        + 30 * (end_date.month() as i32 - start_date.month() as i32)
+        + (end_day as i32 - start_day as i32);
+    days as f64 / 360.0
+}
+
+/// 実日数/実日数(ISDA)の年率
+/// Argments
+/// - start_date: 開始日
+/// - end_date: 終了日
+fn act_act_isda_fraction(start_date: NaiveDate, end_date: NaiveDate) -> f64 {
+    let mut fraction = 0.0;
+    let mut one_start_date = start_date;
+    while one_start_date < end_date {
+        let next_year_date = NaiveDate::from_ymd(one_start_date.year() + 1, 1, 1);
+        let one_end_date = if next_year_date < end_date {next_year_date} else {end_date};
+        let year_days = if is_leap_year(one_start_date.year()) {366.0} else {365.0};
+        fraction += (one_end_date - one_start_date).num_days() as f64 / year_days;
+        one_start_date = one_end_date;
+    }
+    fraction
+}
+
+
+impl WorkCalendar {
+    /// start_dateからend_dateまでの年率を日数計算の規約に従って計算する．
+    /// end_dateがstart_dateより前の場合は負の値となる．
+    /// Argments
+    /// - start_date: 開始日
+    /// - end_date: 終了日
+    /// - day_count: 日数計算の規約
+    ///
+    /// Return
+    /// 年率
+    pub fn year_fraction(&self, start_date: NaiveDate, end_date: NaiveDate, day_count: DayCount) -> f64 {
+        if end_date < start_date {
+            return -self.year_fraction(end_date, start_date, day_count);
+        }
+
+        let actual_days = (end_date - start_date).num_days() as f64;
+        match day_count {
+            DayCount::Act365Fixed => actual_days / 365.0,
+            DayCount::Act360 => actual_days / 360.0,
+            DayCount::ActActIsda => act_act_isda_fraction(start_date, end_date),
+            DayCount::Thirty360BondBasis => {
+                let start_day = start_date.day().min(30);
+                let end_day = if start_day == 30 {end_date.day().min(30)} else {end_date.day()};
+                thirty_360_fraction(start_date, end_date, start_day, end_day)
+            },
+            DayCount::Thirty360European => {
+                thirty_360_fraction(start_date, end_date, start_date.day().min(30), end_date.day().min(30))
+            },
+            DayCount::Thirty360EuropeanIsda => {
+                let start_day = if is_end_of_month(start_date) {30} else {start_date.day()};
+                let end_day = if is_end_of_month(end_date) {30} else {end_date.day()};
+                thirty_360_fraction(start_date, end_date, start_day, end_day)
+            },
+            DayCount::Bus252 => self.workday_index.count_workdays(start_date, end_date) as f64 / BUSINESS_DAYS_PER_YEAR
+        }
+    }
+}
+
+
+/// start_dateからend_dateまでの年率を日数計算の規約に従って計算する．
+/// end_dateがstart_dateより前の場合は負の値となる．
+/// Argments
+/// - start_date: 開始日
+/// - end_date: 終了日
+/// - day_count: 日数計算の規約
+///     - Act365Fixed: 実日数/365
+///     - Act360: 実日数/360
+///     - ActActIsda: 実日数/実日数(ISDA)
+///     - Thirty360BondBasis: 30/360(Bond Basis)
+///     - Thirty360European: 30E/360
+///     - Thirty360EuropeanIsda: 30E/360(ISDA)
+///     - Bus252: 営業日数/252(開始日を含み終了日を含まない)
+///
+/// Return
+/// 年率
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::day_count::*;
+/// let start_date = NaiveDate::from_ymd(2021, 1, 1);
+/// let end_date = NaiveDate::from_ymd(2021, 2, 1);
+/// assert_eq!(year_fraction(start_date, end_date, DayCount::Act365Fixed), 31.0 / 365.0);
+/// assert_eq!(year_fraction(start_date, end_date, DayCount::Bus252), 19.0 / 252.0);
+/// ~~~~
+pub fn year_fraction(start_date: NaiveDate, end_date: NaiveDate, day_count: DayCount) -> f64 {
+    read_work_calendar().year_fraction(start_date, end_date, day_count)
+}
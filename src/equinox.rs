@@ -0,0 +1,137 @@
+use chrono::{NaiveDate, NaiveDateTime, Duration};
+
+// 日本標準時の時差(時間)
+static JST_OFFSET_HOURS: i64 = 9;
+// 1970年1月1日0時(UT)のユリウス日
+static UNIX_EPOCH_JULIAN_DAY: f64 = 2440587.5;
+// J2000.0のユリウス日
+static J2000_JULIAN_DAY: f64 = 2451545.0;
+
+// 周期項(A, B, C) (Meeus, Astronomical Algorithms, Table 27.C)
+static PERIODIC_TERMS: [(f64, f64, f64); 24] = [
+    (485.0, 324.96, 1934.136), (203.0, 337.23, 32964.467), (199.0, 342.08, 20.186), (182.0, 27.85, 445267.112),
+    (156.0, 73.14, 45036.886), (136.0, 171.52, 22518.443), (77.0, 222.54, 65928.934), (74.0, 296.72, 3034.906),
+    (70.0, 243.58, 9037.513), (58.0, 119.81, 33718.147), (52.0, 297.17, 150.678), (50.0, 21.02, 2281.226),
+    (45.0, 247.54, 29929.562), (44.0, 325.15, 31555.956), (29.0, 60.93, 4443.417), (18.0, 155.12, 67555.328),
+    (17.0, 288.79, 4562.452), (16.0, 198.04, 62894.029), (14.0, 199.76, 31436.921), (12.0, 95.39, 14577.848),
+    (12.0, 287.11, 31931.756), (12.0, 320.81, 34777.259), (9.0, 227.73, 1222.114), (8.0, 15.45, 16859.074)
+];
+
+/// 春分・秋分の種類
+#[derive(Debug, Clone, Copy)]
+enum Equinox {
+    /// 春分
+    Vernal,
+    /// 秋分
+    Autumnal
+}
+
+/// 力学時と世界時の差ΔT(秒)をEspenak・Meeusの多項式から計算する
+/// Argment
+/// - decimal_year: 小数の年
+fn delta_t_seconds(decimal_year: f64) -> f64 {
+    let y = decimal_year;
+    if y < 1920.0 {
+        let t = y - 1900.0;
+        -2.79 + 1.494119 * t - 0.0598939 * t.powi(2) + 0.0061966 * t.powi(3) - 0.000197 * t.powi(4)
+    } else if y < 1941.0 {
+        let t = y - 1920.0;
+        21.20 + 0.84493 * t - 0.076100 * t.powi(2) + 0.0020936 * t.powi(3)
+    } else if y < 1961.0 {
+        let t = y - 1950.0;
+        29.07 + 0.407 * t - t.powi(2) / 233.0 + t.powi(3) / 2547.0
+    } else if y < 1986.0 {
+        let t = y - 1975.0;
+        45.45 + 1.067 * t - t.powi(2) / 260.0 - t.powi(3) / 718.0
+    } else if y < 2005.0 {
+        let t = y - 2000.0;
+        63.86 + 0.3345 * t - 0.060374 * t.powi(2) + 0.0017275 * t.powi(3) + 0.000651814 * t.powi(4) + 0.00002373599 * t.powi(5)
+    } else if y < 2050.0 {
+        let t = y - 2000.0;
+        62.92 + 0.32217 * t + 0.005589 * t.powi(2)
+    } else if y < 2150.0 {
+        -20.0 + 32.0 * ((y - 1820.0) / 100.0).powi(2) - 0.5628 * (2150.0 - y)
+    } else {
+        -20.0 + 32.0 * ((y - 1820.0) / 100.0).powi(2)
+    }
+}
+
+/// 春分・秋分の瞬間を力学時のユリウス日(JDE)で計算する(Meeus, Astronomical Algorithms, Chapter 27)
+/// Argments
+/// - year: 年
+/// - equinox: 春分か秋分か
+fn equinox_julian_ephemeris_day(year: i32, equinox: Equinox) -> f64 {
+    let y = (year as f64 - 2000.0) / 1000.0;
+    // 平均春分・秋分
+    let mean_jde = match equinox {
+        Equinox::Vernal => {
+            2451623.80984 + 365242.37404 * y + 0.05169 * y.powi(2) - 0.00411 * y.powi(3) - 0.00057 * y.powi(4)
+        },
+        Equinox::Autumnal => {
+            2451810.21715 + 365242.01767 * y - 0.11575 * y.powi(2) + 0.00337 * y.powi(3) + 0.00078 * y.powi(4)
+        }
+    };
+
+    // 周期項による補正
+    let t = (mean_jde - J2000_JULIAN_DAY) / 36525.0;
+    let w = (35999.373 * t - 2.47).to_radians();
+    let delta_lambda = 1.0 + 0.0334 * w.cos() + 0.0007 * (2.0 * w).cos();
+    let s: f64 = PERIODIC_TERMS.iter().map(|(a, b, c)|{a * (b + c * t).to_radians().cos()}).sum();
+
+    mean_jde + 0.00001 * s / delta_lambda
+}
+
+/// 春分・秋分の瞬間を日本標準時で計算する
+/// Argments
+/// - year: 年
+/// - equinox: 春分か秋分か
+fn equinox_datetime_jst(year: i32, equinox: Equinox) -> NaiveDateTime {
+    let month = match equinox {
+        Equinox::Vernal => 3.0,
+        Equinox::Autumnal => 9.0
+    };
+    let jde = equinox_julian_ephemeris_day(year, equinox);
+    let delta_t = delta_t_seconds(year as f64 + (month - 0.5) / 12.0);
+
+    let unix_epoch = NaiveDate::from_ymd(1970, 1, 1).and_hms(0, 0, 0);
+    let utc_milliseconds = ((jde - UNIX_EPOCH_JULIAN_DAY) * 86_400_000.0 - delta_t * 1000.0).round() as i64;
+    unix_epoch + Duration::milliseconds(utc_milliseconds) + Duration::hours(JST_OFFSET_HOURS)
+}
+
+/// 指定した年の春分の瞬間を日本標準時で取得する．
+/// Meeusの算法(周期項24項)とΔTの多項式近似を用い，1900年から2150年の範囲で分単位の精度をもつ．
+/// Argment
+/// - year: 年
+///
+/// Return
+/// 春分の日時(日本標準時)
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::equinox::*;
+/// let vernal_equinox = get_vernal_equinox(2021);
+/// assert_eq!(vernal_equinox.date(), NaiveDate::from_ymd(2021, 3, 20));
+/// ~~~~
+pub fn get_vernal_equinox(year: i32) -> NaiveDateTime {
+    equinox_datetime_jst(year, Equinox::Vernal)
+}
+
+/// 指定した年の秋分の瞬間を日本標準時で取得する．
+/// Meeusの算法(周期項24項)とΔTの多項式近似を用い，1900年から2150年の範囲で分単位の精度をもつ．
+/// Argment
+/// - year: 年
+///
+/// Return
+/// 秋分の日時(日本標準時)
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::equinox::*;
+/// let autumnal_equinox = get_autumnal_equinox(2021);
+/// assert_eq!(autumnal_equinox.date(), NaiveDate::from_ymd(2021, 9, 23));
+/// ~~~~
+pub fn get_autumnal_equinox(year: i32) -> NaiveDateTime {
+    equinox_datetime_jst(year, Equinox::Autumnal)
+}
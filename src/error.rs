@@ -17,6 +17,42 @@ pub enum Error {
     #[error("date parse error for {date_str:?}")]
     ParseDateError{date_str: String},
 
+    #[error("no workday is reachable from {date_str:?}")]
+    NoWorkdayReachable{date_str: String},
+
+    #[error("intraday borders are empty")]
+    EmptyIntradayBorders,
+
+    #[error("{datetime_str:?} is outside the intraday sessions")]
+    OutsideSession{datetime_str: String},
+
+    #[error("no session named {name:?} is reachable")]
+    UnknownSession{name: String},
+
+    #[error("input is not sorted at index {index} ({datetime_str:?})")]
+    UnsortedInput{index: usize, datetime_str: String},
+
+    #[error("no calendar named {name:?} is registered")]
+    UnknownCalendar{name: String},
+
+    #[error("invalid calendar config: {message}")]
+    InvalidConfig{message: String},
+
+    #[error("unsupported data type {data_type_str:?}")]
+    UnsupportedDataType{data_type_str: String},
+
+    #[error("date out of range from {date_str:?}")]
+    OutOfRange{date_str: String},
+
+    #[error("{date_str:?} is outside the holiday coverage {coverage_str:?}")]
+    OutsideHolidayCoverage{date_str: String, coverage_str: String},
+
+    #[error("time zones of calendars differ: {time_zone_str:?} and {other_time_zone_str:?}")]
+    TimeZoneMismatch{time_zone_str: String, other_time_zone_str: String},
+
+    #[error("the lock of the default work calendar is poisoned")]
+    PoisonedLock,
+
     #[error(transparent)]
     Other(#[from] anyhow::Error)
 }
\ No newline at end of file
@@ -1,15 +1,447 @@
-use chrono::{NaiveDateTime};
+use chrono::{NaiveDate, NaiveDateTime, Duration};
+
+use crate::calendar::WorkCalendar;
+use crate::intraday::SessionInterval;
+use crate::intraday_range::Label;
+use crate::workdays::Closed;
+use crate::error::Error;
+use crate::global::{read_work_calendar, try_read_work_calendar};
+
+
+/// 営業日・営業時間内の日時のラベル
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct IntradayLabel {
+    /// 取引日
+    pub trading_date: NaiveDate,
+    /// その日の営業時間境界のインデックス
+    pub session_index: usize,
+    /// 営業時間の開始からの秒数
+    pub seconds_since_open: i64,
+    /// 営業時間の終了までの秒数
+    pub seconds_until_close: i64
+}
+
+/// 時系列の欠損した足の区間(営業時間で連続する欠損をまとめたもの)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeriesGap {
+    /// 最初の欠損した足の開始日時
+    pub start: NaiveDateTime,
+    /// 欠損の後に観測のある足の開始日時(営業時間での欠損の終了)
+    pub end: NaiveDateTime,
+    /// 欠損した足の数
+    pub missing_count: usize,
+    /// 欠損の営業時間での長さ
+    pub business_duration: Duration
+}
+
+/// 時系列の検証の結果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SeriesReport {
+    /// 欠損した足の区間
+    pub gaps: Vec<SeriesGap>,
+    /// 営業日・営業時間外の観測のインデックス
+    pub outside_session: Vec<usize>,
+    /// 直前の観測と同じ日時の観測のインデックス
+    pub duplicates: Vec<usize>
+}
+
+impl SeriesReport {
+    /// 欠損・営業時間外の観測・重複が無いかどうか
+    pub fn is_complete(&self) -> bool {
+        self.gaps.is_empty() & self.outside_session.is_empty() & self.duplicates.is_empty()
+    }
+}
+
+/// 日時のベクターが昇順でない最初のインデックス
+/// Argment
+/// - datetime_vec: 調べる日時のベクター
+///
+/// Return
+/// 直前の日時より前の日時のインデックス．昇順の場合はNone
+fn find_unsorted_index(datetime_vec:&[NaiveDateTime]) -> Option<usize> {
+    datetime_vec.windows(2).position(|pair|{pair[0] > pair[1]}).map(|i|{i + 1})
+}
+
+/// 日時のベクターが昇順であるかチェックする
+/// Argment
+/// - datetime_vec: 調べる日時のベクター
+///
+/// Return
+/// 昇順でない場合はその最初のインデックスのエラー
+pub fn check_sorted(datetime_vec:&[NaiveDateTime]) -> Result<(), Error> {
+    match find_unsorted_index(datetime_vec) {
+        Some(index) => Err(Error::UnsortedInput{index, datetime_str: datetime_vec[index].to_string()}),
+        None => Ok(())
+    }
+}
+
+
+/// エポック(1970年1月1日0時)からの時間の単位
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EpochUnit {
+    /// 秒
+    Second,
+    /// ミリ秒
+    Millisecond,
+    /// マイクロ秒
+    Microsecond,
+    /// ナノ秒
+    Nanosecond
+}
+
+impl EpochUnit {
+    /// 一秒の単位数
+    fn per_second(&self) -> i64 {
+        match self {
+            EpochUnit::Second => 1,
+            EpochUnit::Millisecond => 1_000,
+            EpochUnit::Microsecond => 1_000_000,
+            EpochUnit::Nanosecond => 1_000_000_000
+        }
+    }
+
+    /// 日時をエポックからの時間に変換する．範囲外の場合はNone
+    fn epoch_of(&self, datetime: NaiveDateTime) -> Option<i64> {
+        let subsec = datetime.timestamp_subsec_nanos() as i64 / (1_000_000_000 / self.per_second());
+        datetime.timestamp().checked_mul(self.per_second())?.checked_add(subsec)
+    }
+}
+
+/// 抽出する対象
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum ExtractTarget {
+    /// 営業日
+    Workdays,
+    /// 営業時間
+    Intraday,
+    /// 営業日・営業時間
+    WorkdaysIntraday
+}
+
+/// エポックからの時間を日ごとの営業日・営業時間の区間と照合する．
+/// 区間は日が変わるときのみ求めるため，要素ごとに日時へ変換しない
+pub(crate) struct EpochMasker<'a> {
+    calendar: &'a WorkCalendar,
+    target: ExtractTarget,
+    unit: EpochUnit,
+    day: Option<i64>,
+    ranges: Vec<(i64, i64)>  // その日の時間を含みうる区間(エポックからの時間)
+}
+
+impl<'a> EpochMasker<'a> {
+    pub(crate) fn new(calendar: &'a WorkCalendar, target: ExtractTarget, unit: EpochUnit) -> Self {
+        EpochMasker {calendar, target, unit, day: None, ranges: Vec::new()}
+    }
+
+    /// エポックからの時間が抽出の対象に含まれるかどうか
+    pub(crate) fn contains(&mut self, timestamp: i64) -> bool {
+        let day = timestamp.div_euclid(self.unit.per_second() * 86_400);
+        if self.day != Some(day) {  // 日が変わるとき
+            self.day = Some(day);
+            self.ranges = self.day_ranges(day);
+        }
+        self.ranges.iter().any(|(start, end)|{(*start <= timestamp) & (timestamp < *end)})
+    }
+
+    /// エポックからday日目の時間を含みうる区間
+    fn day_ranges(&self, day: i64) -> Vec<(i64, i64)> {
+        let date = match NaiveDate::from_ymd(1970, 1, 1).checked_add_signed(Duration::days(day)) {
+            Some(date) => date,
+            None => {return vec![];}
+        };
+        let to_epoch = |datetime: NaiveDateTime|{self.unit.epoch_of(datetime)};
+        match self.target {
+            ExtractTarget::Workdays => {
+                if !self.calendar.check_workday(date) {
+                    return vec![];
+                }
+                let start = to_epoch(date.and_hms(0, 0, 0)).unwrap_or(i64::MIN);
+                let end = date.succ_opt().and_then(|x|{to_epoch(x.and_hms(0, 0, 0))}).unwrap_or(i64::MAX);
+                vec![(start, end)]
+            },
+            ExtractTarget::Intraday | ExtractTarget::WorkdaysIntraday => {
+                if self.calendar.check_intraday_borders().is_err() {  // 営業時間が無い場合
+                    return vec![];
+                }
+                let is_workdays_only = self.target == ExtractTarget::WorkdaysIntraday;
+                date.pred_opt().into_iter().chain(Some(date))
+                    .flat_map(|one_date|{self.calendar.sessions_on_date(one_date, is_workdays_only)})
+                    .map(|session|{(to_epoch(session.start).unwrap_or(i64::MIN), to_epoch(session.end).unwrap_or(i64::MAX))})
+                    .collect()
+            }
+        }
+    }
+}
+
+
+impl WorkCalendar {
+    /// Vec<NaiveDatetime>から営業日のものをboolとして抽出．日付の変わるときのみ判定する
+    /// Argment
+    /// - datetime_vec: 抽出したい日時のベクター
+    ///
+    /// Return
+    /// ブールのベクター
+    pub fn extract_workdays_bool(&self, datetime_vec:&[NaiveDateTime]) -> Vec<bool> {
+        self.extract_workdays_bool_iter(datetime_vec.iter().cloned())
+    }
+
+    /// 日時のイテレーターから営業日のものをboolとして抽出．日付の変わるときのみ判定する
+    /// Argment
+    /// - datetimes: 抽出したい日時のイテレーター
+    ///
+    /// Return
+    /// ブールのベクター
+    pub fn extract_workdays_bool_iter<I: IntoIterator<Item=NaiveDateTime>>(&self, datetimes: I) -> Vec<bool> {
+        let mut now_date: Option<NaiveDate> = None;
+        let mut is_end_today = true;
+
+        datetimes.into_iter().map(|datetime|{
+            let date = datetime.date();
+            if now_date != Some(date) {  // 日付が変わるとき
+                now_date = Some(date);
+                is_end_today = !self.check_workday(date);  // その日が営業日でない場合は終了
+            }
+            !is_end_today
+        }).collect()
+    }
+
+    /// 日時を含む前日・当日に開始する営業時間の区間をmap_fnで変換する．営業時間外の日時はoutsideとする．
+    /// 区間は日付の変わるときのみ求める
+    /// Argments
+    /// - datetime_vec: 変換したい日時のベクター
+    /// - is_workdays_only: 営業日の営業時間のみとするかどうか
+    /// - outside: 営業時間外の値
+    /// - map_fn: 日時とそれを含む営業時間の区間からの変換
+    fn map_sessions<T: Clone, F>(&self, datetime_vec:&[NaiveDateTime], is_workdays_only: bool, outside: T, map_fn: F) -> Vec<T>
+    where F: FnMut(NaiveDateTime, &SessionInterval) -> T
+    {
+        self.map_sessions_iter(datetime_vec.iter().cloned(), is_workdays_only, outside, map_fn)
+    }
+
+    /// 日時のイテレーターの各日時を含む前日・当日に開始する営業時間の区間をmap_fnで変換する．
+    /// 営業時間外の日時はoutsideとする．区間は日付の変わるときのみ求める
+    /// Argments
+    /// - datetimes: 変換したい日時のイテレーター
+    /// - is_workdays_only: 営業日の営業時間のみとするかどうか
+    /// - outside: 営業時間外の値
+    /// - map_fn: 日時とそれを含む営業時間の区間からの変換
+    fn map_sessions_iter<T: Clone, I, F>(&self, datetimes: I, is_workdays_only: bool, outside: T, mut map_fn: F) -> Vec<T>
+    where I: IntoIterator<Item=NaiveDateTime>, F: FnMut(NaiveDateTime, &SessionInterval) -> T
+    {
+        let is_empty_borders = self.check_intraday_borders().is_err();  // 営業時間が無い場合
+        let mut now_date: Option<NaiveDate> = None;
+        let mut sessions: Vec<SessionInterval> = Vec::new();  // 前日・当日に開始する営業時間の区間
+
+        datetimes.into_iter().map(|datetime|{
+            let date = datetime.date();
+            if !is_empty_borders && (now_date != Some(date)) {  // 日付が変わるとき
+                now_date = Some(date);
+                sessions = date.pred_opt().into_iter().chain(Some(date))
+                    .flat_map(|one_date|{self.sessions_on_date(one_date, is_workdays_only)}).collect();
+            }
+
+            match sessions.iter().find(|session|{session.contains(datetime)}) {
+                Some(session) => map_fn(datetime, session),
+                None => outside.clone()
+            }
+        }).collect()
+    }
+
+    /// エポックからの時間のスライスから抽出の対象のものをboolとして抽出
+    /// Argments
+    /// - timestamps: エポックからの時間(ナイーブな日時のタイムスタンプ)のスライス
+    /// - unit: エポックからの時間の単位
+    /// - target: 抽出する対象
+    fn extract_epoch_bool(&self, timestamps:&[i64], unit: EpochUnit, target: ExtractTarget) -> Vec<bool> {
+        let mut masker = EpochMasker::new(self, target, unit);
+        timestamps.iter().map(|timestamp|{masker.contains(*timestamp)}).collect()
+    }
+
+    /// エポックからの時間のスライスから営業日のものをboolとして抽出
+    /// Argments
+    /// - timestamps: エポックからの時間(ナイーブな日時のタイムスタンプ)のスライス
+    /// - unit: エポックからの時間の単位
+    ///
+    /// Return
+    /// ブールのベクター
+    pub fn extract_workdays_bool_epoch(&self, timestamps:&[i64], unit: EpochUnit) -> Vec<bool> {
+        self.extract_epoch_bool(timestamps, unit, ExtractTarget::Workdays)
+    }
+
+    /// エポックからの時間のスライスから営業時間のものをboolとして抽出
+    /// Argments
+    /// - timestamps: エポックからの時間(ナイーブな日時のタイムスタンプ)のスライス
+    /// - unit: エポックからの時間の単位
+    ///
+    /// Return
+    /// ブールのベクター
+    pub fn extract_intraday_bool_epoch(&self, timestamps:&[i64], unit: EpochUnit) -> Vec<bool> {
+        self.extract_epoch_bool(timestamps, unit, ExtractTarget::Intraday)
+    }
+
+    /// エポックからの時間のスライスから営業日・営業時間のものをboolとして抽出．
+    /// 日付をまたぐ営業時間は開始日が営業日であるかで判定する
+    /// Argments
+    /// - timestamps: エポックからの時間(ナイーブな日時のタイムスタンプ)のスライス
+    /// - unit: エポックからの時間の単位
+    ///
+    /// Return
+    /// ブールのベクター
+    pub fn extract_workdays_intraday_bool_epoch(&self, timestamps:&[i64], unit: EpochUnit) -> Vec<bool> {
+        self.extract_epoch_bool(timestamps, unit, ExtractTarget::WorkdaysIntraday)
+    }
+
+    /// 日時が前日・当日に開始する営業時間の区間に含まれるかをboolとして抽出
+    /// Argments
+    /// - datetime_vec: 抽出したい日時のベクター
+    /// - is_workdays_only: 営業日の営業時間のみとするかどうか
+    fn extract_sessions_bool(&self, datetime_vec:&[NaiveDateTime], is_workdays_only: bool) -> Vec<bool> {
+        self.map_sessions(datetime_vec, is_workdays_only, false, |_, _|{true})
+    }
+
+    /// Vec<NaiveDatetime>から営業時間のものをboolとして抽出
+    /// Argment
+    /// - datetime_vec: 抽出したい日時のベクター
+    ///
+    /// Return
+    /// ブールのベクター
+    pub fn extract_intraday_bool(&self, datetime_vec:&[NaiveDateTime]) -> Vec<bool> {
+        self.extract_sessions_bool(datetime_vec, false)
+    }
+
+    /// Vec<NaiveDatetime>から営業日・営業時間のものをboolとして抽出．
+    /// 日付をまたぐ営業時間は開始日が営業日であるかで判定する
+    /// Argment
+    /// - datetime_vec: 抽出したい日時のベクター
+    ///
+    /// Return
+    /// ブールのベクター
+    pub fn extract_workdays_intraday_bool(&self, datetime_vec:&[NaiveDateTime]) -> Vec<bool> {
+        self.extract_sessions_bool(datetime_vec, true)
+    }
+
+    /// 日時のイテレーターから営業時間のものをboolとして抽出．営業時間の区間は日付の変わるときのみ求める
+    /// Argment
+    /// - datetimes: 抽出したい日時のイテレーター
+    ///
+    /// Return
+    /// ブールのベクター
+    pub fn extract_intraday_bool_iter<I: IntoIterator<Item=NaiveDateTime>>(&self, datetimes: I) -> Vec<bool> {
+        self.map_sessions_iter(datetimes, false, false, |_, _|{true})
+    }
+
+    /// 日時のイテレーターから営業日・営業時間のものをboolとして抽出．営業時間の区間は日付の変わるときのみ求める．
+    /// 日付をまたぐ営業時間は開始日が営業日であるかで判定する
+    /// Argment
+    /// - datetimes: 抽出したい日時のイテレーター
+    ///
+    /// Return
+    /// ブールのベクター
+    pub fn extract_workdays_intraday_bool_iter<I: IntoIterator<Item=NaiveDateTime>>(&self, datetimes: I) -> Vec<bool> {
+        self.map_sessions_iter(datetimes, true, false, |_, _|{true})
+    }
+
+    /// Vec<NaiveDatetime>の各日時に営業日・営業時間のラベルを付ける．
+    /// 取引日は日付をまたぐ営業時間の帰属(`SessionAttribution`)に従う
+    /// Argment
+    /// - datetime_vec: ラベルを付けたい日時のベクター
+    ///
+    /// Return
+    /// ラベルのベクター．営業日・営業時間外の日時はNone
+    pub fn label_workdays_intraday(&self, datetime_vec:&[NaiveDateTime]) -> Vec<Option<IntradayLabel>> {
+        let mut trading_date_cache: Option<(SessionInterval, Option<NaiveDate>)> = None;  // 直前の営業時間の区間の取引日
+        self.map_sessions(datetime_vec, true, None, |datetime, session|{
+            let trading_date = match trading_date_cache {
+                Some((cached_session, trading_date)) if cached_session == *session => trading_date,
+                _ => {
+                    let trading_date = self.session_trading_date(session);
+                    trading_date_cache = Some((*session, trading_date));
+                    trading_date
+                }
+            };
+            trading_date.map(|trading_date|{
+                IntradayLabel {
+                    trading_date,
+                    session_index: session.border_index,
+                    seconds_since_open: (datetime - session.start).num_seconds(),
+                    seconds_until_close: (session.end - datetime).num_seconds()
+                }
+            })
+        })
+    }
+
+    /// ソート済みのVec<NaiveDatetime>を営業日・営業時間の等間隔の足と照合し，欠損・営業時間外の観測・重複を検証する．
+    /// 足は営業時間の開始ごとに揃え，観測はその日時を含む足を埋める．
+    /// 検証する範囲は最初の観測から最後の観測まで
+    /// Argments
+    /// - datetime_vec: 検証したい日時のベクター(昇順)
+    /// - step: 足の間隔(正でない場合は欠損を検証しない)
+    ///
+    /// Return
+    /// 検証の結果．昇順でない場合はエラー
+    pub fn try_validate_series(&self, datetime_vec:&[NaiveDateTime], step: Duration) -> Result<SeriesReport, Error> {
+        check_sorted(datetime_vec)?;
+        let mut report = SeriesReport::default();
+        let (first_datetime, last_datetime) = match (datetime_vec.first(), datetime_vec.last()) {
+            (Some(first_datetime), Some(last_datetime)) => (*first_datetime, *last_datetime),
+            _ => {return Ok(report);}
+        };
+
+        let bool_vec = self.extract_workdays_intraday_bool(datetime_vec);
+        let mut bars = self.generate_intraday_range(first_datetime, last_datetime, step, Label::Left, Closed::Both).peekable();
+        let mut missing_bars: Vec<NaiveDateTime> = Vec::new();  // 観測で埋まっていない足
+
+        for (i, datetime) in datetime_vec.iter().enumerate() {
+            if (i > 0) && (datetime_vec[i-1] == *datetime) {
+                report.duplicates.push(i);
+                continue;
+            }
+            if !bool_vec[i] {
+                report.outside_session.push(i);
+                continue;
+            }
+
+            // 観測までの足を進める．最後の足は観測を含むため埋まっている
+            missing_bars.clear();
+            while let Some(bar) = bars.next_if(|bar|{bar <= datetime}) {
+                missing_bars.push(bar);
+            }
+            if let Some(covered_bar) = missing_bars.pop() {
+                if let Some(gap_start) = missing_bars.first().cloned() {
+                    report.gaps.push(SeriesGap {
+                        start: gap_start,
+                        end: covered_bar,
+                        missing_count: missing_bars.len(),
+                        business_duration: Duration::milliseconds(self.business_millis_before(covered_bar) - self.business_millis_before(gap_start))
+                    });
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    /// ソート済みのVec<NaiveDatetime>を営業日・営業時間の等間隔の足と照合し，欠損・営業時間外の観測・重複を検証する．
+    /// 足は営業時間の開始ごとに揃え，観測はその日時を含む足を埋める．
+    /// 検証する範囲は最初の観測から最後の観測まで
+    /// Argments
+    /// - datetime_vec: 検証したい日時のベクター(昇順)
+    /// - step: 足の間隔(正でない場合は欠損を検証しない)
+    ///
+    /// Return
+    /// 検証の結果(昇順でない場合はパニックする．エラーとして扱う場合はtry_validate_seriesを使う)
+    pub fn validate_series(&self, datetime_vec:&[NaiveDateTime], step: Duration) -> SeriesReport {
+        self.try_validate_series(datetime_vec, step).unwrap()
+    }
+}
 
-use crate::global::{INTRADAY_BORDERS, IMPOSSIBLE_DATE_1};
-use crate::workdays::{get_workdays, Closed};
 
 /// Vec<NaiveDatetime>から営業日のものをboolとして抽出
 /// Argment
 /// - datetime_vec: 抽出したい日時のベクター
-/// 
+///
 /// Return
 /// ブールのベクター
-/// 
+///
 /// # Examples
 /// ~~~~
 /// use chrono::{NaiveDateTime, NaiveDate};
@@ -23,67 +455,23 @@ use crate::workdays::{get_workdays, Closed};
 /// .filter(|(i,_x)|{bool_vec[*i]}).map(|(_i,x)|{x}).collect();
 /// println!("extrated workdays datetime: {:?}", extracted_workdays_datetime);
 /// ~~~~
-/// 
+///
 /// extrated workdays datetime: [2021-01-04T00:00:00, 2021-01-04T01:00:00, 2021-01-04T02:00:00, 2021-01-04T03:00:00, 2021-01-04T04:00:00,
 ///  2021-01-04T05:00:00, 2021-01-04T06:00:00, 2021-01-04T07:00:00, 2021-01-04T08:00:00, 2021-01-04T09:00:00, 2021-01-04T10:00:00,
 ///  2021-01-04T11:00:00, 2021-01-04T12:00:00, 2021-01-04T13:00:00, 2021-01-04T14:00:00, 2021-01-04T15:00:00, 2021-01-04T16:00:00,
 ///  2021-01-04T17:00:00, 2021-01-04T18:00:00, 2021-01-04T19:00:00, 2021-01-04T20:00:00, 2021-01-04T21:00:00, 2021-01-04T22:00:00,
 ///  2021-01-04T23:00:00, 2021-01-05T00:00:00, 2021-01-05T01:00:00, 2021-01-05T02:00:00, 2021-01-05T03:00:00]
-pub fn extract_workdays_bool(datetime_vec:&Vec<NaiveDateTime>) -> Vec<bool> {
-    if datetime_vec.len() == 0 {
-        return vec![];
-    }
-
-    let mut bool_vec = vec![false;datetime_vec.len()];
-    let first_date = datetime_vec.first().unwrap().date();
-    let last_date = datetime_vec.last().unwrap().date();
-
-    let workdays_vec = get_workdays(first_date, last_date, Closed::Both);
-    let mut workdays_iter = workdays_vec.iter();
-
-    let mut one_workday = workdays_iter.next().unwrap_or(&IMPOSSIBLE_DATE_1);
-    let mut now_date = first_date;
-    let mut is_end_today = false;
-
-    // 最初はここで判定
-    if one_workday==&now_date {  // その日が営業日の場合
-        one_workday = workdays_iter.next().unwrap_or(&IMPOSSIBLE_DATE_1);  // onw_workdayをインクリメント
-    } else {  // その日が営業日でない場合
-        is_end_today = true; // その日が終了
-    }
-    
-    for (i, datetime) in datetime_vec.iter().enumerate() {
-        // now_dateのインクリメント
-        let date = datetime.date();
-        if now_date < date { // 日付が変わるとき
-            now_date = date;
-            if one_workday==&now_date {  // その日が営業日の場合
-                is_end_today = false; // フラッグを初期化                
-                one_workday = workdays_iter.next().unwrap_or(&IMPOSSIBLE_DATE_1);  // onw_workdayをインクリメント
-
-            } else {  // その日が営業日でない場合
-                is_end_today = true; // その日が終了
-            }
-
-        }
-
-        if is_end_today {  // その日が終了しているとき
-            continue;
-        }
-
-        // bool_vecの変更
-        bool_vec[i] = true;
-    }
-    return bool_vec;
+pub fn extract_workdays_bool(datetime_vec:&[NaiveDateTime]) -> Vec<bool> {
+    read_work_calendar().extract_workdays_bool(datetime_vec)
 }
 
 /// Vec<NaiveDatetime>から営業時間のものをboolとして抽出
 /// Argment
 /// - datetime_vec: 抽出したい日時のベクター
-/// 
+///
 /// Return
 /// ブールのベクター
-/// 
+///
 /// # Examples
 /// ~~~~
 /// use chrono::{NaiveDateTime, NaiveDate};
@@ -97,77 +485,22 @@ pub fn extract_workdays_bool(datetime_vec:&Vec<NaiveDateTime>) -> Vec<bool> {
 /// .filter(|(i,_x)|{bool_vec[*i]}).map(|(_i,x)|{x}).collect();
 /// println!("extracted intraday datetime: {:?}", extracted_intraday_datetime);
 /// ~~~~
-/// 
+///
 /// extracted intraday datetime: [2021-01-01T09:00:00, 2021-01-01T10:00:00, 2021-01-01T11:00:00, 2021-01-01T13:00:00, 2021-01-01T14:00:00,
 ///  2021-01-02T09:00:00, 2021-01-02T10:00:00, 2021-01-02T11:00:00, 2021-01-02T13:00:00, 2021-01-02T14:00:00, 2021-01-03T09:00:00,
 ///  2021-01-03T10:00:00, 2021-01-03T11:00:00, 2021-01-03T13:00:00, 2021-01-03T14:00:00, 2021-01-04T09:00:00, 2021-01-04T10:00:00,
 ///  2021-01-04T11:00:00, 2021-01-04T13:00:00, 2021-01-04T14:00:00]
-pub fn extract_intraday_bool(datetime_vec:&Vec<NaiveDateTime>) -> Vec<bool> {
-    if datetime_vec.len() == 0 {
-        return vec![];
-    }
-
-    let intraday_borders_vec = INTRADAY_BORDERS.read().unwrap();
-    let mut bool_vec = vec![false;datetime_vec.len()];
-    
-    let mut now_date = datetime_vec.first().unwrap().date();
-    let mut borders_index: usize = 0;
-    let mut is_end_today: bool = false;
-    let mut is_end_intraday: bool = false;
-
-    for (i, datetime) in datetime_vec.iter().enumerate() {
-        // borders_indexのインクリメント, is_end_todayのフラッグを処理
-        let date = datetime.date();
-        let time = datetime.time();
-
-        if date > now_date {  // 日付が変わるとき
-            now_date = date;
-            is_end_today = false;  // フラッグを初期化
-        }
-
-        if is_end_today {  // その日が終了しているとき
-            continue;
-        }
-
-        if intraday_borders_vec[borders_index].end <= time {  // timeが終了境界を越えたとき
-            borders_index += 1;
-            is_end_intraday = true;  // その営業時間が終了
-
-            if borders_index >= intraday_borders_vec.len() {  // borders_indexが境界の数と同じになったとき
-                // その日は終了
-                borders_index = 0;  // borders_indexを初期化
-                is_end_today = true;  // その日が終了
-            }
-
-            if (intraday_borders_vec[borders_index].start <= time) & !is_end_today{// timeか境界内に入った場合
-                is_end_intraday = false;  // フラッグを初期化
-            }
-
-        } else if intraday_borders_vec[borders_index].start <= time{ // timeが境界内の場合
-            is_end_intraday = false;  // フラッグを初期化
-
-        } else if intraday_borders_vec[borders_index].start > time {// timeが境界まえの場合
-            is_end_intraday = true;  // 便宜的に営業時間が終了
-        }
-
-        if is_end_intraday {  // その営業時間が終了しているとき
-            continue;
-        }
-
-        // bool_vecの変更
-        bool_vec[i] = true;
-    }
-
-    return bool_vec;
+pub fn extract_intraday_bool(datetime_vec:&[NaiveDateTime]) -> Vec<bool> {
+    read_work_calendar().extract_intraday_bool(datetime_vec)
 }
 
 /// Vec<NaiveDatetime>から営業日・営業時間のものをboolとして抽出
 /// Argment
 /// - datetime_vec: 抽出したい日時のベクター
-/// 
+///
 /// Return
 /// ブールのベクター
-/// 
+///
 /// # Examples
 /// ~~~~
 /// use chrono::{NaiveDateTime, NaiveDate};
@@ -181,83 +514,167 @@ pub fn extract_intraday_bool(datetime_vec:&Vec<NaiveDateTime>) -> Vec<bool> {
 /// .filter(|(i,_x)|{bool_vec[*i]}).map(|(_i,x)|{x}).collect();
 /// println!("extracted workday intraday datetime: {:?}", extracted_workdays_intraday_datetime);
 /// ~~~~
-/// 
+///
 /// extracted workday intraday datetime: [2021-01-04T09:00:00, 2021-01-04T10:00:00, 2021-01-04T11:00:00, 2021-01-04T13:00:00,
 ///  2021-01-04T14:00:00]
-pub fn extract_workdays_intraday_bool(datetime_vec:&Vec<NaiveDateTime>) -> Vec<bool> {
-    if datetime_vec.len() == 0 {
-        return vec![];
-    }
-
-    let mut bool_vec = vec![false;datetime_vec.len()];
-    let first_date = datetime_vec.first().unwrap().date();
-    let last_date = datetime_vec.last().unwrap().date();
-
-    let workdays_vec = get_workdays(first_date, last_date, Closed::Both);
-    let mut workdays_iter = workdays_vec.iter();
-
-    let mut one_workday = workdays_iter.next().unwrap_or(&IMPOSSIBLE_DATE_1);
-    let mut now_date = first_date;
-
-    let intraday_borders_vec = INTRADAY_BORDERS.read().unwrap();
-    
-    let mut borders_index: usize = 0;
-    let mut is_end_today: bool = false;
-    let mut is_end_intraday: bool = false;
-
-    // 最初はここで判定
-    if one_workday==&now_date {  // その日が営業日の場合
-        one_workday = workdays_iter.next().unwrap_or(&IMPOSSIBLE_DATE_1);  // onw_workdayをインクリメント
-    } else {  // その日が営業日でない場合
-        is_end_today = true; // その日が終了
-    }
-
-    for (i, datetime) in datetime_vec.iter().enumerate() {
-        let date = datetime.date();
-        let time = datetime.time();
+pub fn extract_workdays_intraday_bool(datetime_vec:&[NaiveDateTime]) -> Vec<bool> {
+    read_work_calendar().extract_workdays_intraday_bool(datetime_vec)
+}
 
-        if now_date < date { // 日付が変わるとき
-            now_date = date;
-            if one_workday==&now_date {  // その日が営業日の場合
-                is_end_today = false; // フラッグを初期化                
-                one_workday = workdays_iter.next().unwrap_or(&IMPOSSIBLE_DATE_1);  // onw_workdayをインクリメント
+/// 日時のイテレーターから営業日のものをboolとして抽出
+/// Argment
+/// - datetimes: 抽出したい日時のイテレーター
+///
+/// Return
+/// ブールのベクター
+///
+/// # Examples
+/// ~~~~
+/// use chrono::{NaiveDate, Duration};
+/// use rs_workdays::extract::*;
+/// let start_datetime = NaiveDate::from_ymd(2021,1,1).and_hms(0,0,0);
+/// let bool_vec: Vec<bool> = extract_workdays_bool_iter((0..5).map(|i|{start_datetime + Duration::days(i)}));
+/// assert_eq!(bool_vec, vec![false, false, false, true, true]);
+/// ~~~~
+pub fn extract_workdays_bool_iter<I: IntoIterator<Item=NaiveDateTime>>(datetimes: I) -> Vec<bool> {
+    read_work_calendar().extract_workdays_bool_iter(datetimes)
+}
 
-            } else {  // その日が営業日でない場合
-                is_end_today = true; // その日が終了
-            }
+/// 日時のイテレーターから営業時間のものをboolとして抽出
+/// Argment
+/// - datetimes: 抽出したい日時のイテレーター
+///
+/// Return
+/// ブールのベクター
+pub fn extract_intraday_bool_iter<I: IntoIterator<Item=NaiveDateTime>>(datetimes: I) -> Vec<bool> {
+    read_work_calendar().extract_intraday_bool_iter(datetimes)
+}
 
-        }
+/// 日時のイテレーターから営業日・営業時間のものをboolとして抽出
+/// Argment
+/// - datetimes: 抽出したい日時のイテレーター
+///
+/// Return
+/// ブールのベクター
+pub fn extract_workdays_intraday_bool_iter<I: IntoIterator<Item=NaiveDateTime>>(datetimes: I) -> Vec<bool> {
+    read_work_calendar().extract_workdays_intraday_bool_iter(datetimes)
+}
 
-        if is_end_today {  // その日が終了しているとき
-            continue;
-        }
+/// エポックからの時間のスライスから営業日のものをboolとして抽出
+/// Argments
+/// - timestamps: エポックからの時間(ナイーブな日時のタイムスタンプ)のスライス
+/// - unit: エポックからの時間の単位
+///
+/// Return
+/// ブールのベクター
+pub fn extract_workdays_bool_epoch(timestamps:&[i64], unit: EpochUnit) -> Vec<bool> {
+    read_work_calendar().extract_workdays_bool_epoch(timestamps, unit)
+}
 
-        if intraday_borders_vec[borders_index].end <= time {  // timeが終了境界を越えたとき
-            borders_index += 1;  // borderをすすめる
-            is_end_intraday = true;  // その営業時間が終了
-            if borders_index >= intraday_borders_vec.len() {  // borders_indexが境界の数と同じになったとき
-                // その日は終了
-                borders_index = 0;  // borders_indexを初期化
-                is_end_today = true;  // その日が終了
-            }
+/// エポックからの時間のスライスから営業時間のものをboolとして抽出
+/// Argments
+/// - timestamps: エポックからの時間(ナイーブな日時のタイムスタンプ)のスライス
+/// - unit: エポックからの時間の単位
+///
+/// Return
+/// ブールのベクター
+pub fn extract_intraday_bool_epoch(timestamps:&[i64], unit: EpochUnit) -> Vec<bool> {
+    read_work_calendar().extract_intraday_bool_epoch(timestamps, unit)
+}
 
-            if (intraday_borders_vec[borders_index].start <= time) & !is_end_today{// timeか境界内に入った場合
-                is_end_intraday = false;  // フラッグを初期化
-            }
+/// エポックからの時間のスライスから営業日・営業時間のものをboolとして抽出
+/// Argments
+/// - timestamps: エポックからの時間(ナイーブな日時のタイムスタンプ)のスライス
+/// - unit: エポックからの時間の単位
+///
+/// Return
+/// ブールのベクター
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::extract::*;
+/// let timestamps: Vec<i64> = vec![
+///     NaiveDate::from_ymd(2021,1,4).and_hms(8,0,0).timestamp_millis(),
+///     NaiveDate::from_ymd(2021,1,4).and_hms(9,0,0).timestamp_millis(),
+///     NaiveDate::from_ymd(2021,1,4).and_hms(12,0,0).timestamp_millis(),
+/// ];
+/// let bool_vec: Vec<bool> = extract_workdays_intraday_bool_epoch(&timestamps, EpochUnit::Millisecond);
+/// assert_eq!(bool_vec, vec![false, true, false]);
+/// ~~~~
+pub fn extract_workdays_intraday_bool_epoch(timestamps:&[i64], unit: EpochUnit) -> Vec<bool> {
+    read_work_calendar().extract_workdays_intraday_bool_epoch(timestamps, unit)
+}
 
-        } else if intraday_borders_vec[borders_index].start <= time{ // timeが境界内の場合
-            is_end_intraday = false;  // フラッグを初期化
-            
-        } else if intraday_borders_vec[borders_index].start > time {// timeが境界まえの場合
-            is_end_intraday = true;  // 便宜的に営業時間が終了
-        }
+/// Vec<NaiveDatetime>の各日時に営業日・営業時間のラベルを付ける．
+/// 取引日は日付をまたぐ営業時間の帰属(`SessionAttribution`)に従う
+/// Argment
+/// - datetime_vec: ラベルを付けたい日時のベクター
+///
+/// Return
+/// ラベルのベクター．営業日・営業時間外の日時はNone
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::extract::*;
+/// let datetime_vec = vec![
+///     NaiveDate::from_ymd(2021,1,4).and_hms(8,0,0),
+///     NaiveDate::from_ymd(2021,1,4).and_hms(13,0,0),
+/// ];
+/// let label_vec = label_workdays_intraday(&datetime_vec);
+/// assert_eq!(label_vec, vec![
+///     None,
+///     Some(IntradayLabel {trading_date: NaiveDate::from_ymd(2021,1,4), session_index: 1, seconds_since_open: 1800, seconds_until_close: 7200})
+/// ]);
+/// ~~~~
+pub fn label_workdays_intraday(datetime_vec:&[NaiveDateTime]) -> Vec<Option<IntradayLabel>> {
+    read_work_calendar().label_workdays_intraday(datetime_vec)
+}
 
-        if is_end_intraday {  // その営業時間が終了しているとき
-            continue;
-        }
+/// ソート済みのVec<NaiveDatetime>を営業日・営業時間の等間隔の足と照合し，欠損・営業時間外の観測・重複を検証する．
+/// 足は営業時間の開始ごとに揃え，観測はその日時を含む足を埋める．
+/// 検証する範囲は最初の観測から最後の観測まで
+/// Argments
+/// - datetime_vec: 検証したい日時のベクター(昇順)
+/// - step: 足の間隔(正でない場合は欠損を検証しない)
+///
+/// Return
+/// 検証の結果(昇順でない場合はパニックする．エラーとして扱う場合はtry_validate_seriesを使う)
+///
+/// # Examples
+/// ~~~~
+/// use chrono::{NaiveDate, Duration};
+/// use rs_workdays::extract::*;
+/// let datetime_vec = vec![
+///     NaiveDate::from_ymd(2021,1,4).and_hms(11,0,0),
+///     NaiveDate::from_ymd(2021,1,4).and_hms(11,10,0),
+///     NaiveDate::from_ymd(2021,1,4).and_hms(11,10,0),
+///     NaiveDate::from_ymd(2021,1,4).and_hms(12,0,0),
+///     NaiveDate::from_ymd(2021,1,4).and_hms(12,50,0),
+/// ];
+/// let report = validate_series(&datetime_vec, Duration::minutes(10));
+/// assert_eq!(report.duplicates, vec![2]);
+/// assert_eq!(report.outside_session, vec![3]);
+/// // 11:20, 12:30, 12:40の足(昼休みを除いて30分)が欠損
+/// assert_eq!(report.gaps.len(), 1);
+/// assert_eq!(report.gaps[0].start, NaiveDate::from_ymd(2021,1,4).and_hms(11,20,0));
+/// assert_eq!(report.gaps[0].end, NaiveDate::from_ymd(2021,1,4).and_hms(12,50,0));
+/// assert_eq!(report.gaps[0].missing_count, 3);
+/// assert_eq!(report.gaps[0].business_duration, Duration::minutes(30));
+/// ~~~~
+pub fn validate_series(datetime_vec:&[NaiveDateTime], step: Duration) -> SeriesReport {
+    read_work_calendar().validate_series(datetime_vec, step)
+}
 
-        // bool_vecの変更
-        bool_vec[i] = true;
-    }
-    return bool_vec;
+/// ソート済みのVec<NaiveDatetime>を営業日・営業時間の等間隔の足と照合し，欠損・営業時間外の観測・重複を検証する．
+/// 昇順でない場合はエラーを返す
+/// Argments
+/// - datetime_vec: 検証したい日時のベクター(昇順)
+/// - step: 足の間隔(正でない場合は欠損を検証しない)
+///
+/// Return
+/// 検証の結果．デフォルトのカレンダーのロックがポイズンされている場合もエラー
+pub fn try_validate_series(datetime_vec:&[NaiveDateTime], step: Duration) -> Result<SeriesReport, Error> {
+    try_read_work_calendar()?.try_validate_series(datetime_vec, step)
 }
@@ -0,0 +1,117 @@
+use arrow2::array::{Array, BooleanArray, PrimitiveArray};
+use arrow2::datatypes::{DataType, TimeUnit};
+
+use crate::calendar::WorkCalendar;
+use crate::error::Error;
+use crate::extract::{EpochUnit, EpochMasker, ExtractTarget};
+use crate::global::try_read_work_calendar;
+
+
+/// タイムゾーンの無いタイムスタンプの配列の値と単位を取得する
+/// Argment
+/// - array: タイムスタンプの配列
+///
+/// Return
+/// 値の配列とエポックからの時間の単位．タイムスタンプでない場合・タイムゾーンのある場合はエラー
+fn timestamp_values(array: &dyn Array) -> Result<(&PrimitiveArray<i64>, EpochUnit), Error> {
+    let unsupported_error = ||{Error::UnsupportedDataType{data_type_str: format!("{:?}", array.data_type())}};
+    let unit = match array.data_type() {
+        DataType::Timestamp(time_unit, None) => match time_unit {
+            TimeUnit::Second => EpochUnit::Second,
+            TimeUnit::Millisecond => EpochUnit::Millisecond,
+            TimeUnit::Microsecond => EpochUnit::Microsecond,
+            TimeUnit::Nanosecond => EpochUnit::Nanosecond
+        },
+        _ => {return Err(unsupported_error());}
+    };
+    let values = array.as_any().downcast_ref::<PrimitiveArray<i64>>().ok_or_else(unsupported_error)?;
+    Ok((values, unit))
+}
+
+impl WorkCalendar {
+    /// タイムスタンプの配列から抽出の対象のものをBooleanArrayとして抽出．nullはnullのまま
+    /// Argments
+    /// - array: タイムスタンプの配列
+    /// - target: 抽出する対象
+    fn extract_arrow(&self, array: &dyn Array, target: ExtractTarget) -> Result<BooleanArray, Error> {
+        let (values, unit) = timestamp_values(array)?;
+        let mut masker = EpochMasker::new(self, target, unit);
+        Ok(values.iter().map(|timestamp|{timestamp.map(|x|{masker.contains(*x)})}).collect())
+    }
+
+    /// タイムゾーンの無いタイムスタンプの配列から営業日のものをBooleanArrayとして抽出
+    /// Argment
+    /// - array: タイムスタンプの配列
+    ///
+    /// Return
+    /// ブールの配列(nullはnull)．タイムスタンプでない場合・タイムゾーンのある場合はエラー
+    pub fn extract_workdays_bool_arrow(&self, array: &dyn Array) -> Result<BooleanArray, Error> {
+        self.extract_arrow(array, ExtractTarget::Workdays)
+    }
+
+    /// タイムゾーンの無いタイムスタンプの配列から営業時間のものをBooleanArrayとして抽出
+    /// Argment
+    /// - array: タイムスタンプの配列
+    ///
+    /// Return
+    /// ブールの配列(nullはnull)．タイムスタンプでない場合・タイムゾーンのある場合はエラー
+    pub fn extract_intraday_bool_arrow(&self, array: &dyn Array) -> Result<BooleanArray, Error> {
+        self.extract_arrow(array, ExtractTarget::Intraday)
+    }
+
+    /// タイムゾーンの無いタイムスタンプの配列から営業日・営業時間のものをBooleanArrayとして抽出
+    /// Argment
+    /// - array: タイムスタンプの配列
+    ///
+    /// Return
+    /// ブールの配列(nullはnull)．タイムスタンプでない場合・タイムゾーンのある場合はエラー
+    pub fn extract_workdays_intraday_bool_arrow(&self, array: &dyn Array) -> Result<BooleanArray, Error> {
+        self.extract_arrow(array, ExtractTarget::WorkdaysIntraday)
+    }
+}
+
+
+/// タイムゾーンの無いタイムスタンプの配列から営業日のものをBooleanArrayとして抽出
+/// Argment
+/// - array: タイムスタンプの配列
+///
+/// Return
+/// ブールの配列(nullはnull)．タイムスタンプでない場合・タイムゾーンのある場合・デフォルトのカレンダーのロックがポイズンされている場合はエラー
+pub fn extract_workdays_bool_arrow(array: &dyn Array) -> Result<BooleanArray, Error> {
+    try_read_work_calendar()?.extract_workdays_bool_arrow(array)
+}
+
+/// タイムゾーンの無いタイムスタンプの配列から営業時間のものをBooleanArrayとして抽出
+/// Argment
+/// - array: タイムスタンプの配列
+///
+/// Return
+/// ブールの配列(nullはnull)．タイムスタンプでない場合・タイムゾーンのある場合・デフォルトのカレンダーのロックがポイズンされている場合はエラー
+pub fn extract_intraday_bool_arrow(array: &dyn Array) -> Result<BooleanArray, Error> {
+    try_read_work_calendar()?.extract_intraday_bool_arrow(array)
+}
+
+/// タイムゾーンの無いタイムスタンプの配列から営業日・営業時間のものをBooleanArrayとして抽出
+/// Argment
+/// - array: タイムスタンプの配列
+///
+/// Return
+/// ブールの配列(nullはnull)．タイムスタンプでない場合・タイムゾーンのある場合・デフォルトのカレンダーのロックがポイズンされている場合はエラー
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use arrow2::array::{BooleanArray, PrimitiveArray};
+/// use arrow2::datatypes::{DataType, TimeUnit};
+/// use rs_workdays::extract_arrow::*;
+/// let array = PrimitiveArray::<i64>::from(vec![
+///     Some(NaiveDate::from_ymd(2021,1,4).and_hms(9,0,0).timestamp()),
+///     None,
+///     Some(NaiveDate::from_ymd(2021,1,4).and_hms(12,0,0).timestamp()),
+/// ]).to(DataType::Timestamp(TimeUnit::Second, None));
+/// let mask = extract_workdays_intraday_bool_arrow(&array).unwrap();
+/// assert_eq!(mask, BooleanArray::from(vec![Some(true), None, Some(false)]));
+/// ~~~~
+pub fn extract_workdays_intraday_bool_arrow(array: &dyn Array) -> Result<BooleanArray, Error> {
+    try_read_work_calendar()?.extract_workdays_intraday_bool_arrow(array)
+}
@@ -0,0 +1,127 @@
+use chrono::NaiveDateTime;
+use rayon::prelude::*;
+
+use crate::calendar::WorkCalendar;
+use crate::extract::check_sorted;
+use crate::global::read_work_calendar;
+
+
+/// 一つのチャンクの最小の要素数
+const MIN_CHUNK_LEN: usize = 1 << 14;
+
+/// 昇順の日時のスライスを日付の境界で分割する．同じ日付の日時は同じチャンクとなる
+/// Argments
+/// - datetime_vec: 分割したい日時のスライス(昇順)
+/// - chunk_len: チャンクのおおよその要素数
+///
+/// Return
+/// チャンクのベクター
+fn split_at_day_boundaries(datetime_vec:&[NaiveDateTime], chunk_len: usize) -> Vec<&[NaiveDateTime]> {
+    let mut chunks: Vec<&[NaiveDateTime]> = Vec::new();
+    let mut rest = datetime_vec;
+    while rest.len() > chunk_len {
+        let last_date = rest[chunk_len - 1].date();
+        let split_index = chunk_len + rest[chunk_len..].partition_point(|datetime|{datetime.date() == last_date});  // 同じ日付の終わりまで延ばす
+        let (chunk, new_rest) = rest.split_at(split_index);
+        chunks.push(chunk);
+        rest = new_rest;
+    }
+    if !rest.is_empty() {
+        chunks.push(rest);
+    }
+    chunks
+}
+
+impl WorkCalendar {
+    /// 日時のスライスを並列に抽出する．昇順の場合は日付の境界で分割したチャンクごとに逐次の抽出を行い，
+    /// 昇順でない場合は要素ごとに判定する
+    /// Argments
+    /// - datetime_vec: 抽出したい日時のスライス
+    /// - extract_fn: チャンクの逐次の抽出
+    /// - check_fn: 要素ごとの判定
+    fn par_extract<E, C>(&self, datetime_vec:&[NaiveDateTime], extract_fn: E, check_fn: C) -> Vec<bool>
+    where E: Fn(&WorkCalendar, &[NaiveDateTime]) -> Vec<bool> + Sync, C: Fn(&WorkCalendar, NaiveDateTime) -> bool + Sync
+    {
+        if check_sorted(datetime_vec).is_err() {  // 昇順でない場合
+            return datetime_vec.par_iter().map(|datetime|{check_fn(self, *datetime)}).collect();
+        }
+        let chunk_len = (datetime_vec.len() / (rayon::current_num_threads() * 4)).max(MIN_CHUNK_LEN);
+        split_at_day_boundaries(datetime_vec, chunk_len).par_iter()
+            .map(|chunk|{extract_fn(self, chunk)}).collect::<Vec<Vec<bool>>>().concat()
+    }
+
+    /// Vec<NaiveDatetime>から営業日のものをboolとして並列に抽出．結果は`extract_workdays_bool`と同じ
+    /// Argment
+    /// - datetime_vec: 抽出したい日時のベクター
+    ///
+    /// Return
+    /// ブールのベクター
+    pub fn par_extract_workdays_bool(&self, datetime_vec:&[NaiveDateTime]) -> Vec<bool> {
+        self.par_extract(datetime_vec, WorkCalendar::extract_workdays_bool, |calendar, datetime|{calendar.check_workday(datetime.date())})
+    }
+
+    /// Vec<NaiveDatetime>から営業時間のものをboolとして並列に抽出．結果は`extract_intraday_bool`と同じ
+    /// Argment
+    /// - datetime_vec: 抽出したい日時のベクター
+    ///
+    /// Return
+    /// ブールのベクター
+    pub fn par_extract_intraday_bool(&self, datetime_vec:&[NaiveDateTime]) -> Vec<bool> {
+        self.par_extract(datetime_vec, WorkCalendar::extract_intraday_bool, |calendar, datetime|{
+            calendar.check_intraday_borders().is_ok() && calendar.session_containing(datetime, false).is_some()
+        })
+    }
+
+    /// Vec<NaiveDatetime>から営業日・営業時間のものをboolとして並列に抽出．結果は`extract_workdays_intraday_bool`と同じ
+    /// Argment
+    /// - datetime_vec: 抽出したい日時のベクター
+    ///
+    /// Return
+    /// ブールのベクター
+    pub fn par_extract_workdays_intraday_bool(&self, datetime_vec:&[NaiveDateTime]) -> Vec<bool> {
+        self.par_extract(datetime_vec, WorkCalendar::extract_workdays_intraday_bool, |calendar, datetime|{
+            calendar.check_intraday_borders().is_ok() && calendar.session_containing(datetime, true).is_some()
+        })
+    }
+}
+
+
+/// Vec<NaiveDatetime>から営業日のものをboolとして並列に抽出．結果は`extract_workdays_bool`と同じ
+/// Argment
+/// - datetime_vec: 抽出したい日時のベクター
+///
+/// Return
+/// ブールのベクター
+pub fn par_extract_workdays_bool(datetime_vec:&[NaiveDateTime]) -> Vec<bool> {
+    read_work_calendar().par_extract_workdays_bool(datetime_vec)
+}
+
+/// Vec<NaiveDatetime>から営業時間のものをboolとして並列に抽出．結果は`extract_intraday_bool`と同じ
+/// Argment
+/// - datetime_vec: 抽出したい日時のベクター
+///
+/// Return
+/// ブールのベクター
+pub fn par_extract_intraday_bool(datetime_vec:&[NaiveDateTime]) -> Vec<bool> {
+    read_work_calendar().par_extract_intraday_bool(datetime_vec)
+}
+
+/// Vec<NaiveDatetime>から営業日・営業時間のものをboolとして並列に抽出．結果は`extract_workdays_intraday_bool`と同じ
+/// Argment
+/// - datetime_vec: 抽出したい日時のベクター
+///
+/// Return
+/// ブールのベクター
+///
+/// # Examples
+/// ~~~~
+/// use chrono::{NaiveDateTime, NaiveDate, Duration};
+/// use rs_workdays::extract::extract_workdays_intraday_bool;
+/// use rs_workdays::extract_par::par_extract_workdays_intraday_bool;
+/// let start_datetime = NaiveDate::from_ymd(2021,1,1).and_hms(0,0,0);
+/// let datetime_vec: Vec<NaiveDateTime> = (0..100_000).map(|i|{start_datetime + Duration::seconds(i * 30)}).collect();
+/// assert_eq!(par_extract_workdays_intraday_bool(&datetime_vec), extract_workdays_intraday_bool(&datetime_vec));
+/// ~~~~
+pub fn par_extract_workdays_intraday_bool(datetime_vec:&[NaiveDateTime]) -> Vec<bool> {
+    read_work_calendar().par_extract_workdays_intraday_bool(datetime_vec)
+}
@@ -0,0 +1,104 @@
+use polars_core::prelude::{BooleanChunked, DataType, IntoSeries, NewChunkedArray, Series, TimeUnit};
+
+use crate::calendar::WorkCalendar;
+use crate::error::Error;
+use crate::extract::{EpochUnit, EpochMasker, ExtractTarget};
+use crate::global::try_read_work_calendar;
+
+
+impl WorkCalendar {
+    /// タイムゾーンの無いDatetimeのSeriesから抽出の対象のものをブールのSeriesとして抽出．nullはnullのまま
+    /// Argments
+    /// - series: DatetimeのSeries
+    /// - target: 抽出する対象
+    fn extract_series(&self, series: &Series, target: ExtractTarget) -> Result<Series, Error> {
+        let unit = match series.dtype() {
+            DataType::Datetime(time_unit, None) => match time_unit {
+                TimeUnit::Milliseconds => EpochUnit::Millisecond,
+                TimeUnit::Microseconds => EpochUnit::Microsecond,
+                TimeUnit::Nanoseconds => EpochUnit::Nanosecond
+            },
+            data_type => {return Err(Error::UnsupportedDataType{data_type_str: data_type.to_string()});}
+        };
+        let values = series.datetime().map_err(|err|{Error::UnsupportedDataType{data_type_str: err.to_string()}})?;
+        let mut masker = EpochMasker::new(self, target, unit);
+        let mask = BooleanChunked::from_iter_options(series.name(), values.into_iter().map(|timestamp|{timestamp.map(|x|{masker.contains(x)})}));
+        Ok(mask.into_series())
+    }
+
+    /// タイムゾーンの無いDatetimeのSeriesから営業日のものをブールのSeriesとして抽出
+    /// Argment
+    /// - series: DatetimeのSeries
+    ///
+    /// Return
+    /// 同じ名前のブールのSeries(nullはnull)．Datetimeでない場合・タイムゾーンのある場合はエラー
+    pub fn extract_workdays_bool_series(&self, series: &Series) -> Result<Series, Error> {
+        self.extract_series(series, ExtractTarget::Workdays)
+    }
+
+    /// タイムゾーンの無いDatetimeのSeriesから営業時間のものをブールのSeriesとして抽出
+    /// Argment
+    /// - series: DatetimeのSeries
+    ///
+    /// Return
+    /// 同じ名前のブールのSeries(nullはnull)．Datetimeでない場合・タイムゾーンのある場合はエラー
+    pub fn extract_intraday_bool_series(&self, series: &Series) -> Result<Series, Error> {
+        self.extract_series(series, ExtractTarget::Intraday)
+    }
+
+    /// タイムゾーンの無いDatetimeのSeriesから営業日・営業時間のものをブールのSeriesとして抽出
+    /// Argment
+    /// - series: DatetimeのSeries
+    ///
+    /// Return
+    /// 同じ名前のブールのSeries(nullはnull)．Datetimeでない場合・タイムゾーンのある場合はエラー
+    pub fn extract_workdays_intraday_bool_series(&self, series: &Series) -> Result<Series, Error> {
+        self.extract_series(series, ExtractTarget::WorkdaysIntraday)
+    }
+}
+
+
+/// タイムゾーンの無いDatetimeのSeriesから営業日のものをブールのSeriesとして抽出
+/// Argment
+/// - series: DatetimeのSeries
+///
+/// Return
+/// 同じ名前のブールのSeries(nullはnull)．Datetimeでない場合・タイムゾーンのある場合・デフォルトのカレンダーのロックがポイズンされている場合はエラー
+pub fn extract_workdays_bool_series(series: &Series) -> Result<Series, Error> {
+    try_read_work_calendar()?.extract_workdays_bool_series(series)
+}
+
+/// タイムゾーンの無いDatetimeのSeriesから営業時間のものをブールのSeriesとして抽出
+/// Argment
+/// - series: DatetimeのSeries
+///
+/// Return
+/// 同じ名前のブールのSeries(nullはnull)．Datetimeでない場合・タイムゾーンのある場合・デフォルトのカレンダーのロックがポイズンされている場合はエラー
+pub fn extract_intraday_bool_series(series: &Series) -> Result<Series, Error> {
+    try_read_work_calendar()?.extract_intraday_bool_series(series)
+}
+
+/// タイムゾーンの無いDatetimeのSeriesから営業日・営業時間のものをブールのSeriesとして抽出
+/// Argment
+/// - series: DatetimeのSeries
+///
+/// Return
+/// 同じ名前のブールのSeries(nullはnull)．Datetimeでない場合・タイムゾーンのある場合・デフォルトのカレンダーのロックがポイズンされている場合はエラー
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use polars_core::prelude::*;
+/// use rs_workdays::extract_polars::*;
+/// let timestamps: Vec<i64> = vec![
+///     NaiveDate::from_ymd(2021,1,4).and_hms(9,0,0).timestamp_millis(),
+///     NaiveDate::from_ymd(2021,1,4).and_hms(12,0,0).timestamp_millis(),
+/// ];
+/// let series = Int64Chunked::from_slice("time", &timestamps).into_datetime(TimeUnit::Milliseconds, None).into_series();
+/// let mask = extract_workdays_intraday_bool_series(&series).unwrap();
+/// assert_eq!(mask.name(), "time");
+/// assert_eq!(mask.bool().unwrap().into_iter().collect::<Vec<_>>(), vec![Some(true), Some(false)]);
+/// ~~~~
+pub fn extract_workdays_intraday_bool_series(series: &Series) -> Result<Series, Error> {
+    try_read_work_calendar()?.extract_workdays_intraday_bool_series(series)
+}
@@ -1,115 +1,46 @@
-use std::sync::RwLock;
-use std::path::Path;
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard, PoisonError};
 use std::collections::HashSet;
-use chrono::{NaiveDate, Datelike, Weekday, NaiveTime};
+use chrono::{NaiveDate, Weekday};
 use once_cell::sync::Lazy;
 
-#[cfg(feature = "source")]
-use anyhow::Context;
-
 use crate::error::Error;
-
-/// 営業時間の境界
-/// Fields
-/// - start: 開始時間
-/// - end: 終了時間
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct TimeBorder {
-    pub start: NaiveTime,
-    pub end: NaiveTime
-}
-
-
-
-/// csvを読み込んで祝日のVecにする
-/// Argment
-/// - path_str: csvファイルのパス
-#[cfg(feature = "source")]
-fn read_csv<P:AsRef<Path>>(source_path: P) -> Result<Vec<NaiveDate>, Error> {
-    let source_path: &Path = source_path.as_ref();
-    let source_path_str = source_path.to_str().context("cannot convert source path to string")?;
-
-    let parse_from_str = NaiveDate::parse_from_str;
-    let mut holiday_vec: Vec<NaiveDate> = Vec::new();
-
-    let mut rdr = csv::ReaderBuilder::new().has_headers(false).from_path(source_path)
-        .map_err(|_|{Error::ReadCsvError{path_str: source_path_str.to_string()}})?;
-    for result in rdr.records() {
-        let record = result
-            .map_err(|_|{Error::ReadCsvError{path_str: source_path_str.to_string()}})?;
-        holiday_vec.push(
-            parse_from_str(&record[0], "%Y-%m-%d")
-            .map_err(|_|{Error::ParseDateError{date_str: record[0].into()}})?
-        );
-    }
-    Ok(holiday_vec)
-}
-
-
-#[cfg(not(feature = "source"))]
-fn read_csv<P:AsRef<Path>>(_: P) -> Result<Vec<NaiveDate>, Error> {
-    Ok([].to_vec())
-}
+use crate::calendar::{WorkCalendar, HolidayCoverage, CoveragePolicy, SessionAttribution, SnapPolicy, Session};
+use crate::holiday_info::HolidayInfo;
+pub use crate::calendar::TimeBorder;
 
 // グローバル変数
-// 祝日データ
-pub static RANGE_HOLIDAYS: Lazy<RwLock<Vec<NaiveDate>>> = Lazy::new(|| {
-    let start_year = 2016_i32;
-    let end_year = 2025_i32;
-
-    let all_holidays_vec = read_csv("source/holidays.csv").unwrap_or([].to_vec());
-    
-    let range_holidays_set: HashSet<NaiveDate> = all_holidays_vec.into_iter().filter(|x| {
-        (start_year <= x.year()) & (end_year >= x.year())
-    }).collect();  // setにして重複を削除
-    let mut range_holidays_vec: Vec<NaiveDate> = range_holidays_set.into_iter().collect();
-    range_holidays_vec.sort();
-    RwLock::new(range_holidays_vec)
-});
-// 休日曜日
-pub static HOLIDAY_WEEKDAYS: Lazy<RwLock<HashSet<Weekday>>> = Lazy::new(|| {
-    RwLock::new([Weekday::Sat, Weekday::Sun].iter().cloned().collect())
-});
-// 営業時間の境界
-pub static INTRADAY_BORDERS: Lazy<RwLock<Vec<TimeBorder>>> = Lazy::new(|| {
-    RwLock::new([
-        TimeBorder {start: NaiveTime::from_hms(9,0,0), end: NaiveTime::from_hms(11,30,0)},
-        TimeBorder {start: NaiveTime::from_hms(12,30,0), end: NaiveTime::from_hms(15,0,0)},
-    ].iter().cloned().collect())
+// デフォルトのカレンダー(祝日データ・休日曜日・営業時間の境界)
+pub static WORK_CALENDAR: Lazy<RwLock<WorkCalendar>> = Lazy::new(|| {
+    RwLock::new(WorkCalendar::default())
 });
 // どれとも重ならない日
 pub static IMPOSSIBLE_DATE_1: Lazy<NaiveDate> = Lazy::new(||{ NaiveDate::from_ymd(2100,1,1) });  // どれとも重ならないような日にち
 pub static IMPOSSIBLE_DATE_2: Lazy<NaiveDate> = Lazy::new(||{ NaiveDate::from_ymd(2101,1,1) });  // どれとも重ならないような日にち
 
+/// デフォルトのカレンダーの読み込み用のロックを取得する．
+/// カレンダーの更新は計算後の値の代入のみで行うため，ロックがポイズンされていてもそのまま利用する．
+pub(crate) fn read_work_calendar() -> RwLockReadGuard<'static, WorkCalendar> {
+    WORK_CALENDAR.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// デフォルトのカレンダーの書き込み用のロックを取得する．
+pub(crate) fn write_work_calendar() -> RwLockWriteGuard<'static, WorkCalendar> {
+    WORK_CALENDAR.write().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// デフォルトのカレンダーの読み込み用のロックを取得する．ロックがポイズンされている場合はエラーを返す．
+pub(crate) fn try_read_work_calendar() -> Result<RwLockReadGuard<'static, WorkCalendar>, Error> {
+    WORK_CALENDAR.read().map_err(|_|{Error::PoisonedLock})
+}
+
 
 /// csvを読み込んで利用できる祝日の更新をする
 /// Argments
 /// - path_str_vec: csvのパス
 /// - start_year: 利用する開始年(その年の1月1日から)
 /// - end_year: 利用する終了年(その年の12月31日まで)
-pub fn set_holidays_csvs(path_str_vec: &Vec<String>, start_year: i32, end_year: i32) -> Result<(), Error>{
-    let mut range_holidays_vec = RANGE_HOLIDAYS.write().unwrap();
-    // 削除
-    range_holidays_vec.clear();
-
-    assert!(range_holidays_vec.is_empty());
-
-    // 重複が無いようにsetを用意
-    let mut range_holidays_set: HashSet<NaiveDate> = HashSet::new(); 
-
-    for path_str in path_str_vec.iter() {
-        let file_holiday_vec = read_csv(path_str)?;
-    
-        let made_range_holidays_vec: Vec<NaiveDate> = file_holiday_vec.into_iter().filter(|holiday| {
-            (start_year <= holiday.year()) & (end_year >= holiday.year())
-        }).collect();
-        made_range_holidays_vec.into_iter().for_each(|range_holiday|{ range_holidays_set.insert(range_holiday); });
-    }
-
-    // 代入
-    range_holidays_set.into_iter().for_each(|range_holiday|{ range_holidays_vec.push(range_holiday) });
-    range_holidays_vec.sort();
-    Ok(())
+pub fn set_holidays_csvs(path_str_vec: &[String], start_year: i32, end_year: i32) -> Result<(), Error>{
+    write_work_calendar().set_holidays_csvs(path_str_vec, start_year, end_year)
 }
 
 /// 祝日のvecから祝日の更新をする
@@ -117,24 +48,17 @@ pub fn set_holidays_csvs(path_str_vec: &Vec<String>, start_year: i32, end_year:
 /// - holidays_vec: 休日のベクター
 /// - start_year: 利用する開始年(その年の1月1日から)
 /// - end_year: 利用する終了年(その年の12月31日まで)
-pub fn set_range_holidays(holidays_vec: &Vec<NaiveDate>, start_year: i32, end_year: i32) {
-    let mut range_holidays_vec = RANGE_HOLIDAYS.write().unwrap();
-    // 削除
-    range_holidays_vec.clear();
-    assert!(range_holidays_vec.is_empty());
-
-    // 重複が無いようにsetを用意
-    let mut range_holidays_set: HashSet<NaiveDate> = HashSet::new();
-
-    let made_range_holidays_vec: Vec<NaiveDate> = holidays_vec.iter().cloned().filter(|holiday| {
-        (start_year <= holiday.year()) & (end_year >= holiday.year())
-    }).collect();
-
-    made_range_holidays_vec.into_iter().for_each(|range_holiday|{ range_holidays_set.insert(range_holiday); });
+pub fn set_range_holidays(holidays_vec: &[NaiveDate], start_year: i32, end_year: i32) {
+    write_work_calendar().set_range_holidays(holidays_vec, start_year, end_year);
+}
 
-    // 代入
-    range_holidays_set.into_iter().for_each(|range_holiday|{ range_holidays_vec.push(range_holiday) });
-    range_holidays_vec.sort();
+/// 名前をもつ祝日のvecから祝日の更新をする
+/// Argments
+/// - holiday_infos: 休日の情報のベクター
+/// - start_year: 利用する開始年(その年の1月1日から)
+/// - end_year: 利用する終了年(その年の12月31日まで)
+pub fn set_range_holiday_infos(holiday_infos: &[HolidayInfo], start_year: i32, end_year: i32) {
+    write_work_calendar().set_range_holiday_infos(holiday_infos, start_year, end_year);
 }
 
 /// 祝日のvecから祝日の追加をする
@@ -142,81 +66,183 @@ pub fn set_range_holidays(holidays_vec: &Vec<NaiveDate>, start_year: i32, end_ye
 /// - holidays_vec: 休日のベクター
 /// - start_year: 利用する開始年(その年の1月1日から)
 /// - end_year: 利用する終了年(その年の12月31日まで)
-pub fn add_range_holidays(holidays_vec: &Vec<NaiveDate>, start_year: i32, end_year: i32) {
-    let mut range_holidays_vec = RANGE_HOLIDAYS.write().unwrap();
-
-    // 重複が無いようにsetを用意
-    let mut range_holidays_set: HashSet<NaiveDate> = range_holidays_vec.iter().cloned().collect();
-
-    let made_range_holidays_vec: Vec<NaiveDate> = holidays_vec.iter().cloned().filter(|holiday| {
-        (start_year <= holiday.year()) & (end_year >= holiday.year())
-    }).collect();
-
-    made_range_holidays_vec.into_iter().for_each(|range_holiday|{ range_holidays_set.insert(range_holiday); });
-
-    // 削除
-    range_holidays_vec.clear();
-    assert!(range_holidays_vec.is_empty());
+pub fn add_range_holidays(holidays_vec: &[NaiveDate], start_year: i32, end_year: i32) {
+    write_work_calendar().add_range_holidays(holidays_vec, start_year, end_year);
+}
 
-    // 代入
-    range_holidays_set.into_iter().for_each(|range_holiday|{ range_holidays_vec.push(range_holiday) });
-    range_holidays_vec.sort();
+/// 名前をもつ祝日のvecから祝日の追加をする
+/// Argments
+/// - holiday_infos: 休日の情報のベクター
+/// - start_year: 利用する開始年(その年の1月1日から)
+/// - end_year: 利用する終了年(その年の12月31日まで)
+pub fn add_range_holiday_infos(holiday_infos: &[HolidayInfo], start_year: i32, end_year: i32) {
+    write_work_calendar().add_range_holiday_infos(holiday_infos, start_year, end_year);
 }
 
 /// 休日曜日の更新
 /// Argment
 /// - new_holiday_weekdays: 休日曜日のセット
 pub fn set_holiday_weekdays(new_holiday_weekdays: &HashSet<Weekday>) {
-    let mut holiday_weekdays = HOLIDAY_WEEKDAYS.write().unwrap();
-    // 削除
-    holiday_weekdays.clear();
-
-    assert!(holiday_weekdays.is_empty());
-
-    // 代入
-    for holiday_weekday in new_holiday_weekdays.iter().cloned() {
-        holiday_weekdays.insert(holiday_weekday);
-    }
+    write_work_calendar().set_holiday_weekdays(new_holiday_weekdays);
 }
 
 /// 営業時間境界の更新
 /// Argment
 /// - new_intrada_borders: 営業時間境界のベクター
-pub fn set_intraday_borders(new_intraday_borders: &Vec<TimeBorder>) {
-    let mut intraday_borders = INTRADAY_BORDERS.write().unwrap();
+pub fn set_intraday_borders(new_intraday_borders: &[TimeBorder]) {
+    write_work_calendar().set_intraday_borders(new_intraday_borders);
+}
 
-    // 削除
-    intraday_borders.clear();
+/// 曜日ごとの営業時間境界の更新．曜日を指定しない適用期間の指定より優先する
+/// Argments
+/// - weekday: 曜日
+/// - new_intraday_borders: その曜日の営業時間境界のベクター(空の場合はその曜日の営業時間は無い)
+pub fn set_weekday_intraday_borders(weekday: Weekday, new_intraday_borders: &[TimeBorder]) {
+    write_work_calendar().set_weekday_intraday_borders(weekday, new_intraday_borders);
+}
 
-    assert!(intraday_borders.is_empty());
+/// 日付ごとの営業時間境界の更新(大発会・大納会など)．曜日・適用期間の指定より優先する
+/// Argments
+/// - select_date: 日付
+/// - new_intraday_borders: その日の営業時間境界のベクター(空の場合はその日の営業時間は無い)
+pub fn set_date_intraday_borders(select_date: NaiveDate, new_intraday_borders: &[TimeBorder]) {
+    write_work_calendar().set_date_intraday_borders(select_date, new_intraday_borders);
+}
+
+/// 適用期間の営業時間境界の追加(取引時間の変更など)．期間が重なる場合は後から追加したものを優先する
+/// Argments
+/// - start_date: 適用開始日(含む)
+/// - end_date: 適用終了日(含む)
+/// - new_intraday_borders: その期間の営業時間境界のベクター
+pub fn add_range_intraday_borders(start_date: NaiveDate, end_date: NaiveDate, new_intraday_borders: &[TimeBorder]) {
+    write_work_calendar().add_range_intraday_borders(start_date, end_date, new_intraday_borders);
+}
+
+/// 適用期間のうち指定した曜日の営業時間境界の追加(短縮取引の曜日の取引時間の変更など)．
+/// 曜日の指定・曜日を指定しない適用期間の指定より優先し，期間が重なる場合は後から追加したものを優先する
+/// Argments
+/// - start_date: 適用開始日(含む)
+/// - end_date: 適用終了日(含む)
+/// - weekday: 曜日
+/// - new_intraday_borders: その期間のその曜日の営業時間境界のベクター(空の場合はその曜日の営業時間は無い)
+pub fn add_range_weekday_intraday_borders(start_date: NaiveDate, end_date: NaiveDate, weekday: Weekday, new_intraday_borders: &[TimeBorder]) {
+    write_work_calendar().add_range_weekday_intraday_borders(start_date, end_date, weekday, new_intraday_borders);
+}
+
+/// 曜日・日付・適用期間ごとの営業時間境界の削除
+pub fn clear_intraday_schedule() {
+    write_work_calendar().clear_intraday_schedule();
+}
+
+/// 日付をまたぐ営業時間の属する取引日の規則の更新
+/// Argment
+/// - new_session_attribution: 取引日の規則
+pub fn set_session_attribution(new_session_attribution: SessionAttribution) {
+    write_work_calendar().set_session_attribution(new_session_attribution);
+}
+
+/// 名前付きの取引セッションの更新．空の場合は各日の営業時間境界から連続取引のセッションを作る
+/// Argment
+/// - new_sessions: 取引セッションのスライス
+pub fn set_sessions(new_sessions: &[Session]) {
+    write_work_calendar().set_sessions(new_sessions);
+}
+
+/// 祝日データの範囲外の日付を扱う場合の方針の更新
+/// Argment
+/// - new_coverage_policy: 範囲外の日付を扱う場合の方針
+pub fn set_coverage_policy(new_coverage_policy: CoveragePolicy) {
+    write_work_calendar().set_coverage_policy(new_coverage_policy);
+}
+
+/// 方針がWarnで祝日データの範囲外の日付を扱った場合に呼ばれるコールバックの更新
+/// Argment
+/// - handler: 範囲外であることを示すエラーを受け取るコールバック
+pub fn set_coverage_warning_handler<F: Fn(&Error) + Send + Sync + 'static>(handler: F) {
+    write_work_calendar().set_coverage_warning_handler(handler);
+}
 
-    // 代入
-    for one_intraday_border in new_intraday_borders.iter().cloned(){
-        intraday_borders.push(one_intraday_border);
-    }
-    intraday_borders.sort();
+/// 営業時間外の日時と営業時間の序数の対応の方針の更新
+/// Argment
+/// - new_snap_policy: 営業時間外の日時の方針
+pub fn set_snap_policy(new_snap_policy: SnapPolicy) {
+    write_work_calendar().set_snap_policy(new_snap_policy);
+}
+
+/// デフォルトのカレンダーの置き換え
+/// Argment
+/// - new_work_calendar: 新しいカレンダー
+pub fn set_work_calendar(new_work_calendar: WorkCalendar) {
+    *write_work_calendar() = new_work_calendar;
 }
 
 /// 祝日データの取得
 /// Return
 /// - 祝日のvec
 pub fn get_range_holidays() -> Vec<NaiveDate> {
-    let range_holidays_vec = RANGE_HOLIDAYS.read().unwrap();
-    range_holidays_vec.iter().cloned().collect::<Vec<NaiveDate>>()
+    read_work_calendar().range_holidays().to_vec()
 }
 
 /// 休日曜日データの取得
 /// Return
 /// - 休日曜日のset
 pub fn get_holiday_weekdays() -> HashSet<Weekday> {
-    let holiday_weekdays = HOLIDAY_WEEKDAYS.read().unwrap();
-    holiday_weekdays.iter().cloned().collect::<HashSet<Weekday>>()
+    read_work_calendar().holiday_weekdays().clone()
 }
 
 /// 営業時間境界の取得
 /// Return
 /// - 営業時間境界のvec
 pub fn get_intraday_borders() -> Vec<TimeBorder> {
-    let borders = INTRADAY_BORDERS.read().unwrap();
-    borders.iter().cloned().collect::<Vec<TimeBorder>>()
+    read_work_calendar().intraday_borders().to_vec()
+}
+
+/// 名前付きの取引セッションの取得
+/// Return
+/// - 取引セッションのvec(開始時間順)
+pub fn get_sessions() -> Vec<Session> {
+    read_work_calendar().sessions().to_vec()
+}
+
+/// select_dateの営業時間境界の取得．曜日・日付・適用期間の指定を考慮する
+/// Argment
+/// - select_date: 指定する日
+///
+/// Return
+/// - 営業時間境界のvec
+pub fn get_intraday_borders_on(select_date: NaiveDate) -> Vec<TimeBorder> {
+    read_work_calendar().intraday_borders_on(select_date).to_vec()
+}
+
+/// 祝日データが網羅する年の範囲の取得
+/// Return
+/// - 祝日データの範囲．祝日データが無い場合はNone
+pub fn get_coverage() -> Option<HolidayCoverage> {
+    read_work_calendar().coverage()
+}
+
+/// select_dateが祝日データの範囲内であるか方針に従って確認する
+/// Argment
+/// - select_date: 指定する日
+///
+/// Return
+/// 方針がErrorで範囲外の場合はエラー．Warnの場合はset_coverage_warning_handlerで設定したコールバックに警告を渡してOk
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::global::*;
+/// let coverage = get_coverage().unwrap();
+/// assert_eq!((coverage.start_year, coverage.end_year), (2016, 2022));  // csvの祝日は2022年まで
+/// assert!(check_coverage(NaiveDate::from_ymd(2021, 1, 1)).is_ok());
+/// ~~~~
+pub fn check_coverage(select_date: NaiveDate) -> Result<(), Error> {
+    try_read_work_calendar()?.check_coverage(select_date)
+}
+
+/// デフォルトのカレンダーの取得
+/// Return
+/// - カレンダーのコピー
+pub fn get_work_calendar() -> WorkCalendar {
+    read_work_calendar().clone()
 }
\ No newline at end of file
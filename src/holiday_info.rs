@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use chrono::{NaiveDate, Datelike, Weekday};
+
+use crate::calendar::WorkCalendar;
+use crate::global::read_work_calendar;
+
+/// 振替休日・国民の休日の名前(内閣府のデータ)
+pub static SUBSTITUTE_HOLIDAY_NAME: &str = "休日";
+
+/// 休日の種類
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum HolidayKind {
+    /// 国民の祝日
+    National,
+    /// 振替休日
+    Substitute,
+    /// 国民の休日(祝日に挟まれた日)
+    Citizens,
+    /// その他の休日(名前の無い休日・独自の休日など)
+    Other
+}
+
+/// 名前と種類をもつ休日
+/// Fields
+/// - date: 日付
+/// - name: 休日の名前
+/// - kind: 休日の種類
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HolidayInfo {
+    pub date: NaiveDate,
+    pub name: String,
+    pub kind: HolidayKind
+}
+
+impl HolidayInfo {
+    /// 名前の無い休日を作成
+    /// Argment
+    /// - date: 日付
+    pub fn unnamed(date: NaiveDate) -> Self {
+        HolidayInfo {date, name: String::new(), kind: HolidayKind::Other}
+    }
+}
+
+/// 日付と名前の組(内閣府のデータの形式)から休日の種類を推定してHolidayInfoにする．
+/// "休日"は連続する休日をさかのぼって日曜日の祝日があれば振替休日，そうでなければ国民の休日とする．
+/// Argment
+/// - holidays_with_names: 日付と名前のベクター
+pub fn make_holiday_infos(holidays_with_names: Vec<(NaiveDate, String)>) -> Vec<HolidayInfo> {
+    let names_map: HashMap<NaiveDate, String> = holidays_with_names.iter().cloned().collect();
+
+    holidays_with_names.into_iter().map(|(date, name)|{
+        let kind = if name.is_empty() {
+            HolidayKind::Other
+        } else if name == SUBSTITUTE_HOLIDAY_NAME {
+            // 直前の連続する休日に日曜日の祝日があるか
+            let mut one_day = date.pred();
+            let mut is_substitute = false;
+            while let Some(one_name) = names_map.get(&one_day) {
+                if (one_day.weekday() == Weekday::Sun) & (one_name != SUBSTITUTE_HOLIDAY_NAME) {
+                    is_substitute = true;
+                    break;
+                }
+                one_day = one_day.pred();
+            }
+            if is_substitute {HolidayKind::Substitute} else {HolidayKind::Citizens}
+        } else {
+            HolidayKind::National
+        };
+        HolidayInfo {date, name, kind}
+    }).collect()
+}
+
+
+impl WorkCalendar {
+    /// select_dateの休日の情報を取得
+    /// Argment
+    /// - select_date: 指定する日
+    ///
+    /// Return
+    /// 休日の情報(祝日として登録されていない場合はNone)
+    pub fn get_holiday_info(&self, select_date: NaiveDate) -> Option<&HolidayInfo> {
+        self.holiday_infos.get(&select_date)
+    }
+
+    /// select_dateの休日の名前を取得
+    /// Argment
+    /// - select_date: 指定する日
+    ///
+    /// Return
+    /// 休日の名前(祝日として登録されていないか名前が無い場合はNone)
+    pub fn get_holiday_name(&self, select_date: NaiveDate) -> Option<&str> {
+        self.get_holiday_info(select_date)
+            .map(|holiday_info|{holiday_info.name.as_str()})
+            .filter(|name|{!name.is_empty()})
+    }
+
+    /// start_dateからend_dateまで(両端を含む)の休日を名前とともに取得
+    /// Argments
+    /// - start_date: 開始日
+    /// - end_date: 終了日
+    ///
+    /// Return
+    /// 休日の情報のベクター
+    pub fn get_holidays_with_names(&self, start_date: NaiveDate, end_date: NaiveDate) -> Vec<HolidayInfo> {
+        if start_date > end_date {
+            return Vec::new();
+        }
+        self.holiday_infos.range(start_date..=end_date).map(|(_, holiday_info)|{holiday_info.clone()}).collect()
+    }
+}
+
+
+/// select_dateの休日の情報を取得
+/// Argment
+/// - select_date: 指定する日
+///
+/// Return
+/// 休日の情報(祝日として登録されていない場合はNone)
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::holiday_info::*;
+/// let holiday_info = get_holiday_info(NaiveDate::from_ymd(2021, 8, 9)).unwrap();
+/// assert_eq!(holiday_info.kind, HolidayKind::Substitute);
+/// ~~~~
+pub fn get_holiday_info(select_date: NaiveDate) -> Option<HolidayInfo> {
+    read_work_calendar().get_holiday_info(select_date).cloned()
+}
+
+/// select_dateの休日の名前を取得
+/// Argment
+/// - select_date: 指定する日
+///
+/// Return
+/// 休日の名前(祝日として登録されていないか名前が無い場合はNone)
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::holiday_info::*;
+/// assert_eq!(get_holiday_name(NaiveDate::from_ymd(2021, 1, 1)), Some("元日".to_string()));
+/// assert_eq!(get_holiday_name(NaiveDate::from_ymd(2021, 1, 4)), None);
+/// ~~~~
+pub fn get_holiday_name(select_date: NaiveDate) -> Option<String> {
+    read_work_calendar().get_holiday_name(select_date).map(|name|{name.to_string()})
+}
+
+/// start_dateからend_dateまで(両端を含む)の休日を名前とともに取得
+/// Argments
+/// - start_date: 開始日
+/// - end_date: 終了日
+///
+/// Return
+/// 休日の情報のベクター
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::holiday_info::*;
+/// let holidays = get_holidays_with_names(NaiveDate::from_ymd(2021, 5, 1), NaiveDate::from_ymd(2021, 5, 31));
+/// let names: Vec<String> = holidays.into_iter().map(|x|{x.name}).collect();
+/// assert_eq!(names, vec!["憲法記念日", "みどりの日", "こどもの日"]);
+/// ~~~~
+pub fn get_holidays_with_names(start_date: NaiveDate, end_date: NaiveDate) -> Vec<HolidayInfo> {
+    read_work_calendar().get_holidays_with_names(start_date, end_date)
+}
@@ -1,16 +1,575 @@
-use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Duration};
+use chrono::{NaiveDate, NaiveDateTime, Duration};
 
-use crate::global::{INTRADAY_BORDERS, TimeBorder};
-use crate::workdays::{check_workday, get_next_workday, get_previous_workday, get_workdays, Closed};
+use crate::calendar::{WorkCalendar, TimeBorder, SessionAttribution, CoveragePolicy};
+use crate::error::Error;
+use crate::global::{read_work_calendar, try_read_work_calendar};
+use crate::workdays::Closed;
 
+/// 営業時間境界の合計のDuration
+fn borders_duration(intraday_borders: &[TimeBorder]) -> Duration {
+    let mut one_workday_delta_time = Duration::zero();
+
+    for one_borders in intraday_borders.iter() {
+        one_workday_delta_time = one_workday_delta_time + one_borders.duration();
+    }
+    one_workday_delta_time
+}
+
+/// 営業時間の境界の種類．その日の営業時間境界(`TimeBorder`，ソート済み)のインデックスをもち，
+/// 例えば東京証券取引所では0が前場，1が後場を示す
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BorderKind {
+    /// 営業時間の開始
+    Start(usize),
+    /// 営業時間の終了
+    End(usize),
+    /// 営業時間内
+    Intra(usize)
+}
+
+impl BorderKind {
+    /// 営業時間境界のインデックス
+    pub fn index(&self) -> usize {
+        match self {
+            BorderKind::Start(border_index) | BorderKind::End(border_index) | BorderKind::Intra(border_index) => *border_index
+        }
+    }
+
+    /// 従来の状態を示す文字列('border_start'・'border_end'・'border_intra')
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BorderKind::Start(_) => "border_start",
+            BorderKind::End(_) => "border_end",
+            BorderKind::Intra(_) => "border_intra"
+        }
+    }
+}
+
+/// 日時で表した営業時間の区間(開始を含み，終了を含まない)
+/// Fields
+/// - date: 営業時間境界を指定した日(区間の開始日)
+/// - border_index: その日の営業時間境界のインデックス
+/// - start: 開始日時
+/// - end: 終了日時
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct SessionInterval {
+    pub(crate) date: NaiveDate,
+    pub(crate) border_index: usize,
+    pub(crate) start: NaiveDateTime,
+    pub(crate) end: NaiveDateTime
+}
+
+impl SessionInterval {
+    /// select_datetimeが区間内であるかどうか
+    pub(crate) fn contains(&self, select_datetime: NaiveDateTime) -> bool {
+        (self.start <= select_datetime) & (select_datetime < self.end)
+    }
+}
+
+impl WorkCalendar {
+    /// select_dateの営業時間一日分のDuration
+    fn one_workday_duration(&self, select_date: NaiveDate) -> Duration {
+        borders_duration(self.intraday_borders_on(select_date))
+    }
+
+    /// 営業時間の境界が存在するか確認する
+    pub(crate) fn check_intraday_borders(&self) -> Result<(), Error> {
+        if self.intraday_borders().is_empty() & !self.intraday_schedule.has_borders() {
+            Err(Error::EmptyIntradayBorders)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// select_dateに開始する営業時間の区間(開始順)
+    /// Argments
+    /// - select_date: 指定する日
+    /// - is_workdays_only: 営業日のみとするかどうか
+    pub(crate) fn sessions_on_date(&self, select_date: NaiveDate, is_workdays_only: bool) -> impl Iterator<Item=SessionInterval> + '_ {
+        let intraday_borders_vec: &[TimeBorder] = if !is_workdays_only || self.check_workday(select_date) {
+            self.intraday_borders_on(select_date)
+        } else {
+            &[]
+        };
+        intraday_borders_vec.iter().enumerate().filter_map(move |(border_index, border)|{
+            let end_date = if border.is_overnight() {select_date.succ_opt()?} else {select_date};
+            Some(SessionInterval {
+                date: select_date,
+                border_index,
+                start: select_date.and_time(border.start),
+                end: end_date.and_time(border.end)
+            })
+        })
+    }
+
+    /// select_datetimeを含む営業時間の区間(前日に開始した日をまたぐ区間も含む)
+    /// Argments
+    /// - select_datetime: 指定する日時
+    /// - is_workdays_only: 営業日のみとするかどうか
+    pub(crate) fn session_containing(&self, select_datetime: NaiveDateTime, is_workdays_only: bool) -> Option<SessionInterval> {
+        let select_date = select_datetime.date();
+        select_date.pred_opt().into_iter().chain(Some(select_date))
+            .flat_map(|one_date|{self.sessions_on_date(one_date, is_workdays_only)})
+            .find(|session|{session.contains(select_datetime)})
+    }
+
+    /// 営業時間が無い日が続く場合に探索を打ち切る日．
+    /// 祝日・営業時間の指定の範囲より外側は休日曜日と曜日ごとの営業時間のみで決まるため，その範囲を一週間越えれば十分である．
+    pub(crate) fn session_search_limits(&self, select_date: NaiveDate) -> (NaiveDate, NaiveDate) {
+        let mut first_date = select_date;
+        let mut last_date = select_date;
+        if let (Some(first_holiday), Some(last_holiday)) = (self.holidays.first(), self.holidays.last()) {
+            first_date = first_date.min(*first_holiday);
+            last_date = last_date.max(*last_holiday);
+        }
+        if let Some((first_boundary, last_boundary)) = self.intraday_schedule.boundary_dates() {
+            first_date = first_date.min(first_boundary);
+            last_date = last_date.max(last_boundary);
+        }
+        (
+            first_date.checked_sub_signed(Duration::weeks(1)).unwrap_or(NaiveDate::MIN),
+            last_date.checked_add_signed(Duration::weeks(1)).unwrap_or(NaiveDate::MAX)
+        )
+    }
+
+    /// select_dateの次の営業時間のある営業日を取得(select_dateは含まない)
+    pub(crate) fn try_get_next_session_day(&self, select_date: NaiveDate) -> Result<NaiveDate, Error> {
+        let (_, last_date) = self.session_search_limits(select_date);
+        let mut one_day = select_date;
+        loop {
+            one_day = self.try_get_next_workday_unchecked(one_day)?;
+            if !self.intraday_borders_on(one_day).is_empty() {
+                return Ok(one_day);
+            }
+            if one_day > last_date {
+                return Err(Error::EmptyIntradayBorders);
+            }
+        }
+    }
+
+    /// select_dateの前の営業時間のある営業日を取得(select_dateは含まない)
+    fn try_get_previous_session_day(&self, select_date: NaiveDate) -> Result<NaiveDate, Error> {
+        let (first_date, _) = self.session_search_limits(select_date);
+        let mut one_day = select_date;
+        loop {
+            one_day = self.try_get_previous_workday_unchecked(one_day)?;
+            if !self.intraday_borders_on(one_day).is_empty() {
+                return Ok(one_day);
+            }
+            if one_day < first_date {
+                return Err(Error::EmptyIntradayBorders);
+            }
+        }
+    }
+
+    /// select_datetimeより後に終了する最初の営業日の営業時間の区間(select_datetimeを含む区間を含む)
+    pub(crate) fn try_get_next_session(&self, select_datetime: NaiveDateTime) -> Result<SessionInterval, Error> {
+        let select_date = select_datetime.date();
+        let near_session = select_date.pred_opt().into_iter().chain(Some(select_date))
+            .flat_map(|one_date|{self.sessions_on_date(one_date, true)})
+            .find(|session|{session.end > select_datetime});
+        match near_session {
+            Some(session) => Ok(session),
+            None => {
+                let out_date = self.try_get_next_session_day(select_date)?;
+                Ok(self.sessions_on_date(out_date, true).next().unwrap())
+            }
+        }
+    }
+
+    /// select_datetimeより前に開始する最後の営業日の営業時間の区間(select_datetimeを含む区間を含む)
+    pub(crate) fn try_get_previous_session(&self, select_datetime: NaiveDateTime) -> Result<SessionInterval, Error> {
+        let select_date = select_datetime.date();
+        let near_session = self.sessions_on_date(select_date, true)
+            .filter(|session|{session.start < select_datetime}).last();
+        match near_session {
+            Some(session) => Ok(session),
+            None => {
+                let out_date = self.try_get_previous_session_day(select_date)?;
+                Ok(self.sessions_on_date(out_date, true).last().unwrap())
+            }
+        }
+    }
+
+    /// sessionの次の営業日の営業時間の区間
+    pub(crate) fn try_get_session_after(&self, session: &SessionInterval) -> Result<SessionInterval, Error> {
+        match self.sessions_on_date(session.date, true).nth(session.border_index + 1) {
+            Some(next_session) => Ok(next_session),
+            None => {
+                let out_date = self.try_get_next_session_day(session.date)?;
+                Ok(self.sessions_on_date(out_date, true).next().unwrap())
+            }
+        }
+    }
+
+    /// sessionの前の営業日の営業時間の区間
+    pub(crate) fn try_get_session_before(&self, session: &SessionInterval) -> Result<SessionInterval, Error> {
+        if session.border_index > 0 {
+            if let Some(previous_session) = self.sessions_on_date(session.date, true).nth(session.border_index - 1) {
+                return Ok(previous_session);
+            }
+        }
+        let out_date = self.try_get_previous_session_day(session.date)?;
+        Ok(self.sessions_on_date(out_date, true).last().unwrap())
+    }
+
+    /// select_datetimeが営業日・営業時間内であるかどうかを判定．
+    /// 日付をまたぐ営業時間は開始日が営業日であるかで判定する
+    /// Argment
+    /// - select_datetime: 指定する日時
+    ///
+    /// Return
+    /// 営業日・営業時間内であるかどうか
+    pub fn check_workday_intraday(&self, select_datetime: NaiveDateTime) -> bool {
+        self.session_containing(select_datetime, true).is_some()
+    }
+
+    /// select_datetimeを含む営業時間の属する取引日を取得する．
+    /// 日付をまたぐ営業時間の取引日はカレンダーの取引日の規則(`SessionAttribution`)に従う
+    /// Argment
+    /// - select_datetime: 指定する日時
+    ///
+    /// Return
+    /// 取引日．営業日・営業時間外の場合はNone
+    pub fn get_trading_date(&self, select_datetime: NaiveDateTime) -> Option<NaiveDate> {
+        let session = self.session_containing(select_datetime, true)?;
+        self.session_trading_date(&session)
+    }
+
+    /// 営業時間の区間の属する取引日
+    pub(crate) fn session_trading_date(&self, session: &SessionInterval) -> Option<NaiveDate> {
+        match self.session_attribution {
+            SessionAttribution::NextWorkday if session.end.date() > session.date => {
+                self.try_get_next_workday_unchecked(session.date).ok()
+            },
+            _ => Some(session.date)
+        }
+    }
+
+    /// 次の営業日・営業時間内のdatetimeをその境界の種類とともにcoverage_policyに従って取得
+    pub(crate) fn _get_next_border(&self, select_datetime: NaiveDateTime, coverage_policy: CoveragePolicy) -> Result<(NaiveDateTime, BorderKind), Error> {
+        self.check_intraday_borders()?;
+        self._check_coverage(select_datetime.date(), coverage_policy)?;
+        let session = self.try_get_next_session(select_datetime)?;
+
+        let out_tuple = if session.start <= select_datetime {  // 営業日・営業時間の場合
+            (session.end, BorderKind::End(session.border_index))
+        } else {  // 営業時間でない場合
+            (session.start, BorderKind::Start(session.border_index))
+        };
+        self._check_coverage(out_tuple.0.date(), coverage_policy)?;
+        Ok(out_tuple)
+    }
 
-/// select_datetimeが営業日・営業時間内であるかどうかを判定  
+    /// 次の営業日・営業時間内のdatetimeをその境界の種類とともに取得
+    /// Argment
+    /// - select_datetime: 指定する日時
+    ///
+    /// Returns
+    /// - out_datetime: 次の営業日・営業時間内のdatetime
+    /// - 境界の種類(`BorderKind::Start`・`BorderKind::End`)
+    ///
+    /// 営業時間の境界が空の場合・営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラー
+    pub fn try_get_next_border(&self, select_datetime: NaiveDateTime) -> Result<(NaiveDateTime, BorderKind), Error> {
+        self._get_next_border(select_datetime, self.coverage_policy)
+    }
+
+    /// 次の営業日・営業時間内のdatetimeをその境界の種類とともに取得
+    /// Argment
+    /// - select_datetime: 指定する日時
+    ///
+    /// Returns
+    /// - out_datetime: 次の営業日・営業時間内のdatetime
+    /// - 境界の種類(`BorderKind::Start`・`BorderKind::End`)
+    pub fn get_next_border(&self, select_datetime: NaiveDateTime) -> (NaiveDateTime, BorderKind) {
+        self._get_next_border(select_datetime, CoveragePolicy::Ignore).unwrap()
+    }
+
+    /// 前の営業日・営業時間内のdatetimeをその境界の種類とともにcoverage_policyに従って取得
+    pub(crate) fn _get_previous_border(&self, select_datetime: NaiveDateTime, force_is_end:bool, coverage_policy: CoveragePolicy) -> Result<(NaiveDateTime, BorderKind), Error> {
+        self.check_intraday_borders()?;
+        self._check_coverage(select_datetime.date(), coverage_policy)?;
+        let session = self.try_get_previous_session(select_datetime)?;
+
+        let out_tuple = if select_datetime < session.end {  // 営業時間であり，開始境界でない場合
+            (session.start, BorderKind::Start(session.border_index))
+        } else if force_is_end & (select_datetime == session.end) {  // 終了境界で前の開始境界に行くのを強制する
+            (session.start, BorderKind::Start(session.border_index))
+        } else {
+            (session.end, BorderKind::End(session.border_index))
+        };
+        self._check_coverage(out_tuple.0.date(), coverage_policy)?;
+        Ok(out_tuple)
+    }
+
+    /// 前の営業日・営業時間内のdatetimeをその境界の種類とともに取得
+    /// Argment
+    /// - select_datetime: 指定する日時
+    /// - force_is_end: 終了境界の場合に前の開始境界を返すかどうか
+    ///
+    /// Returns
+    /// - out_datetime: 前の営業日・営業時間内のdatetime
+    /// - 境界の種類(`BorderKind::Start`・`BorderKind::End`)
+    ///
+    /// 営業時間の境界が空の場合・営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラー
+    pub fn try_get_previous_border(&self, select_datetime: NaiveDateTime, force_is_end:bool) -> Result<(NaiveDateTime, BorderKind), Error> {
+        self._get_previous_border(select_datetime, force_is_end, self.coverage_policy)
+    }
+
+    /// 前の営業日・営業時間内のdatetimeをその境界の種類とともに取得
+    /// Argment
+    /// - select_datetime: 指定する日時
+    /// - force_is_end: 終了境界の場合に前の開始境界を返すかどうか
+    ///
+    /// Returns
+    /// - out_datetime: 前の営業日・営業時間内のdatetime
+    /// - 境界の種類(`BorderKind::Start`・`BorderKind::End`)
+    pub fn get_previous_border(&self, select_datetime: NaiveDateTime, force_is_end:bool) -> (NaiveDateTime, BorderKind) {
+        self._get_previous_border(select_datetime, force_is_end, CoveragePolicy::Ignore).unwrap()
+    }
+
+    /// 最近の営業日・営業時間内のdatetimeをその境界の種類とともにcoverage_policyに従って取得
+    pub(crate) fn _get_near_border(&self, select_datetime: NaiveDateTime, is_after:bool, coverage_policy: CoveragePolicy) -> Result<(NaiveDateTime, BorderKind), Error> {
+        if let Some(session) = self.session_containing(select_datetime, true) {
+            Ok((select_datetime, BorderKind::Intra(session.border_index)))
+        } else if is_after {
+            self._get_next_border(select_datetime, coverage_policy)
+        } else {
+            self._get_previous_border(select_datetime, false, coverage_policy)
+        }
+    }
+
+    /// 最近の営業日・営業時間内のdatetimeをその境界の種類とともに取得．select_datetimeが営業日・営業時間内の場合そのまま返る．
+    /// Argments
+    /// - select_datetime: 指定する日時
+    /// - is_after: 後ろを探索するかどうか
+    ///
+    /// Returns
+    /// - out_datetime: 最近の営業日・営業時間内のdatetime
+    /// - 境界の種類(`BorderKind::Intra`・`BorderKind::Start`・`BorderKind::End`)
+    ///
+    /// 営業時間の境界が空の場合・営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラー
+    pub fn try_get_near_border(&self, select_datetime: NaiveDateTime, is_after:bool) -> Result<(NaiveDateTime, BorderKind), Error> {
+        self._get_near_border(select_datetime, is_after, self.coverage_policy)
+    }
+
+    /// 最近の営業日・営業時間内のdatetimeをその境界の種類とともに取得．select_datetimeが営業日・営業時間内の場合そのまま返る．
+    /// Argments
+    /// - select_datetime: 指定する日時
+    /// - is_after: 後ろを探索するかどうか
+    ///
+    /// Returns
+    /// - out_datetime: 最近の営業日・営業時間内のdatetime
+    /// - 境界の種類(`BorderKind::Intra`・`BorderKind::Start`・`BorderKind::End`)
+    pub fn get_near_border(&self, select_datetime: NaiveDateTime, is_after:bool) -> (NaiveDateTime, BorderKind) {
+        self._get_near_border(select_datetime, is_after, CoveragePolicy::Ignore).unwrap()
+    }
+
+    /// 次の営業日・営業時間内のdatetimeをその状態を示す文字列('border_start'・'border_end')とともに取得
+    #[deprecated(note="use `try_get_next_border` which returns `BorderKind`")]
+    pub fn try_get_next_border_workday_intraday(&self, select_datetime: NaiveDateTime) -> Result<(NaiveDateTime, &'static str), Error> {
+        self.try_get_next_border(select_datetime).map(|(out_datetime, border_kind)|{(out_datetime, border_kind.as_str())})
+    }
+
+    /// 次の営業日・営業時間内のdatetimeをその状態を示す文字列('border_start'・'border_end')とともに取得
+    #[deprecated(note="use `get_next_border` which returns `BorderKind`")]
+    pub fn get_next_border_workday_intraday(&self, select_datetime: NaiveDateTime) -> (NaiveDateTime, &'static str) {
+        let (out_datetime, border_kind) = self.get_next_border(select_datetime);
+        (out_datetime, border_kind.as_str())
+    }
+
+    /// 前の営業日・営業時間内のdatetimeをその状態を示す文字列('border_start'・'border_end')とともに取得
+    #[deprecated(note="use `try_get_previous_border` which returns `BorderKind`")]
+    pub fn try_get_previous_border_workday_intraday(&self, select_datetime: NaiveDateTime, force_is_end:bool) -> Result<(NaiveDateTime, &'static str), Error> {
+        self.try_get_previous_border(select_datetime, force_is_end).map(|(out_datetime, border_kind)|{(out_datetime, border_kind.as_str())})
+    }
+
+    /// 前の営業日・営業時間内のdatetimeをその状態を示す文字列('border_start'・'border_end')とともに取得
+    #[deprecated(note="use `get_previous_border` which returns `BorderKind`")]
+    pub fn get_previous_border_workday_intraday(&self, select_datetime: NaiveDateTime, force_is_end:bool) -> (NaiveDateTime, &'static str) {
+        let (out_datetime, border_kind) = self.get_previous_border(select_datetime, force_is_end);
+        (out_datetime, border_kind.as_str())
+    }
+
+    /// 最近の営業日・営業時間内のdatetimeをその状態を示す文字列('border_intra'・'border_start'・'border_end')とともに取得
+    #[deprecated(note="use `try_get_near_border` which returns `BorderKind`")]
+    pub fn try_get_near_workday_intraday(&self, select_datetime: NaiveDateTime, is_after:bool) -> Result<(NaiveDateTime, &'static str), Error> {
+        self.try_get_near_border(select_datetime, is_after).map(|(out_datetime, border_kind)|{(out_datetime, border_kind.as_str())})
+    }
+
+    /// 最近の営業日・営業時間内のdatetimeをその状態を示す文字列('border_intra'・'border_start'・'border_end')とともに取得
+    #[deprecated(note="use `get_near_border` which returns `BorderKind`")]
+    pub fn get_near_workday_intraday(&self, select_datetime: NaiveDateTime, is_after:bool) -> (NaiveDateTime, &'static str) {
+        let (out_datetime, border_kind) = self.get_near_border(select_datetime, is_after);
+        (out_datetime, border_kind.as_str())
+    }
+
+    /// 営業日・営業時間を考慮しDateTimeを加算する．
+    /// Argments
+    /// - select_datetime: 指定する日時
+    /// - dela_time: 加算するDuration(>0)
+    ///
+    /// Return
+    /// 加算された日時
+    fn _add_positive_workday_intraday_datetime(&self, select_datetime: NaiveDateTime, delta_time: Duration, coverage_policy: CoveragePolicy) -> Result<NaiveDateTime, Error> {
+        let mut all_delta_time = delta_time;
+        let mut session = self.try_get_next_session(select_datetime)?;
+        let mut session_start = session.start.max(select_datetime);
+
+        loop {
+            let delta_session = session.end - session_start;
+            if all_delta_time < delta_session {  // 残りがその営業時間内の場合
+                let out_datetime = session_start + all_delta_time;
+                self._check_coverage(out_datetime.date(), coverage_policy)?;
+                return Ok(out_datetime);
+            }
+
+            let next_session = self.try_get_session_after(&session)?;
+            if all_delta_time == delta_session {  // 残りがその営業時間と同じ場合は次の営業時間の開始
+                self._check_coverage(next_session.start.date(), coverage_policy)?;
+                return Ok(next_session.start);
+            }
+            all_delta_time = all_delta_time - delta_session;  // 営業時間分を減らす
+            session = next_session;
+            session_start = session.start;
+        }
+    }
+
+    /// 営業日・営業時間を考慮しDateTimeを減算する．
+    /// Argments
+    /// - select_datetime: 指定する日時
+    /// - dela_time: 加算するDuration>0
+    ///
+    /// Return
+    /// 減算された日時
+    fn _sub_positive_workday_intraday_datetime(&self, select_datetime: NaiveDateTime, delta_time: Duration, coverage_policy: CoveragePolicy) -> Result<NaiveDateTime, Error> {
+        let mut all_delta_time = delta_time;
+        let mut session = self.try_get_previous_session(select_datetime)?;
+        let mut session_end = session.end.min(select_datetime);
+
+        loop {
+            let delta_session = session_end - session.start;
+            if all_delta_time <= delta_session {  // 残りがその営業時間内以下の場合
+                let out_datetime = session_end - all_delta_time;
+                self._check_coverage(out_datetime.date(), coverage_policy)?;
+                return Ok(out_datetime);
+            }
+            all_delta_time = all_delta_time - delta_session;  // 営業時間分を減らす
+            session = self.try_get_session_before(&session)?;
+            session_end = session.end;
+        }
+    }
+
+    /// 営業日・営業時間を考慮しDateTimeをcoverage_policyに従って加算する
+    pub(crate) fn _add_workday_intraday_datetime(&self, select_datetime: NaiveDateTime, delta_time: Duration, coverage_policy: CoveragePolicy) -> Result<NaiveDateTime, Error> {
+        self.check_intraday_borders()?;
+        self._check_coverage(select_datetime.date(), coverage_policy)?;
+        if delta_time.num_milliseconds() > 0 {
+            self._add_positive_workday_intraday_datetime(select_datetime, delta_time, coverage_policy)
+        } else if delta_time.num_milliseconds() < 0 {
+            self._sub_positive_workday_intraday_datetime(select_datetime, - delta_time, coverage_policy)
+        } else {  // mmsec以下は無視
+            Ok(select_datetime)
+        }
+    }
+
+    /// 営業日・営業時間を考慮しDateTimeを加算する．
+    /// Argments
+    /// - select_datetime: 指定する日時
+    /// - dela_time: 加算するDuration
+    ///
+    /// Return
+    /// 加算された日時
+    ///
+    /// 営業時間の境界が空の場合・営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラー
+    pub fn try_add_workday_intraday_datetime(&self, select_datetime: NaiveDateTime, delta_time: Duration) -> Result<NaiveDateTime, Error> {
+        self._add_workday_intraday_datetime(select_datetime, delta_time, self.coverage_policy)
+    }
+
+    /// 営業日・営業時間を考慮しDateTimeを加算する．
+    /// Argments
+    /// - select_datetime: 指定する日時
+    /// - dela_time: 加算するDuration
+    ///
+    /// Return
+    /// 加算された日時
+    pub fn add_workday_intraday_datetime(&self, select_datetime: NaiveDateTime, delta_time: Duration) -> NaiveDateTime {
+        self._add_workday_intraday_datetime(select_datetime, delta_time, CoveragePolicy::Ignore).unwrap()
+    }
+
+    /// start_datetimeからend_datetimeの営業日・営業時間を取得
+    /// Argments
+    /// - start_datetime: 開始日時
+    /// - end_datetime: 終了日時
+    ///
+    /// Return
+    /// 営業日・営業時間のDuration．営業時間の境界が空の場合・方針により祝日データの範囲外となる場合はエラー
+    pub fn try_get_timedelta_workdays_intraday(&self, start_datetime: NaiveDateTime, end_datetime: NaiveDateTime) -> Result<Duration, Error> {
+        self.check_intraday_borders()?;
+        self.check_coverage(start_datetime.date())?;
+        self.check_coverage(end_datetime.date())?;
+        Ok(self.get_timedelta_workdays_intraday(start_datetime, end_datetime))
+    }
+
+    /// start_datetimeからend_datetimeの営業日・営業時間を取得
+    /// Argments
+    /// - start_datetime: 開始日時
+    /// - end_datetime: 終了日時
+    ///
+    /// Return
+    /// 営業日・営業時間のDuration(end_datetimeがstart_datetimeより前の場合は負)
+    pub fn get_timedelta_workdays_intraday(&self, start_datetime: NaiveDateTime, end_datetime: NaiveDateTime) -> Duration {
+        if start_datetime > end_datetime {
+            return - self.get_timedelta_workdays_intraday(end_datetime, start_datetime);
+        }
+
+        let mut all_delta_time = Duration::zero();
+        let start_date = start_datetime.date();
+        let end_date = end_datetime.date();
+
+        // 営業時間の区間が全て含まれる日(開始日の翌日から終了日の前々日まで)
+        let full_start_date = start_date.succ_opt();
+        let full_end_date = end_date.pred_opt().and_then(|x|{x.pred_opt()});
+        let partial_dates: Vec<NaiveDate> = match (full_start_date, full_end_date) {
+            (Some(full_start_date), Some(full_end_date)) if full_start_date <= full_end_date => {
+                if self.intraday_schedule.is_empty() {  // 営業時間が全ての営業日で同じ場合
+                    let workdays_number = self.count_workdays(full_start_date, full_end_date, Closed::Both);
+                    all_delta_time = all_delta_time + borders_duration(self.intraday_borders()) * workdays_number as i32;
+                } else {
+                    for one_day in self.iter_workdays_range(full_start_date, full_end_date, Closed::Both) {
+                        all_delta_time = all_delta_time + self.one_workday_duration(one_day);
+                    }
+                }
+                [start_date.pred_opt(), Some(start_date), full_end_date.succ_opt(), Some(end_date)].iter().flatten().cloned().collect()
+            },
+            _ => {
+                start_date.pred_opt().unwrap_or(start_date).iter_days().take_while(|x|{x <= &end_date}).collect()
+            }
+        };
+
+        // 一部が含まれる可能性のある日
+        for one_date in partial_dates.into_iter() {
+            for session in self.sessions_on_date(one_date, true) {
+                let overlap_start = session.start.max(start_datetime);
+                let overlap_end = session.end.min(end_datetime);
+                if overlap_start < overlap_end {
+                    all_delta_time = all_delta_time + (overlap_end - overlap_start);
+                }
+            }
+        }
+
+        all_delta_time
+    }
+}
+
+
+/// select_datetimeが営業日・営業時間内であるかどうかを判定
 /// Argment
 /// - select_datetime: 指定する日時
-/// 
+///
 /// Return
 /// 営業日・営業時間内であるかどうか
-/// 
+///
 /// # Examples
 /// ~~~~
 /// use chrono::{NaiveDate};
@@ -20,364 +579,213 @@ use crate::workdays::{check_workday, get_next_workday, get_previous_workday, get
 /// assert!(!is_workday_intraday);
 /// ~~~~
 pub fn check_workday_intraday(select_datetime: NaiveDateTime) -> bool {
-    let intraday_borders_vec = INTRADAY_BORDERS.read().unwrap();
-    let select_date = select_datetime.date();
-    
-    if check_workday(select_date) {// 営業日である
-        let select_time = select_datetime.time();
-        let is_intraday: bool =  intraday_borders_vec.iter().any(
-            |x| {(x.start <= select_time) & (select_time < x.end)}
-        );
-        return is_intraday;
-    } else {
-        false
-    }
+    read_work_calendar().check_workday_intraday(select_datetime)
+}
+
+/// select_datetimeを含む営業時間の属する取引日を取得する．
+/// 日付をまたぐ営業時間の取引日はカレンダーの取引日の規則(`SessionAttribution`)に従う
+/// Argment
+/// - select_datetime: 指定する日時
+///
+/// Return
+/// 取引日．営業日・営業時間外の場合はNone
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::intraday::*;
+/// assert_eq!(get_trading_date(NaiveDate::from_ymd(2021,1,4).and_hms(10,0,0)), Some(NaiveDate::from_ymd(2021,1,4)));
+/// assert_eq!(get_trading_date(NaiveDate::from_ymd(2021,1,4).and_hms(12,0,0)), None);
+/// ~~~~
+pub fn get_trading_date(select_datetime: NaiveDateTime) -> Option<NaiveDate> {
+    read_work_calendar().get_trading_date(select_datetime)
 }
 
-/// 次の営業日・営業時間内のdatetimeをその状態とともに取得
+/// 次の営業日・営業時間内のdatetimeをその境界の種類とともに取得
 /// Argment
 /// - select_datetime: 指定する日時
-/// 
+///
 /// Returns
 /// - out_datetime: 次の営業日・営業時間内のdatetime
-/// - 状態を示す文字列
-///     - 'border_start': 営業時間の開始
-///     - 'border_end': 営業時間の終了
-/// 
+/// - 境界の種類(`BorderKind::Start`・`BorderKind::End`)
+///
 /// # Examples
 /// ~~~~
 /// use chrono::{NaiveDate};
 /// use rs_workdays::intraday::*;
 /// let select_datetime = NaiveDate::from_ymd(2021,1,1).and_hms(0,0,0);
-/// let (next_border_datetime, border_symbol) = get_next_border_workday_intraday(select_datetime);
-/// assert_eq!((next_border_datetime, border_symbol), (NaiveDate::from_ymd(2021, 1, 4).and_hms(9,0,0), "border_start"));
+/// let (next_border_datetime, border_kind) = get_next_border(select_datetime);
+/// assert_eq!((next_border_datetime, border_kind), (NaiveDate::from_ymd(2021, 1, 4).and_hms(9,0,0), BorderKind::Start(0)));
 /// ~~~~
-pub fn get_next_border_workday_intraday(select_datetime: NaiveDateTime) -> (NaiveDateTime, &'static str) {
-    let intraday_borders_vec = INTRADAY_BORDERS.read().unwrap();
-    let select_date = select_datetime.date();
-
-    if check_workday(select_date) {  // 営業日の場合
-        let select_time = select_datetime.time();
-        if check_workday_intraday(select_datetime) { // 営業日・営業時間の場合
-            let bigger_border_ends: Vec<NaiveTime> = intraday_borders_vec.iter()
-                .filter(|x| {select_time < x.end}).map(|x| {x.end}).collect();
-            let out_time = bigger_border_ends.iter().min().unwrap();
-            let out_datetime: NaiveDateTime = select_date.and_time(*out_time);
-            return (out_datetime, "border_end");
-
-        } else { // 営業時間でない場合
-            let border_starts: Vec<NaiveTime> = intraday_borders_vec.iter()
-                .map(|x| {x.start}).collect();
-            let bigger_border_starts: Vec<NaiveTime> = border_starts.iter().cloned()
-                .filter(|x| {x > &select_time}).collect();
-         
-            if bigger_border_starts.len() > 0 { // 指定時間より遅い営業時間の開始ボーダーがある場合
-                let out_time = bigger_border_starts.iter().min().unwrap();
-                let out_datetime: NaiveDateTime = select_date.and_time(*out_time);
-                return (out_datetime, "border_start");
-            } else { // 指定時間より遅い営業時間が存在しない場合
-                let out_date: NaiveDate = get_next_workday(select_date, 1); // 次の営業日
-                let out_time = border_starts.iter().min().unwrap();
-                let out_datetime: NaiveDateTime = out_date.and_time(*out_time);
-                return (out_datetime, "border_start");
-            }
-        }
-    } else {  // 営業日でない場合
-        let border_starts: Vec<NaiveTime> = intraday_borders_vec.iter()
-            .map(|x| {x.start}).collect();
-        let out_date: NaiveDate = get_next_workday(select_date, 1); // 次の営業日
-        let out_time = border_starts.iter().min().unwrap();
-        let out_datetime: NaiveDateTime = out_date.and_time(*out_time);
-        return (out_datetime, "border_start");
-    }
+pub fn get_next_border(select_datetime: NaiveDateTime) -> (NaiveDateTime, BorderKind) {
+    read_work_calendar().get_next_border(select_datetime)
+}
+
+/// 次の営業日・営業時間内のdatetimeをその境界の種類とともに取得する．営業時間の境界が空の場合・営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラーを返す
+/// Argments
+/// - select_datetime: 指定する日時
+///
+/// Return
+/// (日時, 境界の種類)．デフォルトのカレンダーのロックがポイズンされている場合もエラー
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::intraday::*;
+/// let select_datetime = NaiveDate::from_ymd(2021,1,4).and_hms(12,0,0);
+/// let (next_border_datetime, border_kind) = try_get_next_border(select_datetime).unwrap();
+/// assert_eq!(next_border_datetime, NaiveDate::from_ymd(2021,1,4).and_hms(12,30,0));
+/// assert_eq!(border_kind, BorderKind::Start(1));  // 後場の開始
+/// ~~~~
+pub fn try_get_next_border(select_datetime: NaiveDateTime) -> Result<(NaiveDateTime, BorderKind), Error> {
+    try_read_work_calendar()?.try_get_next_border(select_datetime)
 }
 
-/// 前の営業日・営業時間内のdatetimeをその状態とともに取得
+/// 前の営業日・営業時間内のdatetimeをその境界の種類とともに取得
 /// Argment
 /// - select_datetime: 指定する日時
-/// 
+/// - force_is_end: 終了境界の場合に前の開始境界を返すかどうか
+///
 /// Returns
 /// - out_datetime: 前の営業日・営業時間内のdatetime
-/// - 状態を示す文字列
-///     - 'border_start': 営業時間の開始
-///     - 'border_end': 営業時間の終了
-/// 
+/// - 境界の種類(`BorderKind::Start`・`BorderKind::End`)
+///
 /// # Examples
 /// ~~~~
 /// use chrono::{NaiveDate};
 /// use rs_workdays::intraday::*;
 /// let select_datetime = NaiveDate::from_ymd(2021,1,1).and_hms(0,0,0);
-/// let (previous_border_datetime, border_symbol) = get_previous_border_workday_intraday(select_datetime, false);
-/// assert_eq!((previous_border_datetime, border_symbol), (NaiveDate::from_ymd(2020, 12, 31).and_hms(15,0,0), "border_end"));
-/// 
+/// let (previous_border_datetime, border_kind) = get_previous_border(select_datetime, false);
+/// assert_eq!((previous_border_datetime, border_kind), (NaiveDate::from_ymd(2020, 12, 31).and_hms(15,0,0), BorderKind::End(1)));
+///
 /// let select_datetime = NaiveDate::from_ymd(2021,1,4).and_hms(15,0,0);
-/// let (previous_border_datetime, border_symbol) = get_previous_border_workday_intraday(select_datetime, false);
-/// assert_eq!((previous_border_datetime, border_symbol), (NaiveDate::from_ymd(2021, 1, 4).and_hms(15,0,0), "border_end"));
-/// 
+/// let (previous_border_datetime, border_kind) = get_previous_border(select_datetime, false);
+/// assert_eq!((previous_border_datetime, border_kind), (NaiveDate::from_ymd(2021, 1, 4).and_hms(15,0,0), BorderKind::End(1)));
+///
 /// let select_datetime = NaiveDate::from_ymd(2021,1,4).and_hms(15,0,0);
-/// let (previous_border_datetime, border_symbol) = get_previous_border_workday_intraday(select_datetime, true);
-/// assert_eq!((previous_border_datetime, border_symbol), (NaiveDate::from_ymd(2021, 1, 4).and_hms(12,30,0), "border_start"));
+/// let (previous_border_datetime, border_kind) = get_previous_border(select_datetime, true);
+/// assert_eq!((previous_border_datetime, border_kind), (NaiveDate::from_ymd(2021, 1, 4).and_hms(12,30,0), BorderKind::Start(1)));
 /// ~~~~
-pub fn get_previous_border_workday_intraday(select_datetime: NaiveDateTime, force_is_end:bool) -> (NaiveDateTime, &'static str) {
-    let intraday_borders_vec = INTRADAY_BORDERS.read().unwrap();
-    let select_date = select_datetime.date();
-    let select_time = select_datetime.time();
-
-    if check_workday(select_date) {  // 営業日の場合
-        let border_starts: Vec<NaiveTime> = intraday_borders_vec.iter().map(|x|{x.start}).collect();
-        let is_start_border: bool = border_starts.iter().any(
-            |x|{x==&select_time}
-        );
-        if check_workday_intraday(select_datetime) & !is_start_border { // 営業時間であり，開始境界でない場合
-            let smaller_border_starts: Vec<NaiveTime> = border_starts.iter().cloned().filter(|x|{x<&select_time}).collect();
-            let out_time = smaller_border_starts.iter().max().unwrap();
-            let out_datetime: NaiveDateTime = select_date.and_time(*out_time);
-            return (out_datetime, "border_start");
-        } else { // 営業時間でないか，開始境界である．
-            let border_ends: Vec<NaiveTime> = intraday_borders_vec.iter().map(|x|{x.end}).collect();
-            if force_is_end {  // 終了境界で次の開始境界に行くのを強制する
-                let is_end_border: bool = border_ends.iter().any(
-                    |x|{x==&select_time}
-                );
-                if is_end_border {  // 終了境界
-                    let smaller_border_starts: Vec<NaiveTime> = border_starts.iter().cloned().filter(|x|{x<&select_time}).collect();
-                    let out_time = smaller_border_starts.iter().max().unwrap();
-                    let out_datetime: NaiveDateTime = select_date.and_time(*out_time);
-                    return (out_datetime, "border_start");
-                }
-            }
-            let smaller_border_ends: Vec<NaiveTime> = border_ends.iter().cloned().filter(|x|{x<=&select_time}).collect();
-            if smaller_border_ends.len() > 0 {  // 指定時間より早い営業時間の終了ボーダーがある場合
-                let out_time = smaller_border_ends.iter().max().unwrap();
-                let out_datetime: NaiveDateTime = select_date.and_time(*out_time);
-                return (out_datetime, "border_end");
-            } else {  // 指定時間より早い営業時間が存在しない場合
-                let out_date: NaiveDate = get_previous_workday(select_date, 1);
-                let out_time = border_ends.iter().max().unwrap();
-                let out_datetime: NaiveDateTime = out_date.and_time(*out_time);
-                return (out_datetime, "border_end");
-            }
-        }
-    } else {  // 営業日でない場合
-        let border_ends: Vec<NaiveTime> = intraday_borders_vec.iter().map(|x|{x.end}).collect();
-        let out_date = get_previous_workday(select_date, 1);
-        let out_time = border_ends.iter().max().unwrap();
-        let out_datetime: NaiveDateTime = out_date.and_time(*out_time);
-        return (out_datetime, "border_end");
-    }
+pub fn get_previous_border(select_datetime: NaiveDateTime, force_is_end:bool) -> (NaiveDateTime, BorderKind) {
+    read_work_calendar().get_previous_border(select_datetime, force_is_end)
 }
 
-/// 最近の営業日・営業時間内のdatetimeをその状態とともに取得．select_datetimeが営業日・営業時間内の場合そのまま返る．
+/// 前の営業日・営業時間内のdatetimeをその境界の種類とともに取得する．営業時間の境界が空の場合・営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラーを返す
+/// Argments
+/// - select_datetime: 指定する日時
+/// - force_is_end: 終了境界の場合に前の開始境界を返すかどうか
+///
+/// Return
+/// (日時, 境界の種類)．デフォルトのカレンダーのロックがポイズンされている場合もエラー
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::intraday::*;
+/// let select_datetime = NaiveDate::from_ymd(2021,1,4).and_hms(0,0,0);
+/// let (previous_border_datetime, border_kind) = try_get_previous_border(select_datetime, false).unwrap();
+/// assert_eq!(previous_border_datetime, NaiveDate::from_ymd(2020,12,31).and_hms(15,0,0));
+/// assert_eq!(border_kind, BorderKind::End(1));
+/// ~~~~
+pub fn try_get_previous_border(select_datetime: NaiveDateTime, force_is_end:bool) -> Result<(NaiveDateTime, BorderKind), Error> {
+    try_read_work_calendar()?.try_get_previous_border(select_datetime, force_is_end)
+}
+
+/// 最近の営業日・営業時間内のdatetimeをその境界の種類とともに取得．select_datetimeが営業日・営業時間内の場合そのまま返る．
 /// Argments
 /// - select_datetime: 指定する日時
 /// - is_after: 後ろを探索するかどうか
-/// 
+///
 /// Returns
-/// - out_datetime: 前の営業日・営業時間内のdatetime
-/// - 状態を示す文字列
-///     - 'border_intra': 営業時間内
-///     - 'border_start': 営業時間の開始
-///     - 'border_end': 営業時間の終了
-/// 
+/// - out_datetime: 最近の営業日・営業時間内のdatetime
+/// - 境界の種類(`BorderKind::Intra`・`BorderKind::Start`・`BorderKind::End`)
+///
 /// # Examples
 /// ~~~~
 /// use chrono::{NaiveDate};
 /// use rs_workdays::intraday::*;
 /// let select_datetime = NaiveDate::from_ymd(2021,1,1).and_hms(0,0,0);
-/// let (near_workday_intraday_datetime, border_symbol) = get_near_workday_intraday(select_datetime, true);
-/// assert_eq!((near_workday_intraday_datetime, border_symbol), (NaiveDate::from_ymd(2021, 1, 4).and_hms(9,0,0), "border_start"));
-/// 
+/// let (near_workday_intraday_datetime, border_kind) = get_near_border(select_datetime, true);
+/// assert_eq!((near_workday_intraday_datetime, border_kind), (NaiveDate::from_ymd(2021, 1, 4).and_hms(9,0,0), BorderKind::Start(0)));
+///
 /// let select_datetime = NaiveDate::from_ymd(2021,1,4).and_hms(10,0,0);
-/// let (near_workday_intraday_datetime, border_symbol) = get_near_workday_intraday(select_datetime, true);
-/// assert_eq!((near_workday_intraday_datetime, border_symbol), (NaiveDate::from_ymd(2021, 1, 4).and_hms(10,0,0), "border_intra"));
-/// 
+/// let (near_workday_intraday_datetime, border_kind) = get_near_border(select_datetime, true);
+/// assert_eq!((near_workday_intraday_datetime, border_kind), (NaiveDate::from_ymd(2021, 1, 4).and_hms(10,0,0), BorderKind::Intra(0)));
+///
 /// let select_datetime = NaiveDate::from_ymd(2021,1,1).and_hms(0,0,0);
-/// let (near_workday_intraday_datetime, border_symbol) = get_near_workday_intraday(select_datetime, false);
-/// assert_eq!((near_workday_intraday_datetime, border_symbol), (NaiveDate::from_ymd(2020, 12, 31).and_hms(15,0,0), "border_end"));
+/// let (near_workday_intraday_datetime, border_kind) = get_near_border(select_datetime, false);
+/// assert_eq!((near_workday_intraday_datetime, border_kind), (NaiveDate::from_ymd(2020, 12, 31).and_hms(15,0,0), BorderKind::End(1)));
 /// ~~~~
-pub fn get_near_workday_intraday(select_datetime: NaiveDateTime, is_after:bool) -> (NaiveDateTime, &'static str) {
-    if check_workday_intraday(select_datetime) {
-        return (select_datetime, "border_intra");
-    } else {
-        if is_after {
-            return get_next_border_workday_intraday(select_datetime);
-        } else {
-            return get_previous_border_workday_intraday(select_datetime, false);
-        }
-    }
+pub fn get_near_border(select_datetime: NaiveDateTime, is_after:bool) -> (NaiveDateTime, BorderKind) {
+    read_work_calendar().get_near_border(select_datetime, is_after)
 }
 
-/// 営業日・営業時間を考慮しDateTimeを加算する．
+/// 最近の営業日・営業時間内のdatetimeをその境界の種類とともに取得する．営業時間の境界が空の場合・営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラーを返す
 /// Argments
 /// - select_datetime: 指定する日時
-/// - dela_time: 加算するDuration(>0)
-/// 
+/// - is_after: 後ろを探索するかどうか
+///
 /// Return
-/// 加算された日時
-fn _add_workday_intraday_datetime(select_datetime: NaiveDateTime, delta_time: Duration) -> NaiveDateTime {
-    let mut all_delta_time = delta_time;
-    let intraday_borders_vec = INTRADAY_BORDERS.read().unwrap();
-
-    // 営業時間一日分のdurationを作成
-    let mut one_workday_delta_time = Duration::zero();
-
-    for one_borders in intraday_borders_vec.iter() {
-        one_workday_delta_time =  one_workday_delta_time + (one_borders.end - one_borders.start);
-    } 
-
-    let select_date = select_datetime.date();
-    let (next_border_datetime, next_border_symbol) = get_next_border_workday_intraday(select_datetime);
-
-    if check_workday_intraday(select_datetime) & (next_border_symbol=="border_end") {  //  select_datetimeが営業時間内にある場合
-        let delta_select_date_intraday = next_border_datetime - select_datetime;
-
-        if all_delta_time <= delta_select_date_intraday {  // 残りがその営業時間内以下の場合
-            let out_datetime = select_datetime + all_delta_time;
-            
-            if all_delta_time==delta_select_date_intraday {  // 残りがその営業時間と同じ場合
-                let (out_datetime, out_datetime_symbol) = get_next_border_workday_intraday(out_datetime);
-                assert_eq!(out_datetime_symbol, "border_start");
-                return out_datetime;
-            }
-            return out_datetime;
-        } else {
-            all_delta_time = all_delta_time - delta_select_date_intraday;
-        }
-    }
-
-    if next_border_datetime.date() == select_date {  // その日の営業時間がまだ残っている場合
-        let next_border_time = next_border_datetime.time();
-        let bigger_intraday_borders: Vec<TimeBorder> = intraday_borders_vec.iter().cloned().filter(|x|{x.start >= next_border_time}).collect();
-
-        for bigger_intraday_border in bigger_intraday_borders.iter(){
-            let delta_select_date_intraday = bigger_intraday_border.end.signed_duration_since(bigger_intraday_border.start);
-            if all_delta_time <= delta_select_date_intraday {  // 残りがその営業時間内以下の場合 
-              let out_datetime_start = select_date.and_time(bigger_intraday_border.start);
-              let out_datetime = out_datetime_start + all_delta_time;
-              if all_delta_time == delta_select_date_intraday{  // 残りがその営業時間と同じ場合
-                let (out_datetime, out_datetime_symbol) = get_next_border_workday_intraday(out_datetime);
-                assert_eq!(out_datetime_symbol, "border_start");
-                return out_datetime;
-              }
-              return out_datetime;
-            } else {
-                all_delta_time = all_delta_time - delta_select_date_intraday;   // 営業時間分を減らす
-            }
-        }
-    }
-
-    let mut add_day_number: i32 = 1;  // 追加が必要な営業日の日数
-
-    loop {
-        if all_delta_time <= one_workday_delta_time {
-            break;
-        }
-        all_delta_time = all_delta_time - one_workday_delta_time;
-        add_day_number += 1;
-    }
-
-    let out_date = get_next_workday(select_date, add_day_number);  // 出力する営業日
-
-    for intraday_border in intraday_borders_vec.iter() {
-        let delta_out_date_intraday = intraday_border.end.signed_duration_since(intraday_border.start);
-        
-        if all_delta_time <= delta_out_date_intraday {  // 残りがその営業時間内以下の場合
-            let out_datetime_start = out_date.and_time(intraday_border.start);
-            let out_datetime = out_datetime_start + all_delta_time;
-            if all_delta_time == delta_out_date_intraday {  // 残りがその営業時間と同じ場合 
-                let (out_datetime, out_datetime_symbol) = get_next_border_workday_intraday(out_datetime);   
-                assert_eq!(out_datetime_symbol, "border_start");
-                return out_datetime;
-            }
-            return out_datetime;
-        } else {
-            all_delta_time = all_delta_time - delta_out_date_intraday;  // 営業時間分を減らす
-        }
-    }
-
-    return select_datetime;  // 計算に失敗している
+/// (日時, 境界の種類)．デフォルトのカレンダーのロックがポイズンされている場合もエラー
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::intraday::*;
+/// let select_datetime = NaiveDate::from_ymd(2021,1,4).and_hms(13,0,0);
+/// assert_eq!(try_get_near_border(select_datetime, true).unwrap(), (select_datetime, BorderKind::Intra(1)));
+/// ~~~~
+pub fn try_get_near_border(select_datetime: NaiveDateTime, is_after:bool) -> Result<(NaiveDateTime, BorderKind), Error> {
+    try_read_work_calendar()?.try_get_near_border(select_datetime, is_after)
 }
 
-/// 営業日・営業時間を考慮しDateTimeを減算する．
-/// Argments
-/// - select_datetime: 指定する日時
-/// - dela_time: 加算するDuration>0
-/// 
-/// Return
-/// 減算された日時
-fn _sub_workday_intraday_datetime(select_datetime: NaiveDateTime, delta_time: Duration) -> NaiveDateTime {
-    let mut all_delta_time = delta_time;
-    let intraday_borders_vec = INTRADAY_BORDERS.read().unwrap();
-
-    // 営業時間一日分のdurationを作成
-    let mut one_workday_delta_time = Duration::zero();
-
-    for one_borders in intraday_borders_vec.iter() {
-        one_workday_delta_time =  one_workday_delta_time + (one_borders.end - one_borders.start);
-    }
-
-    let select_date = select_datetime.date();
-    let (previous_border_datetime, previous_border_symbol) = get_previous_border_workday_intraday(select_datetime, false);
-    if check_workday_intraday(select_datetime) & (previous_border_symbol=="border_start") {  // select_datetimeが営業時間内にある場合
-        let delta_select_date_intraday = select_datetime - previous_border_datetime;
-        if all_delta_time <= delta_select_date_intraday {  // 残りがその営業時間内以下の場合
-            let out_datetime = select_datetime - all_delta_time;
-            return out_datetime;
-        } else {
-            all_delta_time = all_delta_time - delta_select_date_intraday;  // 営業時間分を減らす
-        }
-    }
+/// 次の営業日・営業時間内のdatetimeをその状態を示す文字列('border_start'・'border_end')とともに取得
+#[deprecated(note="use `get_next_border` which returns `BorderKind`")]
+pub fn get_next_border_workday_intraday(select_datetime: NaiveDateTime) -> (NaiveDateTime, &'static str) {
+    let (out_datetime, border_kind) = get_next_border(select_datetime);
+    (out_datetime, border_kind.as_str())
+}
 
-    if previous_border_datetime.date()==select_date{  // その日の営業時間がまだ残っている場合
-        let previous_border_time = previous_border_datetime.time();
-        let smaller_intraday_borders: Vec<TimeBorder> = intraday_borders_vec.iter().cloned().filter(|x|{x.end <= previous_border_time}).collect();
-    
-        for smaller_intraday_border in smaller_intraday_borders.iter() {
-            let delta_select_date_intraday = smaller_intraday_border.end.signed_duration_since(smaller_intraday_border.start);
-            if all_delta_time <= delta_select_date_intraday{  // 残りがその営業時間内以下の場合
-                let out_datetime_end = select_date.and_time(smaller_intraday_border.end);
-                let out_datetime = out_datetime_end - all_delta_time;
-                return out_datetime;
-            } else {
-                all_delta_time = all_delta_time - delta_select_date_intraday;  // 営業時間分を減らす
-            }
-        }
-    }
+/// 次の営業日・営業時間内のdatetimeをその状態を示す文字列('border_start'・'border_end')とともに取得
+#[deprecated(note="use `try_get_next_border` which returns `BorderKind`")]
+pub fn try_get_next_border_workday_intraday(select_datetime: NaiveDateTime) -> Result<(NaiveDateTime, &'static str), Error> {
+    try_get_next_border(select_datetime).map(|(out_datetime, border_kind)|{(out_datetime, border_kind.as_str())})
+}
 
-    let mut sub_day_number:i32 = 1;
-    
-    loop {
-        if all_delta_time <= one_workday_delta_time{
-            break;
-        }
-        all_delta_time = all_delta_time - one_workday_delta_time;
-        sub_day_number += 1;
-    }
+/// 前の営業日・営業時間内のdatetimeをその状態を示す文字列('border_start'・'border_end')とともに取得
+#[deprecated(note="use `get_previous_border` which returns `BorderKind`")]
+pub fn get_previous_border_workday_intraday(select_datetime: NaiveDateTime, force_is_end:bool) -> (NaiveDateTime, &'static str) {
+    let (out_datetime, border_kind) = get_previous_border(select_datetime, force_is_end);
+    (out_datetime, border_kind.as_str())
+}
 
-    let out_date = get_previous_workday(select_date, sub_day_number);  // 出力する営業日
+/// 前の営業日・営業時間内のdatetimeをその状態を示す文字列('border_start'・'border_end')とともに取得
+#[deprecated(note="use `try_get_previous_border` which returns `BorderKind`")]
+pub fn try_get_previous_border_workday_intraday(select_datetime: NaiveDateTime, force_is_end:bool) -> Result<(NaiveDateTime, &'static str), Error> {
+    try_get_previous_border(select_datetime, force_is_end).map(|(out_datetime, border_kind)|{(out_datetime, border_kind.as_str())})
+}
 
-    for intraday_border in intraday_borders_vec.iter().rev() {  // 逆順
-        let delta_out_date_intraday = intraday_border.end.signed_duration_since(intraday_border.start);
-        if all_delta_time <= delta_out_date_intraday {
-            let out_datetime_end = out_date.and_time(intraday_border.end);
-            let out_datetime = out_datetime_end - all_delta_time;
-            return out_datetime;
-        } else {
-            all_delta_time = all_delta_time - delta_out_date_intraday;  // 営業時間分を減らす
-        }
-    }
+/// 最近の営業日・営業時間内のdatetimeをその状態を示す文字列('border_intra'・'border_start'・'border_end')とともに取得
+#[deprecated(note="use `get_near_border` which returns `BorderKind`")]
+pub fn get_near_workday_intraday(select_datetime: NaiveDateTime, is_after:bool) -> (NaiveDateTime, &'static str) {
+    let (out_datetime, border_kind) = get_near_border(select_datetime, is_after);
+    (out_datetime, border_kind.as_str())
+}
 
-    return select_datetime;  // 計算に失敗している
+/// 最近の営業日・営業時間内のdatetimeをその状態を示す文字列('border_intra'・'border_start'・'border_end')とともに取得
+#[deprecated(note="use `try_get_near_border` which returns `BorderKind`")]
+pub fn try_get_near_workday_intraday(select_datetime: NaiveDateTime, is_after:bool) -> Result<(NaiveDateTime, &'static str), Error> {
+    try_get_near_border(select_datetime, is_after).map(|(out_datetime, border_kind)|{(out_datetime, border_kind.as_str())})
 }
 
 /// 営業日・営業時間を考慮しDateTimeを加算する．
 /// Argments
 /// - select_datetime: 指定する日時
 /// - dela_time: 加算するDuration
-/// 
+///
 /// Return
 /// 加算された日時
-/// 
+///
 /// # Examples
 /// ~~~~
 /// use chrono::{NaiveDate, Duration};
@@ -391,13 +799,28 @@ fn _sub_workday_intraday_datetime(select_datetime: NaiveDateTime, delta_time: Du
 /// assert_eq!(subed_workday_intraday_datetime, NaiveDate::from_ymd(2020,12,31).and_hms(13,0,0));
 /// ~~~~
 pub fn add_workday_intraday_datetime(select_datetime: NaiveDateTime, delta_time: Duration) -> NaiveDateTime {
-    if delta_time.num_milliseconds() > 0 {
-        return _add_workday_intraday_datetime(select_datetime, delta_time);
-    } else if delta_time.num_milliseconds() < 0 {
-        return _sub_workday_intraday_datetime(select_datetime, - delta_time);
-    } else {  // mmsec以下は無視
-        return select_datetime;
-    }
+    read_work_calendar().add_workday_intraday_datetime(select_datetime, delta_time)
+}
+
+/// 営業日・営業時間を考慮しDateTimeを加算する．営業時間の境界が空の場合・営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラーを返す
+/// Argments
+/// - select_datetime: 指定する日時
+/// - dela_time: 加算するDuration
+///
+/// Return
+/// 加算された日時．デフォルトのカレンダーのロックがポイズンされている場合もエラー
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::intraday::*;
+/// use chrono::Duration;
+/// let select_datetime = NaiveDate::from_ymd(2021,1,1).and_hms(10,0,0);
+/// let added_datetime = try_add_workday_intraday_datetime(select_datetime, Duration::hours(2)).unwrap();
+/// assert_eq!(added_datetime, NaiveDate::from_ymd(2021,1,4).and_hms(11,0,0));
+/// ~~~~
+pub fn try_add_workday_intraday_datetime(select_datetime: NaiveDateTime, delta_time: Duration) -> Result<NaiveDateTime, Error> {
+    try_read_work_calendar()?.try_add_workday_intraday_datetime(select_datetime, delta_time)
 }
 
 
@@ -405,10 +828,10 @@ pub fn add_workday_intraday_datetime(select_datetime: NaiveDateTime, delta_time:
 /// Argments
 /// - start_datetime: 開始日時
 /// - end_datetime: 終了日時
-/// 
+///
 /// Return
 /// 営業日・営業時間のDuration
-/// 
+///
 /// # Examples
 /// ~~~~
 /// use chrono::{NaiveDate, Duration};
@@ -419,61 +842,26 @@ pub fn add_workday_intraday_datetime(select_datetime: NaiveDateTime, delta_time:
 /// assert_eq!(span_duration, Duration::hours(5));
 /// ~~~~
 pub fn get_timedelta_workdays_intraday(start_datetime: NaiveDateTime, end_datetime: NaiveDateTime) -> Duration {
-    let mut all_delta_time = Duration::zero();
-    let intraday_borders_vec = INTRADAY_BORDERS.read().unwrap();
-
-    // 営業時間一日分のdurationを作成
-    let mut one_workday_delta_time = Duration::zero();
-
-    for one_borders in intraday_borders_vec.iter() {
-        one_workday_delta_time =  one_workday_delta_time + (one_borders.end - one_borders.start);
-    }
-
-    let start_date = start_datetime.date();
-    let start_time = start_datetime.time();
-    let end_date = end_datetime.date();
-    let end_time = end_datetime.time();
-
-    // start_dateについて
-    if check_workday(start_date) {  // start_dateが営業日の場合
-        if check_workday_intraday(start_datetime) {  // start_datetimeが営業時間内の場合
-            let bigger_border_ends: Vec<NaiveTime> = intraday_borders_vec.iter()
-            .filter(|x|{x.end > start_time}).map(|x|{x.end}).collect();
-            let near_border_end = bigger_border_ends.iter().min().unwrap();
-            all_delta_time = all_delta_time + near_border_end.signed_duration_since(start_time);
-        }
-
-        let bigger_intraday_borders: Vec<TimeBorder> = intraday_borders_vec.iter().cloned()
-        .filter(|x|{x.start > start_time}).collect();
-
-        for bigger_intraday_border in bigger_intraday_borders.iter() {
-            all_delta_time = all_delta_time +  bigger_intraday_border.end.signed_duration_since(bigger_intraday_border.start);
-        }
-    }
-
-    // 開始時刻から終了時刻までの営業日(開始・終了はふくまない)
-    let workdays = get_workdays(start_date, end_date, Closed::Not);
-
-    for _ in 0..workdays.len() {
-        all_delta_time = all_delta_time + one_workday_delta_time;
-    }
-
-    // end_dateについて
-    if check_workday(end_date) { // end_dateが営業日の場合
-        if check_workday_intraday(end_datetime) {  // end_datetimeが営業時間内の場合
-            let smaller_border_starts: Vec<NaiveTime> = intraday_borders_vec.iter()
-            .filter(|x|{x.start <= end_time}).map(|x|{x.start}).collect();
-            let near_border_start = smaller_border_starts.iter().max().unwrap();
-            all_delta_time = all_delta_time + end_time.signed_duration_since(*near_border_start);
-        }
-
-        let smaller_intraday_borders: Vec<TimeBorder> = intraday_borders_vec.iter().cloned()
-        .filter(|x|{x.end <= end_time}).collect();
-
-        for smaller_intraday_border in smaller_intraday_borders.iter() {
-            all_delta_time = all_delta_time + smaller_intraday_border.end.signed_duration_since(smaller_intraday_border.start);
-        }
-    }
+    read_work_calendar().get_timedelta_workdays_intraday(start_datetime, end_datetime)
+}
 
-    return all_delta_time;
+/// start_datetimeからend_datetimeの営業日・営業時間を取得する．営業時間の境界が空の場合はエラーを返す
+/// Argments
+/// - start_datetime: 開始日時
+/// - end_datetime: 終了日時
+///
+/// Return
+/// 営業日・営業時間のDuration．デフォルトのカレンダーのロックがポイズンされている場合もエラー
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::intraday::*;
+/// use chrono::Duration;
+/// let start_datetime = NaiveDate::from_ymd(2021,1,1).and_hms(0,0,0);
+/// let end_datetime = NaiveDate::from_ymd(2021,1,5).and_hms(0,0,0);
+/// assert_eq!(try_get_timedelta_workdays_intraday(start_datetime, end_datetime).unwrap(), Duration::hours(5));
+/// ~~~~
+pub fn try_get_timedelta_workdays_intraday(start_datetime: NaiveDateTime, end_datetime: NaiveDateTime) -> Result<Duration, Error> {
+    try_read_work_calendar()?.try_get_timedelta_workdays_intraday(start_datetime, end_datetime)
 }
\ No newline at end of file
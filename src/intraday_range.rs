@@ -0,0 +1,174 @@
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+use chrono::{NaiveDateTime, Duration};
+
+use crate::calendar::WorkCalendar;
+use crate::global::read_work_calendar;
+use crate::intraday::SessionInterval;
+use crate::workdays::Closed;
+
+/// 足の日時のラベル
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Label {
+    /// 足の開始日時
+    Left,
+    /// 足の終了日時(営業時間の最後の足は営業時間の終了)
+    Right
+}
+
+/// 営業日・営業時間内の等間隔の日時を返す遅延イテレーター．
+/// 足は営業時間の開始(昼休み明けを含む)ごとに揃え，営業時間の終了で打ち切る．
+///
+/// # Examples
+/// ~~~~
+/// use chrono::{NaiveDate, Duration};
+/// use rs_workdays::WorkCalendar;
+/// use rs_workdays::workdays::Closed;
+/// use rs_workdays::intraday_range::Label;
+/// let calendar = WorkCalendar::default();
+/// let start_datetime = NaiveDate::from_ymd(2021, 1, 4).and_hms(11, 0, 0);
+/// let end_datetime = NaiveDate::from_ymd(2021, 1, 4).and_hms(13, 0, 0);
+/// let bars: Vec<_> = calendar.generate_intraday_range(start_datetime, end_datetime, Duration::minutes(20), Label::Left, Closed::Left).collect();
+/// assert_eq!(bars, vec![
+///     NaiveDate::from_ymd(2021, 1, 4).and_hms(11, 0, 0),
+///     NaiveDate::from_ymd(2021, 1, 4).and_hms(11, 20, 0),
+///     NaiveDate::from_ymd(2021, 1, 4).and_hms(12, 30, 0),
+///     NaiveDate::from_ymd(2021, 1, 4).and_hms(12, 50, 0)
+/// ]);
+/// ~~~~
+#[derive(Debug, Clone)]
+pub struct IntradayRange<'a> {
+    calendar: Cow<'a, WorkCalendar>,
+    session: Option<SessionInterval>,
+    bar_index: i64,
+    start_datetime: NaiveDateTime,
+    end_datetime: NaiveDateTime,
+    step: Duration,
+    label: Label,
+    closed: Closed
+}
+
+impl<'a> IntradayRange<'a> {
+    /// start_datetimeからend_datetimeまでのイテレーターを作成
+    fn new(calendar: Cow<'a, WorkCalendar>, start_datetime: NaiveDateTime, end_datetime: NaiveDateTime, step: Duration, label: Label, closed: Closed) -> Self {
+        let session = if step > Duration::zero() {  // 間隔が正でない場合は空
+            calendar.try_get_previous_session(start_datetime).or_else(|_|{calendar.try_get_next_session(start_datetime)}).ok()
+        } else {
+            None
+        };
+        let mut intraday_range = IntradayRange {calendar, session, bar_index: 0, start_datetime, end_datetime, step, label, closed};
+        if let Some(session) = intraday_range.session {  // 開始日時の直前の足まで飛ばす
+            let skip_count = (start_datetime - session.start).num_nanoseconds()
+                .and_then(|x|{step.num_nanoseconds().map(|y|{x / y - 1})}).unwrap_or(0);
+            intraday_range.bar_index = skip_count.max(0);
+        }
+        intraday_range
+    }
+
+    /// カレンダーの借用をもたないイテレーターに変換する
+    fn into_owned(self) -> IntradayRange<'static> {
+        IntradayRange {
+            calendar: Cow::Owned(self.calendar.into_owned()),
+            session: self.session,
+            bar_index: self.bar_index,
+            start_datetime: self.start_datetime,
+            end_datetime: self.end_datetime,
+            step: self.step,
+            label: self.label,
+            closed: self.closed
+        }
+    }
+
+    /// 営業時間のbar_index番目の足のラベル．営業時間内に足が無い場合はNone
+    fn bar_label(&self, session: &SessionInterval) -> Option<NaiveDateTime> {
+        let bar_offset = self.step.num_nanoseconds()?.checked_mul(self.bar_index)?;
+        let bar_start = session.start.checked_add_signed(Duration::nanoseconds(bar_offset))?;
+        if bar_start >= session.end {
+            return None;
+        }
+        match self.label {
+            Label::Left => Some(bar_start),
+            Label::Right => Some(bar_start.checked_add_signed(self.step).map_or(session.end, |x|{x.min(session.end)}))
+        }
+    }
+}
+
+impl<'a> Iterator for IntradayRange<'a> {
+    type Item = NaiveDateTime;
+
+    fn next(&mut self) -> Option<NaiveDateTime> {
+        let is_left_closed = matches!(self.closed, Closed::Left | Closed::Both);
+        let is_right_closed = matches!(self.closed, Closed::Right | Closed::Both);
+        while let Some(session) = self.session {
+            let bar_label = match self.bar_label(&session) {
+                Some(bar_label) => bar_label,
+                None => {  // 次の営業時間へ
+                    self.session = self.calendar.try_get_session_after(&session).ok();
+                    self.bar_index = 0;
+                    continue;
+                }
+            };
+            if (bar_label > self.end_datetime) | ((bar_label == self.end_datetime) & !is_right_closed) {
+                self.session = None;
+                break;
+            }
+            self.bar_index += 1;
+            if (bar_label > self.start_datetime) | ((bar_label == self.start_datetime) & is_left_closed) {
+                return Some(bar_label);
+            }
+        }
+        None
+    }
+}
+
+impl<'a> FusedIterator for IntradayRange<'a> {}
+
+
+impl WorkCalendar {
+    /// start_datetimeからend_datetimeまでの営業日・営業時間内の等間隔の日時のイテレーターを取得．
+    /// 足は営業時間の開始ごとに揃える
+    /// Argments
+    /// - start_datetime: 開始日時
+    /// - end_datetime: 終了日時
+    /// - step: 足の間隔(正でない場合は空)
+    /// - label: 足の開始・終了のどちらをラベルとするか
+    /// - closed: 境界を含めるかどうか
+    ///
+    /// Return
+    /// 足のラベルの日時のイテレーター
+    pub fn generate_intraday_range(&self, start_datetime: NaiveDateTime, end_datetime: NaiveDateTime, step: Duration, label: Label, closed: Closed) -> IntradayRange<'_> {
+        IntradayRange::new(Cow::Borrowed(self), start_datetime, end_datetime, step, label, closed)
+    }
+}
+
+
+/// start_datetimeからend_datetimeまでの営業日・営業時間内の等間隔の日時のイテレーターを取得．
+/// 足は営業時間の開始ごとに揃える
+/// Argments
+/// - start_datetime: 開始日時
+/// - end_datetime: 終了日時
+/// - step: 足の間隔(正でない場合は空)
+/// - label: 足の開始・終了のどちらをラベルとするか
+/// - closed: 境界を含めるかどうか
+///
+/// Return
+/// 足のラベルの日時のイテレーター
+///
+/// # Examples
+/// ~~~~
+/// use chrono::{NaiveDate, Duration};
+/// use rs_workdays::workdays::Closed;
+/// use rs_workdays::intraday_range::*;
+/// let start_datetime = NaiveDate::from_ymd(2020, 12, 31).and_hms(14, 0, 0);
+/// let end_datetime = NaiveDate::from_ymd(2021, 1, 4).and_hms(10, 0, 0);
+/// let bars: Vec<_> = generate_intraday_range(start_datetime, end_datetime, Duration::minutes(30), Label::Right, Closed::Right).collect();
+/// assert_eq!(bars, vec![
+///     NaiveDate::from_ymd(2020, 12, 31).and_hms(14, 30, 0),
+///     NaiveDate::from_ymd(2020, 12, 31).and_hms(15, 0, 0),
+///     NaiveDate::from_ymd(2021, 1, 4).and_hms(9, 30, 0),
+///     NaiveDate::from_ymd(2021, 1, 4).and_hms(10, 0, 0)
+/// ]);
+/// ~~~~
+pub fn generate_intraday_range(start_datetime: NaiveDateTime, end_datetime: NaiveDateTime, step: Duration, label: Label, closed: Closed) -> IntradayRange<'static> {
+    read_work_calendar().generate_intraday_range(start_datetime, end_datetime, step, label, closed).into_owned()
+}
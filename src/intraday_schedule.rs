@@ -0,0 +1,114 @@
+use std::collections::{HashMap, BTreeMap};
+use chrono::{NaiveDate, Datelike, Weekday};
+
+use crate::calendar::TimeBorder;
+
+/// 曜日・日付・適用期間ごとの営業時間の境界．
+/// 日付の指定，曜日を指定した適用期間の指定，曜日の指定，曜日を指定しない適用期間の指定の順に優先し(適用期間の指定は後から追加したものを優先する)，
+/// どれにも該当しない日はカレンダーの営業時間の境界を用いる．
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(crate) struct IntradaySchedule {
+    /// 曜日ごとの営業時間の境界
+    weekday_borders: HashMap<Weekday, Vec<TimeBorder>>,
+    /// 日付ごとの営業時間の境界
+    date_borders: BTreeMap<NaiveDate, Vec<TimeBorder>>,
+    /// 適用期間(両端を含む)と曜日(Noneの場合は全ての曜日)ごとの営業時間の境界
+    range_borders: Vec<(NaiveDate, NaiveDate, Option<Weekday>, Vec<TimeBorder>)>
+}
+
+/// 営業時間の境界をソートしたベクターにする
+fn sorted_borders(intraday_borders: &[TimeBorder]) -> Vec<TimeBorder> {
+    let mut intraday_borders = intraday_borders.to_vec();
+    intraday_borders.sort();
+    intraday_borders
+}
+
+impl IntradaySchedule {
+    /// 曜日の営業時間の境界を設定する
+    pub(crate) fn set_weekday_borders(&mut self, weekday: Weekday, intraday_borders: &[TimeBorder]) {
+        self.weekday_borders.insert(weekday, sorted_borders(intraday_borders));
+    }
+
+    /// 日付の営業時間の境界を設定する
+    pub(crate) fn set_date_borders(&mut self, select_date: NaiveDate, intraday_borders: &[TimeBorder]) {
+        self.date_borders.insert(select_date, sorted_borders(intraday_borders));
+    }
+
+    /// 適用期間の営業時間の境界を追加する
+    pub(crate) fn add_range_borders(&mut self, start_date: NaiveDate, end_date: NaiveDate, intraday_borders: &[TimeBorder]) {
+        self.range_borders.push((start_date, end_date, None, sorted_borders(intraday_borders)));
+    }
+
+    /// 適用期間のうち指定した曜日の営業時間の境界を追加する
+    pub(crate) fn add_range_weekday_borders(&mut self, start_date: NaiveDate, end_date: NaiveDate, weekday: Weekday, intraday_borders: &[TimeBorder]) {
+        self.range_borders.push((start_date, end_date, Some(weekday), sorted_borders(intraday_borders)));
+    }
+
+    /// 指定が存在しないかどうか
+    pub(crate) fn is_empty(&self) -> bool {
+        self.weekday_borders.is_empty() & self.date_borders.is_empty() & self.range_borders.is_empty()
+    }
+
+    /// 空でない営業時間の境界の指定が存在するかどうか
+    pub(crate) fn has_borders(&self) -> bool {
+        self.weekday_borders.values().any(|x|{!x.is_empty()})
+        | self.date_borders.values().any(|x|{!x.is_empty()})
+        | self.range_borders.iter().any(|x|{!x.3.is_empty()})
+    }
+
+    /// select_dateの営業時間の境界．指定が無い場合はNone
+    /// Argment
+    /// - select_date: 指定する日
+    pub(crate) fn borders_on(&self, select_date: NaiveDate) -> Option<&[TimeBorder]> {
+        if let Some(intraday_borders) = self.date_borders.get(&select_date) {
+            return Some(intraday_borders);
+        }
+        let find_range_borders = |select_weekday: Option<Weekday>| {
+            self.range_borders.iter().rev()
+                .find(|(start_date, end_date, weekday, _)|{
+                    (*start_date <= select_date) & (select_date <= *end_date) & (*weekday == select_weekday)
+                })
+                .map(|(_, _, _, intraday_borders)|{intraday_borders.as_slice()})
+        };
+        if let Some(intraday_borders) = find_range_borders(Some(select_date.weekday())) {
+            return Some(intraday_borders);
+        }
+        if let Some(intraday_borders) = self.weekday_borders.get(&select_date.weekday()) {
+            return Some(intraday_borders);
+        }
+        find_range_borders(None)
+    }
+
+    /// 日付・適用期間の指定の最初と最後の日．これより外側では営業時間の境界は曜日のみで決まる
+    pub(crate) fn boundary_dates(&self) -> Option<(NaiveDate, NaiveDate)> {
+        let dates = self.date_borders.keys().cloned()
+            .chain(self.range_borders.iter().flat_map(|(start_date, end_date, _, _)|{vec![*start_date, *end_date]}));
+        fold_boundary_dates(dates)
+    }
+
+    /// 日付・適用期間の指定の最初と最後の日．ただし日付の限界(NaiveDate::MIN・NaiveDate::MAX)まで続く適用期間の端は除く
+    pub(crate) fn finite_boundary_dates(&self) -> Option<(NaiveDate, NaiveDate)> {
+        let dates = self.date_borders.keys().cloned()
+            .chain(self.range_borders.iter().flat_map(|(start_date, end_date, _, _)|{vec![*start_date, *end_date]}))
+            .filter(|one_date|{(*one_date != NaiveDate::MIN) & (*one_date != NaiveDate::MAX)});
+        fold_boundary_dates(dates)
+    }
+
+    /// 営業時間の境界の指定が変わりうる日(日付の指定の当日と翌日・適用期間の開始日と終了日の翌日)．
+    /// 隣り合う変わりうる日の間では営業時間の境界は曜日のみで決まる
+    pub(crate) fn change_dates(&self) -> Vec<NaiveDate> {
+        self.date_borders.keys().flat_map(|one_date|{vec![Some(*one_date), one_date.succ_opt()]})
+            .chain(self.range_borders.iter().flat_map(|(start_date, end_date, _, _)|{vec![Some(*start_date), end_date.succ_opt()]}))
+            .flatten().collect()
+    }
+}
+
+/// 日付の最初と最後
+fn fold_boundary_dates(dates: impl Iterator<Item=NaiveDate>) -> Option<(NaiveDate, NaiveDate)> {
+    dates.fold(None, |boundary, one_date|{
+        match boundary {
+            Some((first_date, last_date)) => Some((one_date.min(first_date), one_date.max(last_date))),
+            None => Some((one_date, one_date))
+        }
+    })
+}
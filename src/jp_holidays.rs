@@ -0,0 +1,257 @@
+use std::collections::BTreeMap;
+use chrono::{NaiveDate, Datelike, Weekday, Duration};
+use once_cell::sync::Lazy;
+
+use crate::global::set_range_holiday_infos;
+use crate::holiday_info::{HolidayInfo, HolidayKind, SUBSTITUTE_HOLIDAY_NAME};
+use crate::equinox::{get_vernal_equinox, get_autumnal_equinox};
+
+// 祝日法の施行日
+static LAW_START_DATE: Lazy<NaiveDate> = Lazy::new(||{ NaiveDate::from_ymd(1948, 7, 20) });
+// 振替休日の施行日
+static SUBSTITUTE_START_DATE: Lazy<NaiveDate> = Lazy::new(||{ NaiveDate::from_ymd(1973, 4, 12) });
+// 国民の休日の施行日
+static CITIZENS_START_DATE: Lazy<NaiveDate> = Lazy::new(||{ NaiveDate::from_ymd(1985, 12, 27) });
+// 振替休日が次の祝日でない日まで移動するようになった年
+static SUBSTITUTE_CHAIN_START_YEAR: i32 = 2007;
+
+/// 第n週のweekday曜日を取得(ハッピーマンデー)
+/// Argments
+/// - year: 年
+/// - month: 月
+/// - weekday: 曜日
+/// - n: 第何週か(1から)
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: u32) -> NaiveDate {
+    let first_day = NaiveDate::from_ymd(year, month, 1);
+    let offset = (7 + weekday.num_days_from_monday() - first_day.weekday().num_days_from_monday()) % 7;
+    first_day + Duration::days((offset + 7 * (n - 1)) as i64)
+}
+
+/// 指定した年の「国民の祝日」(振替休日・国民の休日を除く)を名前とともに取得
+/// Argment
+/// - year: 年
+fn national_holidays(year: i32) -> BTreeMap<NaiveDate, &'static str> {
+    let mut holidays: BTreeMap<NaiveDate, &'static str> = BTreeMap::new();
+    let date = |month: u32, day: u32| {NaiveDate::from_ymd(year, month, day)};
+
+    // 元日
+    holidays.insert(date(1, 1), "元日");
+
+    // 成人の日
+    if year < 2000 {
+        holidays.insert(date(1, 15), "成人の日");
+    } else {
+        holidays.insert(nth_weekday(year, 1, Weekday::Mon, 2), "成人の日");
+    }
+
+    // 建国記念の日
+    if year >= 1967 {
+        holidays.insert(date(2, 11), "建国記念の日");
+    }
+
+    // 春分の日
+    holidays.insert(get_vernal_equinox(year).date(), "春分の日");
+
+    // 4月29日
+    if year < 1989 {
+        holidays.insert(date(4, 29), "天皇誕生日");
+    } else if year < 2007 {
+        holidays.insert(date(4, 29), "みどりの日");
+    } else {
+        holidays.insert(date(4, 29), "昭和の日");
+    }
+
+    // 憲法記念日・みどりの日・こどもの日
+    holidays.insert(date(5, 3), "憲法記念日");
+    if year >= 2007 {
+        holidays.insert(date(5, 4), "みどりの日");
+    }
+    holidays.insert(date(5, 5), "こどもの日");
+
+    // 海の日
+    match year {
+        1996..=2002 => {holidays.insert(date(7, 20), "海の日");},
+        2020 => {holidays.insert(date(7, 23), "海の日");},
+        2021 => {holidays.insert(date(7, 22), "海の日");},
+        year if year >= 2003 => {holidays.insert(nth_weekday(year, 7, Weekday::Mon, 3), "海の日");},
+        _ => {}
+    }
+
+    // 山の日
+    match year {
+        2020 => {holidays.insert(date(8, 10), "山の日");},
+        2021 => {holidays.insert(date(8, 8), "山の日");},
+        year if year >= 2016 => {holidays.insert(date(8, 11), "山の日");},
+        _ => {}
+    }
+
+    // 敬老の日
+    match year {
+        1966..=2002 => {holidays.insert(date(9, 15), "敬老の日");},
+        year if year >= 2003 => {holidays.insert(nth_weekday(year, 9, Weekday::Mon, 3), "敬老の日");},
+        _ => {}
+    }
+
+    // 秋分の日
+    holidays.insert(get_autumnal_equinox(year).date(), "秋分の日");
+
+    // 体育の日・スポーツの日
+    match year {
+        1966..=1999 => {holidays.insert(date(10, 10), "体育の日");},
+        2000..=2018 => {holidays.insert(nth_weekday(year, 10, Weekday::Mon, 2), "体育の日");},
+        2019 => {holidays.insert(nth_weekday(year, 10, Weekday::Mon, 2), "体育の日（スポーツの日）");},
+        2020 => {holidays.insert(date(7, 24), "スポーツの日");},
+        2021 => {holidays.insert(date(7, 23), "スポーツの日");},
+        year if year >= 2022 => {holidays.insert(nth_weekday(year, 10, Weekday::Mon, 2), "スポーツの日");},
+        _ => {}
+    }
+
+    // 文化の日・勤労感謝の日
+    holidays.insert(date(11, 3), "文化の日");
+    holidays.insert(date(11, 23), "勤労感謝の日");
+
+    // 天皇誕生日
+    match year {
+        1989..=2018 => {holidays.insert(date(12, 23), "天皇誕生日");},
+        year if year >= 2020 => {holidays.insert(date(2, 23), "天皇誕生日");},
+        _ => {}
+    }
+
+    // 皇室の行事などによる一度きりの祝日
+    match year {
+        1959 => {holidays.insert(date(4, 10), "結婚の儀");},
+        1989 => {holidays.insert(date(2, 24), "大喪の礼");},
+        1990 => {holidays.insert(date(11, 12), "即位礼正殿の儀");},
+        1993 => {holidays.insert(date(6, 9), "結婚の儀");},
+        2019 => {
+            holidays.insert(date(5, 1), "休日（祝日扱い）");
+            holidays.insert(date(10, 22), "休日（祝日扱い）");
+        },
+        _ => {}
+    }
+
+    // 祝日法の施行前の日を除く
+    holidays.into_iter().filter(|(holiday, _)|{holiday >= &LAW_START_DATE}).collect()
+}
+
+/// 指定した年の振替休日を取得
+/// Argment
+/// - national_holidays: その年の国民の祝日
+fn substitute_holidays(national_holidays: &BTreeMap<NaiveDate, &'static str>) -> Vec<NaiveDate> {
+    let mut substitute_holidays_vec: Vec<NaiveDate> = Vec::new();
+
+    for holiday in national_holidays.keys() {
+        if (holiday.weekday() != Weekday::Sun) | (holiday < &SUBSTITUTE_START_DATE) {
+            continue;
+        }
+        let mut substitute_holiday = holiday.succ();
+        if holiday.year() >= SUBSTITUTE_CHAIN_START_YEAR {  // 祝日でない日まで移動
+            while national_holidays.contains_key(&substitute_holiday) {
+                substitute_holiday = substitute_holiday.succ();
+            }
+        } else if national_holidays.contains_key(&substitute_holiday) {  // 翌日が祝日の場合は振替なし
+            continue;
+        }
+        substitute_holidays_vec.push(substitute_holiday);
+    }
+    substitute_holidays_vec
+}
+
+/// 指定した年の国民の休日(祝日に挟まれた日)を取得
+/// Argments
+/// - national_holidays: その年の国民の祝日
+/// - substitute_holidays: その年の振替休日
+fn citizens_holidays(national_holidays: &BTreeMap<NaiveDate, &'static str>, substitute_holidays: &[NaiveDate]) -> Vec<NaiveDate> {
+    national_holidays.keys().map(|holiday|{holiday.succ()})
+        .filter(|one_day|{one_day >= &CITIZENS_START_DATE})
+        .filter(|one_day|{national_holidays.contains_key(&one_day.succ())})
+        .filter(|one_day|{!national_holidays.contains_key(one_day)})
+        .filter(|one_day|{!substitute_holidays.contains(one_day)})
+        .filter(|one_day|{one_day.weekday() != Weekday::Sun})
+        .collect()
+}
+
+/// 祝日法の規則からstart_yearからend_yearまでの祝日・休日を名前・種類とともに取得する．
+/// 振替休日・国民の休日の名前は内閣府のデータと同様に"休日"となる．
+/// Argments
+/// - start_year: 開始年(その年の1月1日から)
+/// - end_year: 終了年(その年の12月31日まで)
+///
+/// Return
+/// 日付でソートされた休日の情報のベクター
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::jp_holidays::*;
+/// use rs_workdays::holiday_info::HolidayKind;
+/// let holiday_infos = get_jp_holiday_infos(2026, 2026);
+/// let citizens_holiday = holiday_infos.iter().find(|x|{x.date==NaiveDate::from_ymd(2026, 9, 22)}).unwrap();
+/// assert_eq!(citizens_holiday.kind, HolidayKind::Citizens);
+/// ~~~~
+pub fn get_jp_holiday_infos(start_year: i32, end_year: i32) -> Vec<HolidayInfo> {
+    let mut holiday_infos: Vec<HolidayInfo> = Vec::new();
+
+    for year in start_year..=end_year {
+        let national_holidays_map = national_holidays(year);
+        let substitute_holidays_vec = substitute_holidays(&national_holidays_map);
+        let citizens_holidays_vec = citizens_holidays(&national_holidays_map, &substitute_holidays_vec);
+
+        let make_info = |date: NaiveDate, name: &str, kind: HolidayKind| {HolidayInfo {date, name: name.to_string(), kind}};
+        let mut year_holiday_infos: Vec<HolidayInfo> = national_holidays_map.into_iter()
+            .map(|(date, name)|{make_info(date, name, HolidayKind::National)}).collect();
+        year_holiday_infos.extend(substitute_holidays_vec.into_iter().map(|x|{make_info(x, SUBSTITUTE_HOLIDAY_NAME, HolidayKind::Substitute)}));
+        year_holiday_infos.extend(citizens_holidays_vec.into_iter().map(|x|{make_info(x, SUBSTITUTE_HOLIDAY_NAME, HolidayKind::Citizens)}));
+        year_holiday_infos.sort_by_key(|x|{x.date});
+        holiday_infos.extend(year_holiday_infos);
+    }
+    holiday_infos
+}
+
+/// 祝日法の規則からstart_yearからend_yearまでの祝日・休日を名前とともに取得する．
+/// 振替休日・国民の休日の名前は内閣府のデータと同様に"休日"となる．
+/// Argments
+/// - start_year: 開始年(その年の1月1日から)
+/// - end_year: 終了年(その年の12月31日まで)
+///
+/// Return
+/// 日付でソートされた(祝日, 名前)のベクター
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::jp_holidays::*;
+/// let holidays = get_jp_holidays_with_names(2030, 2030);
+/// assert_eq!(holidays[0], (NaiveDate::from_ymd(2030, 1, 1), "元日".to_string()));
+/// assert!(holidays.contains(&(NaiveDate::from_ymd(2030, 5, 6), "休日".to_string())));
+/// ~~~~
+pub fn get_jp_holidays_with_names(start_year: i32, end_year: i32) -> Vec<(NaiveDate, String)> {
+    get_jp_holiday_infos(start_year, end_year).into_iter().map(|x|{(x.date, x.name)}).collect()
+}
+
+/// 祝日法の規則からstart_yearからend_yearまでの祝日・休日を取得する
+/// Argments
+/// - start_year: 開始年(その年の1月1日から)
+/// - end_year: 終了年(その年の12月31日まで)
+///
+/// Return
+/// 祝日のベクター
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::jp_holidays::*;
+/// let holidays = get_jp_holidays(2030, 2030);
+/// assert!(holidays.contains(&NaiveDate::from_ymd(2030, 9, 23)));
+/// ~~~~
+pub fn get_jp_holidays(start_year: i32, end_year: i32) -> Vec<NaiveDate> {
+    get_jp_holiday_infos(start_year, end_year).into_iter().map(|x|{x.date}).collect()
+}
+
+/// 祝日法の規則から計算した祝日を祝日に設定する
+/// Argments
+/// - start_year: 利用範囲の開始年
+/// - end_year: 利用範囲の終了年
+pub fn set_jp_holidays(start_year: i32, end_year: i32) {
+    set_range_holiday_infos(&get_jp_holiday_infos(start_year, end_year), start_year, end_year);
+}
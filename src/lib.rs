@@ -1,5 +1,21 @@
 //! # 営業日・営業時間を取得・抽出
 
+/// 祝日・休日曜日・営業時間をまとめたカレンダー
+pub mod calendar;
+pub use calendar::{WorkCalendar, TimeBorder, Session, SessionKind, HolidayCoverage, CoveragePolicy, SessionAttribution, SnapPolicy};
+
+/// 営業日のビットマップによる索引
+mod workday_index;
+
+/// 曜日・日付・適用期間ごとの営業時間
+mod intraday_schedule;
+
+/// 営業時間の累積の索引
+mod business_index;
+
+/// カレンダーの和・積・差の合成
+mod compose;
+
 /// 祝日・休日曜日・営業時間などの設定
 pub mod global;
 
@@ -7,25 +23,105 @@ pub use global::{
     set_holidays_csvs, 
     set_intraday_borders, 
     set_holiday_weekdays, 
+    set_weekday_intraday_borders,
+    set_date_intraday_borders,
+    add_range_intraday_borders,
+    add_range_weekday_intraday_borders,
+    clear_intraday_schedule,
+    set_session_attribution,
+    set_sessions,
     set_range_holidays,
     add_range_holidays,
+    set_range_holiday_infos,
+    add_range_holiday_infos,
+    set_coverage_policy,
+    set_coverage_warning_handler,
+    set_snap_policy,
+    set_work_calendar,
     get_range_holidays,
     get_holiday_weekdays,
-    get_intraday_borders
+    get_intraday_borders,
+    get_intraday_borders_on,
+    get_sessions,
+    get_coverage,
+    check_coverage,
+    get_work_calendar
 };
 
+/// 名前付きのカレンダーの登録・取得
+pub mod registry;
+pub use registry::{calendar, register_calendar, remove_calendar, calendar_names, use_calendar};
+
+#[cfg(feature="config")]
+pub use registry::{load_calendars_config, load_calendars_config_file};
+
 /// 営業日の取得・チェック
 pub mod workdays;
 pub use workdays::*;
 
+/// 営業日の遅延イテレーター
+pub mod workday_iter;
+pub use workday_iter::{WorkdayIter, iter_workdays, iter_workdays_range};
+
+/// 営業日の調整規約
+pub mod roll;
+pub use roll::{RollConvention, adjust};
+
+/// 日数計算の規約による年率
+pub mod day_count;
+pub use day_count::{DayCount, year_fraction};
+
 /// 営業時間内かチェック・営業時間のDuration演算
 pub mod intraday;
 pub use intraday::*;
 
+/// 名前付きの取引セッションの検索
+pub mod session;
+pub use session::{DatedSession, current_session, next_session_start, try_next_session_start, sessions_on};
+
+/// 営業時間内の等間隔の日時の遅延イテレーター
+pub mod intraday_range;
+pub use intraday_range::{IntradayRange, Label, generate_intraday_range};
+
+/// 日時と営業時間の序数の相互変換
+pub mod business_ordinal;
+pub use business_ordinal::{Resolution, to_business_ordinal, try_to_business_ordinal, from_business_ordinal, try_from_business_ordinal};
+
 /// 営業時間内のデータの抽出
 pub mod extract;
 pub use extract::*;
 
+/// rayonによる並列の抽出
+#[cfg(feature="rayon")]
+pub mod extract_par;
+
+/// arrowの配列からの抽出
+#[cfg(feature="arrow")]
+pub mod extract_arrow;
+
+/// polarsのSeriesからの抽出
+#[cfg(feature="polars")]
+pub mod extract_polars;
+
+/// 祝日の名前・種類
+pub mod holiday_info;
+pub use holiday_info::{HolidayInfo, HolidayKind, make_holiday_infos, get_holiday_info, get_holiday_name, get_holidays_with_names};
+
+/// 春分・秋分の計算
+pub mod equinox;
+pub use equinox::{get_vernal_equinox, get_autumnal_equinox};
+
+/// 祝日法の規則による祝日の計算
+pub mod jp_holidays;
+pub use jp_holidays::{get_jp_holidays, get_jp_holidays_with_names, get_jp_holiday_infos, set_jp_holidays};
+
+/// タイムゾーンを考慮した営業時間の判定・演算
+#[cfg(feature="tz")]
+pub mod timezone;
+
+#[cfg(feature="tz")]
+pub use timezone::*;
+
 /// エラー
 pub mod error;
 pub use error::Error;
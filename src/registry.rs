@@ -0,0 +1,317 @@
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard, PoisonError};
+use std::collections::HashMap;
+use chrono::NaiveDate;
+use once_cell::sync::Lazy;
+
+#[cfg(feature="config")]
+use std::collections::HashSet;
+#[cfg(feature="config")]
+use std::path::Path;
+#[cfg(feature="config")]
+use chrono::{NaiveTime, Weekday};
+
+use crate::error::Error;
+use crate::calendar::WorkCalendar;
+use crate::holiday_info::{HolidayInfo, HolidayKind};
+use crate::global::set_work_calendar;
+#[cfg(feature="config")]
+use crate::calendar::TimeBorder;
+
+/// 東京証券取引所の年末年始の休業日の名前
+pub static TSE_YEAR_END_CLOSURE_NAME: &str = "年末年始休業";
+
+// グローバル変数
+// 名前付きのカレンダー(組み込みのJP・TSEと登録したカレンダー)
+pub static CALENDAR_REGISTRY: Lazy<RwLock<HashMap<String, WorkCalendar>>> = Lazy::new(|| {
+    let mut calendars: HashMap<String, WorkCalendar> = HashMap::new();
+    calendars.insert("JP".to_string(), WorkCalendar::jp());
+    calendars.insert("TSE".to_string(), WorkCalendar::tse());
+    RwLock::new(calendars)
+});
+
+/// 名前付きのカレンダーの読み込み用のロックを取得する．
+fn read_calendar_registry() -> RwLockReadGuard<'static, HashMap<String, WorkCalendar>> {
+    CALENDAR_REGISTRY.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// 名前付きのカレンダーの書き込み用のロックを取得する．
+fn write_calendar_registry() -> RwLockWriteGuard<'static, HashMap<String, WorkCalendar>> {
+    CALENDAR_REGISTRY.write().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// 東京証券取引所の年末年始の休業日(12月31日・1月2日・1月3日)．1月1日は国民の祝日とする
+/// Argments
+/// - start_year: 開始年
+/// - end_year: 終了年
+fn tse_year_end_closures(start_year: i32, end_year: i32) -> Vec<HolidayInfo> {
+    (start_year..=end_year).flat_map(|year|{
+        vec![NaiveDate::from_ymd(year, 1, 2), NaiveDate::from_ymd(year, 1, 3), NaiveDate::from_ymd(year, 12, 31)]
+    }).map(|date|{
+        HolidayInfo {date, name: TSE_YEAR_END_CLOSURE_NAME.to_string(), kind: HolidayKind::Other}
+    }).collect()
+}
+
+impl WorkCalendar {
+    /// 国民の祝日のみを休日とするカレンダー(内閣府の祝日データsource/holidays.csvの祝日と土日)．
+    /// 祝日は2016年から2025年のうちcsvに含まれる年(現在は2016年から2022年，`coverage()`を参照)のみで，それ以降の祝日は営業日となる．
+    /// csvのパスは作業ディレクトリからの相対パスであり，読み込めない場合は祝日の無いカレンダーとなる．
+    /// 営業時間は東京証券取引所の取引時間(9時～11時30分・12時30分～15時)で，`WorkCalendar::default()`と同じ
+    pub fn jp() -> Self {
+        WorkCalendar::default()
+    }
+
+    /// 東京証券取引所のカレンダー．国民の祝日のみのカレンダーに祝日データの範囲の年(現在は2016年から2022年)の年末年始の休業日(12月31日・1月2日・1月3日)を加える
+    pub fn tse() -> Self {
+        let mut work_calendar = WorkCalendar::jp();
+        if let Some(coverage) = work_calendar.coverage() {
+            let closures = tse_year_end_closures(coverage.start_year, coverage.end_year);
+            work_calendar.add_range_holiday_infos(&closures, coverage.start_year, coverage.end_year);
+        }
+        work_calendar
+    }
+}
+
+/// 名前付きのカレンダーの取得
+/// Argment
+/// - name: カレンダーの名前("JP"・"TSE"または登録した名前)
+///
+/// Return
+/// カレンダーのコピー．登録されていない名前の場合はエラー
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::calendar;
+///
+/// let tse_calendar = calendar("TSE").unwrap();
+/// let jp_calendar = calendar("JP").unwrap();
+/// assert!(!tse_calendar.check_workday(NaiveDate::from_ymd(2020, 12, 31)));
+/// assert!(jp_calendar.check_workday(NaiveDate::from_ymd(2020, 12, 31)));
+/// assert!(calendar("unknown").is_err());
+/// ~~~~
+pub fn calendar(name: &str) -> Result<WorkCalendar, Error> {
+    read_calendar_registry().get(name).cloned()
+        .ok_or_else(||{Error::UnknownCalendar{name: name.to_string()}})
+}
+
+/// カレンダーを名前を付けて登録する．同じ名前のカレンダーは置き換える
+/// Argments
+/// - name: カレンダーの名前
+/// - work_calendar: 登録するカレンダー
+pub fn register_calendar(name: &str, work_calendar: WorkCalendar) {
+    write_calendar_registry().insert(name.to_string(), work_calendar);
+}
+
+/// 登録されたカレンダーの削除
+/// Argment
+/// - name: カレンダーの名前
+///
+/// Return
+/// 削除したカレンダー．登録されていない場合はNone
+pub fn remove_calendar(name: &str) -> Option<WorkCalendar> {
+    write_calendar_registry().remove(name)
+}
+
+/// 登録されたカレンダーの名前の取得
+/// Return
+/// - 名前のvec(昇順)
+pub fn calendar_names() -> Vec<String> {
+    let mut names: Vec<String> = read_calendar_registry().keys().cloned().collect();
+    names.sort();
+    names
+}
+
+/// 名前付きのカレンダーをデフォルトのカレンダーにする
+/// Argment
+/// - name: カレンダーの名前
+pub fn use_calendar(name: &str) -> Result<(), Error> {
+    set_work_calendar(calendar(name)?);
+    Ok(())
+}
+
+/// 設定のエラーを作成する
+#[cfg(feature="config")]
+fn invalid_config(message: String) -> Error {
+    Error::InvalidConfig{message}
+}
+
+/// 設定の値を文字列として取得する(tomlの日付・時間はその表記とする)
+#[cfg(feature="config")]
+fn value_str(value: &toml::Value, key: &str) -> Result<String, Error> {
+    match value {
+        toml::Value::String(value_str) => Ok(value_str.clone()),
+        toml::Value::Datetime(datetime) => Ok(datetime.to_string()),
+        _ => Err(invalid_config(format!("{} must be a string", key)))
+    }
+}
+
+/// 設定の値を配列として取得する
+#[cfg(feature="config")]
+fn value_array<'a>(value: &'a toml::Value, key: &str) -> Result<&'a [toml::Value], Error> {
+    value.as_array().map(|x|{x.as_slice()}).ok_or_else(||{invalid_config(format!("{} must be an array", key))})
+}
+
+/// 設定の値を年として取得する
+#[cfg(feature="config")]
+fn value_year(table: &toml::value::Table, key: &str, default_year: i32) -> Result<i32, Error> {
+    match table.get(key) {
+        Some(value) => value.as_integer().map(|x|{x as i32}).ok_or_else(||{invalid_config(format!("{} must be an integer", key))}),
+        None => Ok(default_year)
+    }
+}
+
+/// 設定の時間("09:00"・"09:00:00"またはtomlの時間)を読み込む
+#[cfg(feature="config")]
+fn parse_time(value: &toml::Value) -> Result<NaiveTime, Error> {
+    let time_str = value_str(value, "time")?;
+    NaiveTime::parse_from_str(&time_str, "%H:%M:%S")
+        .or_else(|_|{NaiveTime::parse_from_str(&time_str, "%H:%M")})
+        .map_err(|_|{invalid_config(format!("cannot parse time {:?}", time_str))})
+}
+
+/// 設定のテーブルからカレンダーを作成する．baseに指定したカレンダーは設定内・登録済みの順に探す
+/// Argments
+/// - name: カレンダーの名前
+/// - calendar_tables: 設定のカレンダーのテーブル
+/// - built_calendars: 作成済みのカレンダー
+/// - building_names: 作成中のカレンダーの名前(baseの循環の検出用)
+#[cfg(feature="config")]
+fn build_calendar(
+    name: &str,
+    calendar_tables: &toml::value::Table,
+    built_calendars: &mut HashMap<String, WorkCalendar>,
+    building_names: &mut Vec<String>
+) -> Result<WorkCalendar, Error> {
+    if let Some(work_calendar) = built_calendars.get(name) {
+        return Ok(work_calendar.clone());
+    }
+    let table = match calendar_tables.get(name) {
+        Some(value) => value.as_table().ok_or_else(||{invalid_config(format!("calendars.{} must be a table", name))})?,
+        None => return calendar(name)
+    };
+    if building_names.iter().any(|x|{x == name}) {
+        return Err(invalid_config(format!("circular base of calendar {:?}", name)));
+    }
+    building_names.push(name.to_string());
+
+    let mut work_calendar = match table.get("base") {
+        Some(base) => {
+            let base_name = value_str(base, "base")?;
+            if base_name == name {  // 同じ名前の登録済みのカレンダーを元にする
+                calendar(name)?
+            } else {
+                build_calendar(&base_name, calendar_tables, built_calendars, building_names)?
+            }
+        },
+        None => WorkCalendar::new(Vec::new(), [Weekday::Sat, Weekday::Sun].iter().cloned().collect(), Vec::new())
+    };
+    let start_year = value_year(table, "start_year", i32::MIN)?;
+    let end_year = value_year(table, "end_year", i32::MAX)?;
+
+    if let Some(holidays_csvs) = table.get("holidays_csvs") {
+        let path_str_vec = value_array(holidays_csvs, "holidays_csvs")?.iter()
+            .map(|x|{value_str(x, "holidays_csvs")}).collect::<Result<Vec<String>, Error>>()?;
+        work_calendar.set_holidays_csvs(&path_str_vec, start_year, end_year)?;
+    }
+    if let Some(holidays) = table.get("holidays") {
+        let holidays_vec = value_array(holidays, "holidays")?.iter().map(|x|{
+            let date_str = value_str(x, "holidays")?;
+            NaiveDate::parse_from_str(&date_str, "%Y-%m-%d").map_err(|_|{Error::ParseDateError{date_str}})
+        }).collect::<Result<Vec<NaiveDate>, Error>>()?;
+        work_calendar.add_range_holidays(&holidays_vec, start_year, end_year);
+    }
+    if let Some(holiday_weekdays) = table.get("holiday_weekdays") {
+        let holiday_weekdays_set = value_array(holiday_weekdays, "holiday_weekdays")?.iter().map(|x|{
+            let weekday_str = value_str(x, "holiday_weekdays")?;
+            weekday_str.parse::<Weekday>().map_err(|_|{invalid_config(format!("cannot parse weekday {:?}", weekday_str))})
+        }).collect::<Result<HashSet<Weekday>, Error>>()?;
+        work_calendar.set_holiday_weekdays(&holiday_weekdays_set);
+    }
+    if let Some(intraday_borders) = table.get("intraday_borders") {
+        let intraday_borders_vec = value_array(intraday_borders, "intraday_borders")?.iter().map(|x|{
+            match value_array(x, "intraday_borders")? {
+                [start, end] => Ok(TimeBorder {start: parse_time(start)?, end: parse_time(end)?}),
+                _ => Err(invalid_config("intraday_borders must be pairs of start and end".to_string()))
+            }
+        }).collect::<Result<Vec<TimeBorder>, Error>>()?;
+        work_calendar.set_intraday_borders(&intraday_borders_vec);
+    }
+
+    building_names.pop();
+    built_calendars.insert(name.to_string(), work_calendar.clone());
+    Ok(work_calendar)
+}
+
+/// tomlの設定からカレンダーを登録する(feature="config")．
+/// `[calendars.<名前>]`のテーブルごとにカレンダーを作成し，`default`を指定した場合はそのカレンダーをデフォルトのカレンダーにする．
+/// テーブルのキーは以下の順に適用する
+/// - base: 元にするカレンダーの名前(設定内または登録済み)．無い場合は祝日・営業時間の無い土日休みのカレンダー
+/// - holidays_csvs: 祝日のcsvのパスの配列(祝日を置き換える)
+/// - holidays: 追加する祝日("%Y-%m-%d")の配列
+/// - holiday_weekdays: 休日曜日("Sat"など)の配列
+/// - intraday_borders: 営業時間境界(開始時間と終了時間の組)の配列
+/// - start_year・end_year: holidays_csvs・holidaysの利用する年の範囲
+///
+/// いずれかのカレンダーの作成に失敗した場合は何も登録しない
+/// Argment
+/// - config_str: tomlの文字列
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::{calendar, load_calendars_config};
+///
+/// load_calendars_config(r#"
+/// [calendars.ACME-corp]
+/// base = "TSE"
+/// holidays = ["2021-08-16"]
+/// holiday_weekdays = ["Sat", "Sun"]
+/// intraday_borders = [["09:00", "12:00"], ["13:00", "17:30"]]
+/// "#).unwrap();
+/// let acme_calendar = calendar("ACME-corp").unwrap();
+/// assert!(!acme_calendar.check_workday(NaiveDate::from_ymd(2021, 8, 16)));
+/// assert!(!acme_calendar.check_workday(NaiveDate::from_ymd(2020, 12, 31)));
+/// ~~~~
+#[cfg(feature="config")]
+pub fn load_calendars_config(config_str: &str) -> Result<(), Error> {
+    let config: toml::Value = config_str.parse().map_err(|err: toml::de::Error|{invalid_config(err.to_string())})?;
+    let empty_table = toml::value::Table::new();
+    let calendar_tables = match config.get("calendars") {
+        Some(value) => value.as_table().ok_or_else(||{invalid_config("calendars must be a table".to_string())})?,
+        None => &empty_table
+    };
+
+    let mut built_calendars: HashMap<String, WorkCalendar> = HashMap::new();
+    for name in calendar_tables.keys() {
+        build_calendar(name, calendar_tables, &mut built_calendars, &mut Vec::new())?;
+    }
+    let default_calendar = match config.get("default") {
+        Some(default_name) => {
+            let default_name = value_str(default_name, "default")?;
+            match built_calendars.get(&default_name) {
+                Some(work_calendar) => Some(work_calendar.clone()),
+                None => Some(calendar(&default_name)?)
+            }
+        },
+        None => None
+    };
+
+    // 全て作成できてから登録する
+    write_calendar_registry().extend(built_calendars);
+    if let Some(default_calendar) = default_calendar {
+        set_work_calendar(default_calendar);
+    }
+    Ok(())
+}
+
+/// tomlの設定ファイルからカレンダーを登録する(feature="config")．設定の形式は`load_calendars_config`と同じ
+/// Argment
+/// - config_path: 設定ファイルのパス
+#[cfg(feature="config")]
+pub fn load_calendars_config_file<P:AsRef<Path>>(config_path: P) -> Result<(), Error> {
+    let config_path: &Path = config_path.as_ref();
+    let config_str = std::fs::read_to_string(config_path)
+        .map_err(|err|{invalid_config(format!("cannot read {:?}: {}", config_path, err))})?;
+    load_calendars_config(&config_str)
+}
+
@@ -1,6 +1,7 @@
 use chrono::NaiveDate;
 
-use crate::global::set_range_holidays;
+use crate::global::set_range_holiday_infos;
+use crate::holiday_info::make_holiday_infos;
 use crate::error::Error;
 
 /// 内閣府による祝日データを取得して祝日に設定する(同期)(feature!="wasm")
@@ -16,17 +17,18 @@ pub fn request_holidays_naikaku(start_year: i32, end_year: i32) -> Result<(), Er
     let (decoded_content, _, _) = encoding_rs::SHIFT_JIS.decode(&res_bytes);
     let mut rdr = csv::ReaderBuilder::new().has_headers(true).from_reader(decoded_content.as_bytes());
 
-    let mut holidays: Vec<NaiveDate> = Vec::new();
+    let mut holidays_with_names: Vec<(NaiveDate, String)> = Vec::new();
 
     for result in rdr.records() {
         let record = result.map_err(|_|{Error::ReadCsvError{path_str: url.to_string()}})?;
-        holidays.push(
+        holidays_with_names.push((
             NaiveDate::parse_from_str(record[0].into(), "%Y/%m/%d")
-            .map_err(|_|{Error::ParseDateError{date_str:record[0].into()}})?
-        );
+            .map_err(|_|{Error::ParseDateError{date_str:record[0].into()}})?,
+            record[1].into()
+        ));
     }
 
-    set_range_holidays(&holidays, start_year, end_year);
+    set_range_holiday_infos(&make_holiday_infos(holidays_with_names), start_year, end_year);
     Ok(())
 }
 
@@ -43,16 +45,17 @@ pub async fn request_holidays_naikaku(start_year: i32, end_year: i32) -> Result<
     let (decoded_content, _, _) = encoding_rs::SHIFT_JIS.decode(&res_bytes);
     let mut rdr = csv::ReaderBuilder::new().has_headers(true).from_reader(decoded_content.as_bytes());
 
-    let mut holidays: Vec<NaiveDate> = Vec::new();
+    let mut holidays_with_names: Vec<(NaiveDate, String)> = Vec::new();
 
     for result in rdr.records() {
         let record = result.map_err(|_|{Error::ReadCsvError{path_str: url.to_string()}})?;
-        holidays.push(
+        holidays_with_names.push((
             NaiveDate::parse_from_str(record[0].into(), "%Y/%m/%d")
-            .map_err(|_|{Error::ParseDateError{date_str:record[0].into()}})?
-        );
+            .map_err(|_|{Error::ParseDateError{date_str:record[0].into()}})?,
+            record[1].into()
+        ));
     }
 
-    set_range_holidays(&holidays, start_year, end_year);
+    set_range_holiday_infos(&make_holiday_infos(holidays_with_names), start_year, end_year);
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,154 @@
+use chrono::{NaiveDate, Datelike};
+
+use crate::calendar::{WorkCalendar, CoveragePolicy};
+use crate::error::Error;
+use crate::global::{read_work_calendar, try_read_work_calendar};
+
+/// 営業日の調整規約(ISDAのBusiness Day Convention)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RollConvention {
+    /// 次の営業日
+    Following,
+    /// 次の営業日(月をまたぐ場合は前の営業日)
+    ModifiedFollowing,
+    /// 前の営業日
+    Preceding,
+    /// 前の営業日(月をまたぐ場合は次の営業日)
+    ModifiedPreceding,
+    /// 月末の日(その月の末日または最終営業日)はその月の最終営業日．それ以外はModifiedFollowing
+    EndOfMonth,
+    /// 最も近い営業日(等距離の場合は次の営業日)
+    Nearest
+}
+
+/// select_dateの月の末日を取得
+/// Argment
+/// - select_date: 指定する日
+fn last_day_of_month(select_date: NaiveDate) -> NaiveDate {
+    let (year, month) = if select_date.month() == 12 {
+        (select_date.year() + 1, 1)
+    } else {
+        (select_date.year(), select_date.month() + 1)
+    };
+    NaiveDate::from_ymd(year, month, 1).pred()
+}
+
+/// 二つの日が同じ月であるかどうか
+fn is_same_month(date_1: NaiveDate, date_2: NaiveDate) -> bool {
+    (date_1.year() == date_2.year()) & (date_1.month() == date_2.month())
+}
+
+
+impl WorkCalendar {
+    /// select_dateをcoverage_policyに従って営業日に調整する
+    fn _adjust(&self, select_date: NaiveDate, convention: RollConvention, coverage_policy: CoveragePolicy) -> Result<NaiveDate, Error> {
+        match convention {
+            RollConvention::Following => self._get_near_workday(select_date, true, coverage_policy),
+            RollConvention::Preceding => self._get_near_workday(select_date, false, coverage_policy),
+            RollConvention::ModifiedFollowing => {
+                let following_day = self._get_near_workday(select_date, true, coverage_policy)?;
+                if is_same_month(following_day, select_date) {
+                    Ok(following_day)
+                } else {
+                    self._get_near_workday(select_date, false, coverage_policy)
+                }
+            },
+            RollConvention::ModifiedPreceding => {
+                let preceding_day = self._get_near_workday(select_date, false, coverage_policy)?;
+                if is_same_month(preceding_day, select_date) {
+                    Ok(preceding_day)
+                } else {
+                    self._get_near_workday(select_date, true, coverage_policy)
+                }
+            },
+            RollConvention::EndOfMonth => {
+                let last_workday = self._get_near_workday(last_day_of_month(select_date), false, coverage_policy)?;
+                if (select_date == last_day_of_month(select_date)) | (select_date == last_workday) {
+                    Ok(last_workday)
+                } else {
+                    self._adjust(select_date, RollConvention::ModifiedFollowing, coverage_policy)
+                }
+            },
+            RollConvention::Nearest => {
+                if self.check_workday(select_date) {
+                    return Ok(select_date);
+                }
+                let following_day = self._get_next_workday(select_date, 1, coverage_policy)?;
+                let preceding_day = self._get_previous_workday(select_date, 1, coverage_policy)?;
+                if (following_day - select_date) <= (select_date - preceding_day) {
+                    Ok(following_day)
+                } else {
+                    Ok(preceding_day)
+                }
+            }
+        }
+    }
+
+    /// select_dateを規約に従って営業日に調整する
+    /// Argments
+    /// - select_date: 指定する日
+    /// - convention: 営業日の調整規約
+    ///
+    /// Return
+    /// 調整後の営業日．営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラー
+    pub fn try_adjust(&self, select_date: NaiveDate, convention: RollConvention) -> Result<NaiveDate, Error> {
+        self._adjust(select_date, convention, self.coverage_policy)
+    }
+
+    /// select_dateを規約に従って営業日に調整する
+    /// Argments
+    /// - select_date: 指定する日
+    /// - convention: 営業日の調整規約
+    ///
+    /// Return
+    /// 調整後の営業日(営業日が存在しない場合はパニックする)
+    pub fn adjust(&self, select_date: NaiveDate, convention: RollConvention) -> NaiveDate {
+        self._adjust(select_date, convention, CoveragePolicy::Ignore).unwrap()
+    }
+}
+
+
+/// select_dateを規約に従って営業日に調整する
+/// Argments
+/// - select_date: 指定する日
+/// - convention: 営業日の調整規約
+///     - Following: 次の営業日
+///     - ModifiedFollowing: 次の営業日．月をまたぐ場合は前の営業日
+///     - Preceding: 前の営業日
+///     - ModifiedPreceding: 前の営業日．月をまたぐ場合は次の営業日
+///     - EndOfMonth: 月末の日(その月の末日または最終営業日)はその月の最終営業日．それ以外はModifiedFollowing
+///     - Nearest: 最も近い営業日．等距離の場合は次の営業日
+///
+/// Return
+/// 調整後の営業日
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::roll::*;
+/// let select_date = NaiveDate::from_ymd(2019, 4, 30);  // 10連休中
+/// assert_eq!(adjust(select_date, RollConvention::Following), NaiveDate::from_ymd(2019, 5, 7));
+/// assert_eq!(adjust(select_date, RollConvention::ModifiedFollowing), NaiveDate::from_ymd(2019, 4, 26));
+/// ~~~~
+pub fn adjust(select_date: NaiveDate, convention: RollConvention) -> NaiveDate {
+    read_work_calendar().adjust(select_date, convention)
+}
+
+/// select_dateを規約に従って営業日に調整する．営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラーを返す
+/// Argments
+/// - select_date: 指定する日
+/// - convention: 営業日の調整規約
+///
+/// Return
+/// 調整後の営業日．デフォルトのカレンダーのロックがポイズンされている場合もエラー
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::roll::*;
+/// let select_date = NaiveDate::from_ymd(2019, 5, 1);  // 10連休中
+/// assert_eq!(try_adjust(select_date, RollConvention::ModifiedPreceding).unwrap(), NaiveDate::from_ymd(2019, 5, 7));
+/// ~~~~
+pub fn try_adjust(select_date: NaiveDate, convention: RollConvention) -> Result<NaiveDate, Error> {
+    try_read_work_calendar()?.try_adjust(select_date, convention)
+}
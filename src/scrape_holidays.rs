@@ -14,7 +14,7 @@ use crate::error::Error;
 /// - holidays: 祝日データ
 /// - holiday_names: 祝日名データ
 /// - source_path: 保存パス
-fn write_csv_file<P:AsRef<Path>>(holidays: &Vec<NaiveDate>, holiday_names: &Vec<String>, source_path: P) -> Result<(), Error>{
+fn write_csv_file<P:AsRef<Path>>(holidays: &[NaiveDate], holiday_names: &[String], source_path: P) -> Result<(), Error>{
     let source_path: &Path = source_path.as_ref();
     let source_path_str = source_path.to_str().context("cannot convert source path to string")?;
 
@@ -0,0 +1,223 @@
+use chrono::{NaiveDate, NaiveDateTime};
+
+use crate::calendar::{WorkCalendar, Session, SessionKind, CoveragePolicy};
+use crate::error::Error;
+use crate::global::{read_work_calendar, try_read_work_calendar};
+use crate::intraday::SessionInterval;
+
+/// 日付の付いた取引セッション(開始を含み，終了を含まない)
+/// Fields
+/// - session: 取引セッション
+/// - date: 開始日
+/// - start: 開始日時
+/// - end: 終了日時
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatedSession {
+    pub session: Session,
+    pub date: NaiveDate,
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime
+}
+
+impl DatedSession {
+    /// select_dateに開始する取引セッション．日付の範囲外に終了する場合はNone
+    fn new(session: Session, select_date: NaiveDate) -> Option<Self> {
+        let end_date = if session.border().is_overnight() {select_date.succ_opt()?} else {select_date};
+        let start = select_date.and_time(session.start);
+        let end = end_date.and_time(session.end);
+        Some(DatedSession {session, date: select_date, start, end})
+    }
+
+    /// select_datetimeがセッション内であるかどうか
+    pub fn contains(&self, select_datetime: NaiveDateTime) -> bool {
+        (self.start <= select_datetime) & (select_datetime < self.end)
+    }
+}
+
+
+impl WorkCalendar {
+    /// select_dateに開始する名前付きの取引セッションをその日の営業時間境界に合わせる．
+    /// 通常の営業時間境界と重なるセッションはその日の営業時間境界と重なる部分とし(重ならない場合は除く)，
+    /// 重ならないセッション(プレオープンなど)はそのままとする
+    fn clipped_sessions_on(&self, select_date: NaiveDate) -> Vec<DatedSession> {
+        let day_intervals: Vec<SessionInterval> = self.sessions_on_date(select_date, false).collect();
+        let regular_intervals: Vec<(NaiveDateTime, NaiveDateTime)> = self.intraday_borders.iter().filter_map(|border|{
+            let end_date = if border.is_overnight() {select_date.succ_opt()?} else {select_date};
+            Some((select_date.and_time(border.start), end_date.and_time(border.end)))
+        }).collect();
+
+        let mut dated_sessions: Vec<DatedSession> = Vec::new();
+        for dated_session in self.sessions.iter().filter_map(|session|{DatedSession::new(session.clone(), select_date)}) {
+            if !regular_intervals.iter().any(|(start, end)|{(*start < dated_session.end) & (dated_session.start < *end)}) {
+                dated_sessions.push(dated_session);
+                continue;
+            }
+            for interval in day_intervals.iter() {
+                let start = dated_session.start.max(interval.start);
+                let end = dated_session.end.min(interval.end);
+                if start < end {
+                    let session = Session {start: start.time(), end: end.time(), ..dated_session.session.clone()};
+                    dated_sessions.push(DatedSession {session, date: select_date, start, end});
+                }
+            }
+        }
+        dated_sessions.sort_by_key(|dated_session|{(dated_session.start, dated_session.end)});
+        dated_sessions
+    }
+
+    /// select_dateに開始する取引セッションを取得．営業日でない場合・その日の営業時間境界が空の場合は空．
+    /// セッションが設定されていない場合は営業時間境界から"session0"・"session1"…という名前の連続取引のセッションを作る．
+    /// 設定されている場合は通常の営業時間境界と重なるセッションをその日の営業時間境界(半日取引など)で切り詰める
+    /// Argment
+    /// - select_date: 指定する日
+    ///
+    /// Return
+    /// 日付の付いた取引セッションのベクター(開始時間順)
+    pub fn sessions_on(&self, select_date: NaiveDate) -> Vec<DatedSession> {
+        if !self.check_workday(select_date) || self.intraday_borders_on(select_date).is_empty() {
+            return Vec::new();
+        }
+        if !self.sessions.is_empty() {
+            return self.clipped_sessions_on(select_date);
+        }
+        self.intraday_borders_on(select_date).iter().enumerate()
+            .map(|(border_index, border)|{Session::new(&format!("session{}", border_index), border.start, border.end, SessionKind::Continuous)})
+            .filter_map(|session|{DatedSession::new(session, select_date)})
+            .collect()
+    }
+
+    /// select_datetimeを含む取引セッションを取得(前日に開始した日をまたぐセッションも含む)．
+    /// セッションが重なる場合は最も遅く開始したものとする
+    /// Argment
+    /// - select_datetime: 指定する日時
+    ///
+    /// Return
+    /// 日付の付いた取引セッション．どのセッションにも含まれない場合はNone
+    pub fn current_session(&self, select_datetime: NaiveDateTime) -> Option<DatedSession> {
+        let select_date = select_datetime.date();
+        select_date.pred_opt().into_iter().chain(Some(select_date))
+            .flat_map(|one_date|{self.sessions_on(one_date)})
+            .filter(|dated_session|{dated_session.contains(select_datetime)})
+            .max_by_key(|dated_session|{dated_session.start})
+    }
+
+    /// select_datetimeより後に開始する名前がnameの取引セッションの開始日時をcoverage_policyに従って取得
+    fn _next_session_start(&self, select_datetime: NaiveDateTime, name: &str, coverage_policy: CoveragePolicy) -> Result<NaiveDateTime, Error> {
+        self._check_coverage(select_datetime.date(), coverage_policy)?;
+        if !self.sessions.is_empty() & !self.sessions.iter().any(|session|{session.name == name}) {
+            return Err(Error::UnknownSession{name: name.to_string()});
+        }
+
+        let (_, last_date) = self.session_search_limits(select_datetime.date());
+        let mut one_day = select_datetime.date();
+        loop {
+            let next_session = self.sessions_on(one_day).into_iter()
+                .find(|dated_session|{(dated_session.session.name == name) & (dated_session.start > select_datetime)});
+            if let Some(dated_session) = next_session {
+                self._check_coverage(dated_session.date, coverage_policy)?;
+                return Ok(dated_session.start);
+            }
+            if one_day > last_date {  // 曜日ごとの営業時間にも無い
+                return Err(Error::UnknownSession{name: name.to_string()});
+            }
+            one_day = self.try_get_next_session_day(one_day)?;
+        }
+    }
+
+    /// select_datetimeより後に開始する名前がnameの取引セッションの開始日時を取得
+    /// Argments
+    /// - select_datetime: 指定する日時
+    /// - name: セッションの名前
+    ///
+    /// Return
+    /// 開始日時
+    ///
+    /// 名前がnameのセッションが見つからない場合・営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラー
+    pub fn try_next_session_start(&self, select_datetime: NaiveDateTime, name: &str) -> Result<NaiveDateTime, Error> {
+        self._next_session_start(select_datetime, name, self.coverage_policy)
+    }
+
+    /// select_datetimeより後に開始する名前がnameの取引セッションの開始日時を取得
+    /// Argments
+    /// - select_datetime: 指定する日時
+    /// - name: セッションの名前
+    ///
+    /// Return
+    /// 開始日時．見つからない場合はNone
+    pub fn next_session_start(&self, select_datetime: NaiveDateTime, name: &str) -> Option<NaiveDateTime> {
+        self._next_session_start(select_datetime, name, CoveragePolicy::Ignore).ok()
+    }
+}
+
+
+/// select_dateに開始する取引セッションを取得．営業日でない場合は空
+/// Argment
+/// - select_date: 指定する日
+///
+/// Return
+/// 日付の付いた取引セッションのベクター(開始時間順)
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::session::*;
+/// let sessions = sessions_on(NaiveDate::from_ymd(2021,1,4));
+/// let names: Vec<&str> = sessions.iter().map(|x|{x.session.name.as_str()}).collect();
+/// assert_eq!(names, vec!["session0", "session1"]);
+/// assert!(sessions_on(NaiveDate::from_ymd(2021,1,1)).is_empty());
+/// ~~~~
+pub fn sessions_on(select_date: NaiveDate) -> Vec<DatedSession> {
+    read_work_calendar().sessions_on(select_date)
+}
+
+/// select_datetimeを含む取引セッションを取得(前日に開始した日をまたぐセッションも含む)．
+/// セッションが重なる場合は最も遅く開始したものとする
+/// Argment
+/// - select_datetime: 指定する日時
+///
+/// Return
+/// 日付の付いた取引セッション．どのセッションにも含まれない場合はNone
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::session::*;
+/// let dated_session = current_session(NaiveDate::from_ymd(2021,1,4).and_hms(13,0,0)).unwrap();
+/// assert_eq!(dated_session.session.name, "session1");
+/// assert_eq!(dated_session.start, NaiveDate::from_ymd(2021,1,4).and_hms(12,30,0));
+/// assert_eq!(current_session(NaiveDate::from_ymd(2021,1,4).and_hms(12,0,0)), None);
+/// ~~~~
+pub fn current_session(select_datetime: NaiveDateTime) -> Option<DatedSession> {
+    read_work_calendar().current_session(select_datetime)
+}
+
+/// select_datetimeより後に開始する名前がnameの取引セッションの開始日時を取得
+/// Argments
+/// - select_datetime: 指定する日時
+/// - name: セッションの名前
+///
+/// Return
+/// 開始日時．見つからない場合はNone
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::session::*;
+/// let select_datetime = NaiveDate::from_ymd(2020,12,31).and_hms(13,0,0);
+/// assert_eq!(next_session_start(select_datetime, "session0"), Some(NaiveDate::from_ymd(2021,1,4).and_hms(9,0,0)));
+/// assert_eq!(next_session_start(select_datetime, "unknown"), None);
+/// ~~~~
+pub fn next_session_start(select_datetime: NaiveDateTime, name: &str) -> Option<NaiveDateTime> {
+    read_work_calendar().next_session_start(select_datetime, name)
+}
+
+/// select_datetimeより後に開始する名前がnameの取引セッションの開始日時を取得
+/// Argments
+/// - select_datetime: 指定する日時
+/// - name: セッションの名前
+///
+/// Return
+/// 開始日時．名前がnameのセッションが見つからない場合などはエラー．デフォルトのカレンダーのロックがポイズンされている場合もエラー
+pub fn try_next_session_start(select_datetime: NaiveDateTime, name: &str) -> Result<NaiveDateTime, Error> {
+    try_read_work_calendar()?.try_next_session_start(select_datetime, name)
+}
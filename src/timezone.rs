@@ -0,0 +1,573 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Duration, TimeZone, Offset, LocalResult};
+use chrono_tz::Tz;
+
+use crate::calendar::{WorkCalendar, CoveragePolicy};
+use crate::error::Error;
+use crate::global::{read_work_calendar, try_read_work_calendar, write_work_calendar};
+use crate::intraday::{SessionInterval, BorderKind};
+
+
+impl WorkCalendar {
+    /// タイムゾーンの更新．営業時間の境界はこのタイムゾーンの現地時刻として扱う
+    /// Argment
+    /// - new_time_zone: IANAタイムゾーン
+    pub fn set_time_zone(&mut self, new_time_zone: Tz) {
+        self.time_zone = new_time_zone;
+    }
+
+    /// タイムゾーンの取得
+    /// Return
+    /// - IANAタイムゾーン
+    pub fn time_zone(&self) -> Tz {
+        self.time_zone
+    }
+
+    /// 日時をカレンダーのタイムゾーンの現地時刻にする
+    /// Argment
+    /// - select_datetime: 任意のタイムゾーンの日時
+    ///
+    /// Return
+    /// 現地時刻
+    pub fn to_local_datetime<T: TimeZone>(&self, select_datetime: &DateTime<T>) -> NaiveDateTime {
+        select_datetime.with_timezone(&self.time_zone).naive_local()
+    }
+
+    /// 現地時刻をカレンダーのタイムゾーンの日時にする．
+    /// 夏時間の開始で存在しない時刻は変化前のオフセットで解釈して変化の分だけ繰り下げ(2時30分→3時30分)，
+    /// 夏時間の終了で重複する時刻は早い方とする
+    /// Argment
+    /// - local_datetime: 現地時刻
+    ///
+    /// Return
+    /// カレンダーのタイムゾーンの日時
+    pub fn from_local_datetime(&self, local_datetime: NaiveDateTime) -> DateTime<Tz> {
+        match self.time_zone.from_local_datetime(&local_datetime) {
+            LocalResult::Single(out_datetime) => out_datetime,
+            LocalResult::Ambiguous(earliest_datetime, _) => earliest_datetime,
+            LocalResult::None => {  // 一日前のオフセット(変化前のオフセット)で解釈する
+                let before_offset = self.time_zone.offset_from_utc_datetime(&(local_datetime - Duration::days(1))).fix();
+                let utc_datetime = local_datetime - Duration::seconds(before_offset.local_minus_utc() as i64);
+                self.time_zone.from_utc_datetime(&utc_datetime)
+            }
+        }
+    }
+
+    /// 営業時間の区間の開始・終了の日時
+    fn session_datetimes(&self, session: &SessionInterval) -> (DateTime<Tz>, DateTime<Tz>) {
+        (self.from_local_datetime(session.start), self.from_local_datetime(session.end))
+    }
+
+    /// 日時より後に終了する最初の営業時間の区間(夏時間の変化を考慮する)
+    fn try_get_next_session_tz(&self, select_datetime: &DateTime<Tz>) -> Result<(SessionInterval, DateTime<Tz>, DateTime<Tz>), Error> {
+        let mut session = self.try_get_next_session(select_datetime.naive_local() - Duration::days(1))?;
+        loop {
+            let (start_datetime, end_datetime) = self.session_datetimes(&session);
+            if end_datetime > *select_datetime {
+                return Ok((session, start_datetime, end_datetime));
+            }
+            session = self.try_get_session_after(&session)?;
+        }
+    }
+
+    /// 日時より前に開始する最後の営業時間の区間(夏時間の変化を考慮する)
+    fn try_get_previous_session_tz(&self, select_datetime: &DateTime<Tz>) -> Result<(SessionInterval, DateTime<Tz>, DateTime<Tz>), Error> {
+        let mut session = self.try_get_previous_session(select_datetime.naive_local() + Duration::days(1))?;
+        loop {
+            let (start_datetime, end_datetime) = self.session_datetimes(&session);
+            if start_datetime < *select_datetime {
+                return Ok((session, start_datetime, end_datetime));
+            }
+            session = self.try_get_session_before(&session)?;
+        }
+    }
+
+    /// 日時を含む営業時間の区間(夏時間の変化を考慮した開始・終了の日時で判定する)．
+    /// 存在しない時刻の繰り下げは1時間程度のため，現地時刻の日付の二日前から開始する区間を調べれば十分である
+    fn session_containing_tz(&self, select_datetime: &DateTime<Tz>, is_workdays_only: bool) -> Option<SessionInterval> {
+        let select_date = select_datetime.naive_local().date();
+        let first_date = select_date.pred_opt().and_then(|one_date|{one_date.pred_opt()}).unwrap_or(select_date);
+        first_date.iter_days().take_while(|one_date|{*one_date <= select_date})
+            .flat_map(|one_date|{self.sessions_on_date(one_date, is_workdays_only)})
+            .find(|session|{
+                let (start_datetime, end_datetime) = self.session_datetimes(session);
+                (start_datetime <= *select_datetime) & (*select_datetime < end_datetime)
+            })
+    }
+
+    /// select_datetimeが営業日・営業時間内であるかどうかを判定．
+    /// 夏時間の変化を考慮した営業時間の開始・終了の日時(`from_local_datetime`)と比較する
+    /// Argment
+    /// - select_datetime: 任意のタイムゾーンの日時
+    ///
+    /// Return
+    /// 営業日・営業時間内であるかどうか
+    pub fn check_workday_intraday_tz<T: TimeZone>(&self, select_datetime: &DateTime<T>) -> bool {
+        self.session_containing_tz(&select_datetime.with_timezone(&self.time_zone), true).is_some()
+    }
+
+    /// select_datetimeを含む営業時間の属する取引日を取得
+    /// Argment
+    /// - select_datetime: 任意のタイムゾーンの日時
+    ///
+    /// Return
+    /// 取引日．営業日・営業時間外の場合はNone
+    pub fn get_trading_date_tz<T: TimeZone>(&self, select_datetime: &DateTime<T>) -> Option<NaiveDate> {
+        let session = self.session_containing_tz(&select_datetime.with_timezone(&self.time_zone), true)?;
+        self.session_trading_date(&session)
+    }
+
+    /// 次の営業日・営業時間内の日時をその状態とともにcoverage_policyに従って取得
+    fn _get_next_border_tz<T: TimeZone>(&self, select_datetime: &DateTime<T>, coverage_policy: CoveragePolicy) -> Result<(DateTime<Tz>, BorderKind), Error> {
+        self.check_intraday_borders()?;
+        let select_datetime = select_datetime.with_timezone(&self.time_zone);
+        self._check_coverage(select_datetime.naive_local().date(), coverage_policy)?;
+        let (session, start_datetime, end_datetime) = self.try_get_next_session_tz(&select_datetime)?;
+
+        let out_tuple = if start_datetime <= select_datetime {  // 営業日・営業時間の場合
+            (end_datetime, BorderKind::End(session.border_index))
+        } else {
+            (start_datetime, BorderKind::Start(session.border_index))
+        };
+        self._check_coverage(out_tuple.0.naive_local().date(), coverage_policy)?;
+        Ok(out_tuple)
+    }
+
+    /// 次の営業日・営業時間内の日時をその状態とともに取得
+    /// Argment
+    /// - select_datetime: 任意のタイムゾーンの日時
+    ///
+    /// Returns
+    /// - カレンダーのタイムゾーンの日時
+    /// - 境界の種類(`BorderKind::Start`・`BorderKind::End`)
+    ///
+    /// 営業時間の境界が空の場合・営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラー
+    pub fn try_get_next_border_tz<T: TimeZone>(&self, select_datetime: &DateTime<T>) -> Result<(DateTime<Tz>, BorderKind), Error> {
+        self._get_next_border_tz(select_datetime, self.coverage_policy)
+    }
+
+    /// 次の営業日・営業時間内の日時をその状態とともに取得
+    /// Argment
+    /// - select_datetime: 任意のタイムゾーンの日時
+    ///
+    /// Returns
+    /// - カレンダーのタイムゾーンの日時
+    /// - 境界の種類(`BorderKind::Start`・`BorderKind::End`)
+    pub fn get_next_border_tz<T: TimeZone>(&self, select_datetime: &DateTime<T>) -> (DateTime<Tz>, BorderKind) {
+        self._get_next_border_tz(select_datetime, CoveragePolicy::Ignore).unwrap()
+    }
+
+    /// 前の営業日・営業時間内の日時をその状態とともにcoverage_policyに従って取得
+    fn _get_previous_border_tz<T: TimeZone>(&self, select_datetime: &DateTime<T>, force_is_end: bool, coverage_policy: CoveragePolicy) -> Result<(DateTime<Tz>, BorderKind), Error> {
+        self.check_intraday_borders()?;
+        let select_datetime = select_datetime.with_timezone(&self.time_zone);
+        self._check_coverage(select_datetime.naive_local().date(), coverage_policy)?;
+        let (session, start_datetime, end_datetime) = self.try_get_previous_session_tz(&select_datetime)?;
+
+        let out_tuple = if select_datetime < end_datetime {  // 営業時間であり，開始境界でない場合
+            (start_datetime, BorderKind::Start(session.border_index))
+        } else if force_is_end & (select_datetime == end_datetime) {  // 終了境界で前の開始境界に行くのを強制する
+            (start_datetime, BorderKind::Start(session.border_index))
+        } else {
+            (end_datetime, BorderKind::End(session.border_index))
+        };
+        self._check_coverage(out_tuple.0.naive_local().date(), coverage_policy)?;
+        Ok(out_tuple)
+    }
+
+    /// 前の営業日・営業時間内の日時をその状態とともに取得
+    /// Argments
+    /// - select_datetime: 任意のタイムゾーンの日時
+    /// - force_is_end: 終了境界の場合に前の開始境界を返すかどうか
+    ///
+    /// Returns
+    /// - カレンダーのタイムゾーンの日時
+    /// - 境界の種類(`BorderKind::Start`・`BorderKind::End`)
+    ///
+    /// 営業時間の境界が空の場合・営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラー
+    pub fn try_get_previous_border_tz<T: TimeZone>(&self, select_datetime: &DateTime<T>, force_is_end: bool) -> Result<(DateTime<Tz>, BorderKind), Error> {
+        self._get_previous_border_tz(select_datetime, force_is_end, self.coverage_policy)
+    }
+
+    /// 前の営業日・営業時間内の日時をその状態とともに取得
+    /// Argments
+    /// - select_datetime: 任意のタイムゾーンの日時
+    /// - force_is_end: 終了境界の場合に前の開始境界を返すかどうか
+    ///
+    /// Returns
+    /// - カレンダーのタイムゾーンの日時
+    /// - 境界の種類(`BorderKind::Start`・`BorderKind::End`)
+    pub fn get_previous_border_tz<T: TimeZone>(&self, select_datetime: &DateTime<T>, force_is_end: bool) -> (DateTime<Tz>, BorderKind) {
+        self._get_previous_border_tz(select_datetime, force_is_end, CoveragePolicy::Ignore).unwrap()
+    }
+
+    /// 最近の営業日・営業時間内の日時をその状態とともにcoverage_policyに従って取得
+    fn _get_near_border_tz<T: TimeZone>(&self, select_datetime: &DateTime<T>, is_after: bool, coverage_policy: CoveragePolicy) -> Result<(DateTime<Tz>, BorderKind), Error> {
+        if let Some(session) = self.session_containing_tz(&select_datetime.with_timezone(&self.time_zone), true) {
+            Ok((select_datetime.with_timezone(&self.time_zone), BorderKind::Intra(session.border_index)))
+        } else if is_after {
+            self._get_next_border_tz(select_datetime, coverage_policy)
+        } else {
+            self._get_previous_border_tz(select_datetime, false, coverage_policy)
+        }
+    }
+
+    /// 最近の営業日・営業時間内の日時をその状態とともに取得．select_datetimeが営業日・営業時間内の場合そのまま返る．
+    /// Argments
+    /// - select_datetime: 任意のタイムゾーンの日時
+    /// - is_after: 後ろを探索するかどうか
+    ///
+    /// Returns
+    /// - カレンダーのタイムゾーンの日時
+    /// - 境界の種類(`BorderKind::Intra`・`BorderKind::Start`・`BorderKind::End`)
+    ///
+    /// 営業時間の境界が空の場合・営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラー
+    pub fn try_get_near_border_tz<T: TimeZone>(&self, select_datetime: &DateTime<T>, is_after: bool) -> Result<(DateTime<Tz>, BorderKind), Error> {
+        self._get_near_border_tz(select_datetime, is_after, self.coverage_policy)
+    }
+
+    /// 最近の営業日・営業時間内の日時をその状態とともに取得．select_datetimeが営業日・営業時間内の場合そのまま返る．
+    /// Argments
+    /// - select_datetime: 任意のタイムゾーンの日時
+    /// - is_after: 後ろを探索するかどうか
+    ///
+    /// Returns
+    /// - カレンダーのタイムゾーンの日時
+    /// - 境界の種類(`BorderKind::Intra`・`BorderKind::Start`・`BorderKind::End`)
+    pub fn get_near_border_tz<T: TimeZone>(&self, select_datetime: &DateTime<T>, is_after: bool) -> (DateTime<Tz>, BorderKind) {
+        self._get_near_border_tz(select_datetime, is_after, CoveragePolicy::Ignore).unwrap()
+    }
+
+    /// 営業日・営業時間を考慮し日時をcoverage_policyに従って加算する
+    fn _add_workday_intraday_datetime_tz<T: TimeZone>(&self, select_datetime: &DateTime<T>, delta_time: Duration, coverage_policy: CoveragePolicy) -> Result<DateTime<Tz>, Error> {
+        self.check_intraday_borders()?;
+        let select_datetime = select_datetime.with_timezone(&self.time_zone);
+        self._check_coverage(select_datetime.naive_local().date(), coverage_policy)?;
+
+        let out_datetime = if delta_time.num_milliseconds() > 0 {
+            let mut all_delta_time = delta_time;
+            let (mut session, start_datetime, mut end_datetime) = self.try_get_next_session_tz(&select_datetime)?;
+            let mut session_start = start_datetime.max(select_datetime);
+            loop {
+                let delta_session = end_datetime - session_start;
+                if all_delta_time < delta_session {  // 残りがその営業時間内の場合
+                    break session_start + all_delta_time;
+                }
+                let next_session = self.try_get_session_after(&session)?;
+                let (next_start_datetime, next_end_datetime) = self.session_datetimes(&next_session);
+                if all_delta_time == delta_session {  // 残りがその営業時間と同じ場合は次の営業時間の開始
+                    break next_start_datetime;
+                }
+                all_delta_time = all_delta_time - delta_session;
+                session = next_session;
+                session_start = next_start_datetime;
+                end_datetime = next_end_datetime;
+            }
+        } else if delta_time.num_milliseconds() < 0 {
+            let mut all_delta_time = - delta_time;
+            let (mut session, mut start_datetime, end_datetime) = self.try_get_previous_session_tz(&select_datetime)?;
+            let mut session_end = end_datetime.min(select_datetime);
+            loop {
+                let delta_session = session_end - start_datetime;
+                if all_delta_time <= delta_session {  // 残りがその営業時間内以下の場合
+                    break session_end - all_delta_time;
+                }
+                all_delta_time = all_delta_time - delta_session;
+                session = self.try_get_session_before(&session)?;
+                let (previous_start_datetime, previous_end_datetime) = self.session_datetimes(&session);
+                start_datetime = previous_start_datetime;
+                session_end = previous_end_datetime;
+            }
+        } else {  // mmsec以下は無視
+            select_datetime
+        };
+        self._check_coverage(out_datetime.naive_local().date(), coverage_policy)?;
+        Ok(out_datetime)
+    }
+
+    /// 営業日・営業時間を考慮し日時を加算する．夏時間の変化をまたぐ営業時間は実際の経過時間で数える
+    /// Argments
+    /// - select_datetime: 任意のタイムゾーンの日時
+    /// - delta_time: 加算するDuration
+    ///
+    /// Return
+    /// 加算されたカレンダーのタイムゾーンの日時
+    ///
+    /// 営業時間の境界が空の場合・営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラー
+    pub fn try_add_workday_intraday_datetime_tz<T: TimeZone>(&self, select_datetime: &DateTime<T>, delta_time: Duration) -> Result<DateTime<Tz>, Error> {
+        self._add_workday_intraday_datetime_tz(select_datetime, delta_time, self.coverage_policy)
+    }
+
+    /// 営業日・営業時間を考慮し日時を加算する．夏時間の変化をまたぐ営業時間は実際の経過時間で数える
+    /// Argments
+    /// - select_datetime: 任意のタイムゾーンの日時
+    /// - delta_time: 加算するDuration
+    ///
+    /// Return
+    /// 加算されたカレンダーのタイムゾーンの日時
+    pub fn add_workday_intraday_datetime_tz<T: TimeZone>(&self, select_datetime: &DateTime<T>, delta_time: Duration) -> DateTime<Tz> {
+        self._add_workday_intraday_datetime_tz(select_datetime, delta_time, CoveragePolicy::Ignore).unwrap()
+    }
+
+    /// start_datetimeからend_datetimeの営業日・営業時間を取得．夏時間の変化をまたぐ営業時間は実際の経過時間で数える
+    /// Argments
+    /// - start_datetime: 開始日時
+    /// - end_datetime: 終了日時
+    ///
+    /// Return
+    /// 営業日・営業時間のDuration(end_datetimeがstart_datetimeより前の場合は負)
+    pub fn get_timedelta_workdays_intraday_tz<T: TimeZone, U: TimeZone>(&self, start_datetime: &DateTime<T>, end_datetime: &DateTime<U>) -> Duration {
+        let start_datetime = start_datetime.with_timezone(&self.time_zone);
+        let end_datetime = end_datetime.with_timezone(&self.time_zone);
+        if start_datetime > end_datetime {
+            return - self.get_timedelta_workdays_intraday_tz(&end_datetime, &start_datetime);
+        }
+
+        let mut all_delta_time = Duration::zero();
+        let mut session_tuple = self.try_get_next_session_tz(&start_datetime).ok();
+        while let Some((session, session_start, session_end)) = session_tuple {
+            if session_start >= end_datetime {
+                break;
+            }
+            all_delta_time = all_delta_time + (session_end.min(end_datetime) - session_start.max(start_datetime));
+            session_tuple = self.try_get_session_after(&session).ok().map(|next_session|{
+                let (next_start_datetime, next_end_datetime) = self.session_datetimes(&next_session);
+                (next_session, next_start_datetime, next_end_datetime)
+            });
+        }
+        all_delta_time
+    }
+
+    /// 日時のベクターから営業日のものをboolとして抽出(現地時刻の日付で判定)
+    /// Argment
+    /// - datetime_vec: 抽出したい日時のベクター
+    ///
+    /// Return
+    /// ブールのベクター
+    pub fn extract_workdays_bool_tz<T: TimeZone>(&self, datetime_vec: &[DateTime<T>]) -> Vec<bool> {
+        let local_datetime_vec: Vec<NaiveDateTime> = datetime_vec.iter().map(|x|{self.to_local_datetime(x)}).collect();
+        self.extract_workdays_bool(&local_datetime_vec)
+    }
+
+    /// 日時のベクターから営業時間のものをboolとして抽出(夏時間の変化を考慮した営業時間の開始・終了の日時で判定)
+    /// Argment
+    /// - datetime_vec: 抽出したい日時のベクター
+    ///
+    /// Return
+    /// ブールのベクター
+    pub fn extract_intraday_bool_tz<T: TimeZone>(&self, datetime_vec: &[DateTime<T>]) -> Vec<bool> {
+        datetime_vec.iter().map(|x|{self.session_containing_tz(&x.with_timezone(&self.time_zone), false).is_some()}).collect()
+    }
+
+    /// 日時のベクターから営業日・営業時間のものをboolとして抽出(夏時間の変化を考慮した営業時間の開始・終了の日時で判定)
+    /// Argment
+    /// - datetime_vec: 抽出したい日時のベクター
+    ///
+    /// Return
+    /// ブールのベクター
+    pub fn extract_workdays_intraday_bool_tz<T: TimeZone>(&self, datetime_vec: &[DateTime<T>]) -> Vec<bool> {
+        datetime_vec.iter().map(|x|{self.check_workday_intraday_tz(x)}).collect()
+    }
+}
+
+
+/// デフォルトのカレンダーのタイムゾーンの更新
+/// Argment
+/// - new_time_zone: IANAタイムゾーン
+pub fn set_time_zone(new_time_zone: Tz) {
+    write_work_calendar().set_time_zone(new_time_zone);
+}
+
+/// デフォルトのカレンダーのタイムゾーンの取得
+/// Return
+/// - IANAタイムゾーン
+pub fn get_time_zone() -> Tz {
+    read_work_calendar().time_zone()
+}
+
+/// select_datetimeが営業日・営業時間内であるかどうかを判定(夏時間の変化を考慮した営業時間の開始・終了の日時と比較する)
+/// Argment
+/// - select_datetime: 任意のタイムゾーンの日時
+///
+/// Return
+/// 営業日・営業時間内であるかどうか
+///
+/// # Examples
+/// ~~~~
+/// use chrono::{TimeZone, Utc};
+/// use rs_workdays::timezone::*;
+/// let select_datetime = Utc.ymd(2021, 1, 4).and_hms(0, 0, 0);  // 東京の9時
+/// assert!(check_workday_intraday_tz(&select_datetime));
+/// ~~~~
+pub fn check_workday_intraday_tz<T: TimeZone>(select_datetime: &DateTime<T>) -> bool {
+    read_work_calendar().check_workday_intraday_tz(select_datetime)
+}
+
+/// select_datetimeを含む営業時間の属する取引日を取得
+/// Argment
+/// - select_datetime: 任意のタイムゾーンの日時
+///
+/// Return
+/// 取引日．営業日・営業時間外の場合はNone
+pub fn get_trading_date_tz<T: TimeZone>(select_datetime: &DateTime<T>) -> Option<NaiveDate> {
+    read_work_calendar().get_trading_date_tz(select_datetime)
+}
+
+/// 次の営業日・営業時間内の日時をその状態とともに取得
+/// Argment
+/// - select_datetime: 任意のタイムゾーンの日時
+///
+/// Returns
+/// - カレンダーのタイムゾーンの日時
+/// - 境界の種類(`BorderKind::Start`・`BorderKind::End`)
+///
+/// 営業時間の境界が空の場合・営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラー
+pub fn try_get_next_border_tz<T: TimeZone>(select_datetime: &DateTime<T>) -> Result<(DateTime<Tz>, BorderKind), Error> {
+    try_read_work_calendar()?.try_get_next_border_tz(select_datetime)
+}
+
+/// 次の営業日・営業時間内の日時をその状態とともに取得
+/// Argment
+/// - select_datetime: 任意のタイムゾーンの日時
+///
+/// Returns
+/// - カレンダーのタイムゾーンの日時
+/// - 境界の種類(`BorderKind::Start`・`BorderKind::End`)
+///
+/// # Examples
+/// ~~~~
+/// use chrono::{TimeZone, Utc};
+/// use chrono_tz::Asia::Tokyo;
+/// use rs_workdays::timezone::*;
+/// use rs_workdays::intraday::BorderKind;
+/// let select_datetime = Utc.ymd(2021, 1, 4).and_hms(3, 0, 0);  // 東京の12時
+/// assert_eq!(get_next_border_tz(&select_datetime), (Tokyo.ymd(2021, 1, 4).and_hms(12, 30, 0), BorderKind::Start(1)));
+/// ~~~~
+pub fn get_next_border_tz<T: TimeZone>(select_datetime: &DateTime<T>) -> (DateTime<Tz>, BorderKind) {
+    read_work_calendar().get_next_border_tz(select_datetime)
+}
+
+/// 前の営業日・営業時間内の日時をその状態とともに取得
+/// Argments
+/// - select_datetime: 任意のタイムゾーンの日時
+/// - force_is_end: 終了境界の場合に前の開始境界を返すかどうか
+///
+/// Returns
+/// - カレンダーのタイムゾーンの日時
+/// - 境界の種類(`BorderKind::Start`・`BorderKind::End`)
+///
+/// 営業時間の境界が空の場合・営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラー
+pub fn try_get_previous_border_tz<T: TimeZone>(select_datetime: &DateTime<T>, force_is_end: bool) -> Result<(DateTime<Tz>, BorderKind), Error> {
+    try_read_work_calendar()?.try_get_previous_border_tz(select_datetime, force_is_end)
+}
+
+/// 前の営業日・営業時間内の日時をその状態とともに取得
+/// Argments
+/// - select_datetime: 任意のタイムゾーンの日時
+/// - force_is_end: 終了境界の場合に前の開始境界を返すかどうか
+///
+/// Returns
+/// - カレンダーのタイムゾーンの日時
+/// - 境界の種類(`BorderKind::Start`・`BorderKind::End`)
+pub fn get_previous_border_tz<T: TimeZone>(select_datetime: &DateTime<T>, force_is_end: bool) -> (DateTime<Tz>, BorderKind) {
+    read_work_calendar().get_previous_border_tz(select_datetime, force_is_end)
+}
+
+/// 最近の営業日・営業時間内の日時をその状態とともに取得．select_datetimeが営業日・営業時間内の場合そのまま返る．
+/// Argments
+/// - select_datetime: 任意のタイムゾーンの日時
+/// - is_after: 後ろを探索するかどうか
+///
+/// Returns
+/// - カレンダーのタイムゾーンの日時
+/// - 境界の種類(`BorderKind::Intra`・`BorderKind::Start`・`BorderKind::End`)
+///
+/// 営業時間の境界が空の場合・営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラー
+pub fn try_get_near_border_tz<T: TimeZone>(select_datetime: &DateTime<T>, is_after: bool) -> Result<(DateTime<Tz>, BorderKind), Error> {
+    try_read_work_calendar()?.try_get_near_border_tz(select_datetime, is_after)
+}
+
+/// 最近の営業日・営業時間内の日時をその状態とともに取得．select_datetimeが営業日・営業時間内の場合そのまま返る．
+/// Argments
+/// - select_datetime: 任意のタイムゾーンの日時
+/// - is_after: 後ろを探索するかどうか
+///
+/// Returns
+/// - カレンダーのタイムゾーンの日時
+/// - 境界の種類(`BorderKind::Intra`・`BorderKind::Start`・`BorderKind::End`)
+pub fn get_near_border_tz<T: TimeZone>(select_datetime: &DateTime<T>, is_after: bool) -> (DateTime<Tz>, BorderKind) {
+    read_work_calendar().get_near_border_tz(select_datetime, is_after)
+}
+
+/// 営業日・営業時間を考慮し日時を加算する．夏時間の変化をまたぐ営業時間は実際の経過時間で数える
+/// Argments
+/// - select_datetime: 任意のタイムゾーンの日時
+/// - delta_time: 加算するDuration
+///
+/// Return
+/// 加算されたカレンダーのタイムゾーンの日時
+///
+/// 営業時間の境界が空の場合・営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラー
+pub fn try_add_workday_intraday_datetime_tz<T: TimeZone>(select_datetime: &DateTime<T>, delta_time: Duration) -> Result<DateTime<Tz>, Error> {
+    try_read_work_calendar()?.try_add_workday_intraday_datetime_tz(select_datetime, delta_time)
+}
+
+/// 営業日・営業時間を考慮し日時を加算する．夏時間の変化をまたぐ営業時間は実際の経過時間で数える
+/// Argments
+/// - select_datetime: 任意のタイムゾーンの日時
+/// - delta_time: 加算するDuration
+///
+/// Return
+/// 加算されたカレンダーのタイムゾーンの日時
+///
+/// # Examples
+/// ~~~~
+/// use chrono::{TimeZone, Utc, Duration};
+/// use chrono_tz::Asia::Tokyo;
+/// use rs_workdays::timezone::*;
+/// let select_datetime = Utc.ymd(2021, 1, 4).and_hms(1, 0, 0);  // 東京の10時
+/// assert_eq!(add_workday_intraday_datetime_tz(&select_datetime, Duration::hours(2)), Tokyo.ymd(2021, 1, 4).and_hms(13, 0, 0));
+/// ~~~~
+pub fn add_workday_intraday_datetime_tz<T: TimeZone>(select_datetime: &DateTime<T>, delta_time: Duration) -> DateTime<Tz> {
+    read_work_calendar().add_workday_intraday_datetime_tz(select_datetime, delta_time)
+}
+
+/// start_datetimeからend_datetimeの営業日・営業時間を取得．夏時間の変化をまたぐ営業時間は実際の経過時間で数える
+/// Argments
+/// - start_datetime: 開始日時
+/// - end_datetime: 終了日時
+///
+/// Return
+/// 営業日・営業時間のDuration(end_datetimeがstart_datetimeより前の場合は負)
+pub fn get_timedelta_workdays_intraday_tz<T: TimeZone, U: TimeZone>(start_datetime: &DateTime<T>, end_datetime: &DateTime<U>) -> Duration {
+    read_work_calendar().get_timedelta_workdays_intraday_tz(start_datetime, end_datetime)
+}
+
+/// 日時のベクターから営業日のものをboolとして抽出(現地時刻の日付で判定)
+/// Argment
+/// - datetime_vec: 抽出したい日時のベクター
+///
+/// Return
+/// ブールのベクター
+pub fn extract_workdays_bool_tz<T: TimeZone>(datetime_vec: &[DateTime<T>]) -> Vec<bool> {
+    read_work_calendar().extract_workdays_bool_tz(datetime_vec)
+}
+
+/// 日時のベクターから営業時間のものをboolとして抽出(夏時間の変化を考慮した営業時間の開始・終了の日時で判定)
+/// Argment
+/// - datetime_vec: 抽出したい日時のベクター
+///
+/// Return
+/// ブールのベクター
+pub fn extract_intraday_bool_tz<T: TimeZone>(datetime_vec: &[DateTime<T>]) -> Vec<bool> {
+    read_work_calendar().extract_intraday_bool_tz(datetime_vec)
+}
+
+/// 日時のベクターから営業日・営業時間のものをboolとして抽出(夏時間の変化を考慮した営業時間の開始・終了の日時で判定)
+/// Argment
+/// - datetime_vec: 抽出したい日時のベクター
+///
+/// Return
+/// ブールのベクター
+pub fn extract_workdays_intraday_bool_tz<T: TimeZone>(datetime_vec: &[DateTime<T>]) -> Vec<bool> {
+    read_work_calendar().extract_workdays_intraday_bool_tz(datetime_vec)
+}
@@ -0,0 +1,139 @@
+use std::collections::HashSet;
+use chrono::{NaiveDate, Datelike, Weekday};
+
+// 一年分のビットマップのワード数(366日分)
+const YEAR_WORDS: usize = 6;
+// 祝日が無い場合の索引の開始年
+static EMPTY_INDEX_YEAR: i32 = 1970;
+
+/// 営業日の索引．
+/// 祝日のある年の範囲について，年ごとの営業日のビットマップと年初までの累積営業日数をもつ．
+/// 範囲外の日は休日曜日のみから判定する．
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct WorkdayIndex {
+    /// 索引の開始年
+    start_year: i32,
+    /// 年ごとの営業日のビットマップ(1月1日からi日目が営業日の場合にiビット目が1)
+    year_bitmaps: Vec<[u64; YEAR_WORDS]>,
+    /// 開始年の1月1日から各年の1月1日までの営業日数(長さは年数+1)
+    cumulative_counts: Vec<i64>,
+    /// 休日曜日のビットマスク(月曜日を0ビット目とする)
+    holiday_weekday_mask: u8
+}
+
+impl WorkdayIndex {
+    /// 祝日と休日曜日から索引を作成
+    /// Argments
+    /// - holidays: ソート済みの祝日のスライス
+    /// - holiday_weekdays: 休日曜日のセット
+    pub(crate) fn new(holidays: &[NaiveDate], holiday_weekdays: &HashSet<Weekday>) -> Self {
+        let holiday_weekday_mask = holiday_weekdays.iter()
+            .fold(0_u8, |mask, weekday|{mask | (1 << weekday.num_days_from_monday())});
+
+        let (start_year, end_year) = match (holidays.first(), holidays.last()) {
+            (Some(first_holiday), Some(last_holiday)) => (first_holiday.year(), last_holiday.year()),
+            _ => (EMPTY_INDEX_YEAR, EMPTY_INDEX_YEAR - 1)  // 空の索引
+        };
+
+        let mut year_bitmaps: Vec<[u64; YEAR_WORDS]> = Vec::new();
+        let mut cumulative_counts: Vec<i64> = vec![0];
+        let mut holiday_iter = holidays.iter().peekable();
+
+        for year in start_year..=end_year {
+            let mut bitmap = [0_u64; YEAR_WORDS];
+            let first_day = NaiveDate::from_ymd(year, 1, 1);
+            for (i, one_day) in first_day.iter_days().take_while(|x|{x.year()==year}).enumerate() {
+                let is_holiday_weekday = holiday_weekday_mask & (1 << one_day.weekday().num_days_from_monday()) != 0;
+                let mut is_holiday = false;
+                while let Some(holiday) = holiday_iter.peek() {
+                    if **holiday > one_day {
+                        break;
+                    }
+                    is_holiday |= **holiday == one_day;
+                    holiday_iter.next();
+                }
+                if !is_holiday_weekday & !is_holiday {
+                    bitmap[i / 64] |= 1 << (i % 64);
+                }
+            }
+            let year_count: i64 = bitmap.iter().map(|x|{x.count_ones() as i64}).sum();
+            cumulative_counts.push(cumulative_counts.last().unwrap() + year_count);
+            year_bitmaps.push(bitmap);
+        }
+
+        WorkdayIndex {start_year, year_bitmaps, cumulative_counts, holiday_weekday_mask}
+    }
+
+    /// 索引の開始日(開始年の1月1日)
+    fn start_date(&self) -> NaiveDate {
+        NaiveDate::from_ymd(self.start_year, 1, 1)
+    }
+
+    /// 索引の終了日(終了年の翌年の1月1日，含まない)
+    fn end_date(&self) -> NaiveDate {
+        NaiveDate::from_ymd(self.start_year + self.year_bitmaps.len() as i32, 1, 1)
+    }
+
+    /// 休日曜日であるかどうか
+    fn is_holiday_weekday(&self, select_date: NaiveDate) -> bool {
+        self.holiday_weekday_mask & (1 << select_date.weekday().num_days_from_monday()) != 0
+    }
+
+    /// start_date(含む)からend_date(含まない)までの休日曜日でない日数．祝日は考慮しない
+    fn count_weekday_workdays(&self, start_date: NaiveDate, end_date: NaiveDate) -> i64 {
+        let all_days = (end_date - start_date).num_days();
+        let start_weekday_number = start_date.weekday().num_days_from_monday() as i64;
+        let holiday_weekday_days: i64 = (0..7_i64).filter(|x|{self.holiday_weekday_mask & (1 << x) != 0}).map(|weekday_number|{
+            let offset = (weekday_number - start_weekday_number).rem_euclid(7);
+            if offset < all_days {(all_days - offset - 1) / 7 + 1} else {0}
+        }).sum();
+        all_days - holiday_weekday_days
+    }
+
+    /// 全ての曜日が休日曜日であるかどうか(営業日が存在しない)
+    pub(crate) fn is_all_holiday_weekdays(&self) -> bool {
+        self.holiday_weekday_mask == 0b111_1111
+    }
+
+    /// select_dateが営業日であるか判定
+    /// Argment
+    /// - select_date: 指定する日
+    pub(crate) fn check_workday(&self, select_date: NaiveDate) -> bool {
+        let year_index = select_date.year() - self.start_year;
+        if (year_index < 0) | (year_index >= self.year_bitmaps.len() as i32) {  // 索引の範囲外
+            return !self.is_holiday_weekday(select_date);
+        }
+        let day_index = select_date.ordinal0() as usize;
+        self.year_bitmaps[year_index as usize][day_index / 64] & (1 << (day_index % 64)) != 0
+    }
+
+    /// 索引の開始日からselect_dateまで(select_dateを含まない)の営業日数．
+    /// select_dateが開始日より前の場合は負の値となる．
+    /// Argment
+    /// - select_date: 指定する日
+    pub(crate) fn workdays_before(&self, select_date: NaiveDate) -> i64 {
+        let start_date = self.start_date();
+        let end_date = self.end_date();
+        if select_date < start_date {
+            return -self.count_weekday_workdays(select_date, start_date);
+        }
+        if select_date >= end_date {
+            return self.cumulative_counts.last().unwrap() + self.count_weekday_workdays(end_date, select_date);
+        }
+
+        let year_index = (select_date.year() - self.start_year) as usize;
+        let bitmap = &self.year_bitmaps[year_index];
+        let day_index = select_date.ordinal0() as usize;
+        let full_words_count: i64 = bitmap[..day_index / 64].iter().map(|x|{x.count_ones() as i64}).sum();
+        let partial_word_count = (bitmap[day_index / 64] & ((1_u64 << (day_index % 64)) - 1)).count_ones() as i64;
+        self.cumulative_counts[year_index] + full_words_count + partial_word_count
+    }
+
+    /// start_date(含む)からend_date(含まない)までの営業日数
+    /// Argments
+    /// - start_date: 開始日
+    /// - end_date: 終了日
+    pub(crate) fn count_workdays(&self, start_date: NaiveDate, end_date: NaiveDate) -> i64 {
+        self.workdays_before(end_date) - self.workdays_before(start_date)
+    }
+}
@@ -0,0 +1,176 @@
+use std::sync::Arc;
+use std::iter::FusedIterator;
+use chrono::NaiveDate;
+
+use crate::calendar::WorkCalendar;
+use crate::global::read_work_calendar;
+use crate::workdays::Closed;
+use crate::workday_index::WorkdayIndex;
+
+/// 営業日を順に返す遅延イテレーター．
+/// 範囲の両端から営業日を取り出せるため，`.rev()`などを営業日のベクターを作成せずに利用できる．
+/// 終了日を指定しない場合はchronoで扱える最後の日までを範囲とする．
+/// 営業日の索引はカレンダーと共有するため，作成時に索引をコピーしない．
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::WorkCalendar;
+/// let calendar = WorkCalendar::default();
+/// let workdays: Vec<NaiveDate> = calendar.iter_workdays(NaiveDate::from_ymd(2021, 1, 1)).step_by(5).take(2).collect();
+/// assert_eq!(workdays, vec![NaiveDate::from_ymd(2021, 1, 4), NaiveDate::from_ymd(2021, 1, 12)]);
+/// ~~~~
+#[derive(Debug, Clone)]
+pub struct WorkdayIter {
+    workday_index: Arc<WorkdayIndex>,
+    front_date: NaiveDate,
+    back_date: NaiveDate,
+    is_finished: bool
+}
+
+impl WorkdayIter {
+    /// front_dateからback_dateまで(両端を含む)のイテレーターを作成．全ての曜日が休日曜日の場合は営業日が無いため空とする
+    fn new(workday_index: Arc<WorkdayIndex>, front_date: Option<NaiveDate>, back_date: Option<NaiveDate>) -> Self {
+        match (front_date, back_date) {
+            (Some(front_date), Some(back_date)) => {
+                let is_finished = (front_date > back_date) | workday_index.is_all_holiday_weekdays();
+                WorkdayIter {workday_index, front_date, back_date, is_finished}
+            },
+            _ => {  // 範囲が日付の範囲外の場合は空
+                WorkdayIter {workday_index, front_date: NaiveDate::MAX, back_date: NaiveDate::MAX, is_finished: true}
+            }
+        }
+    }
+}
+
+impl Iterator for WorkdayIter {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<NaiveDate> {
+        while !self.is_finished {
+            let one_day = self.front_date;
+            if one_day >= self.back_date {  // 範囲の最後の日
+                self.is_finished = true;
+            } else {
+                self.front_date = one_day.succ();
+            }
+
+            if self.workday_index.check_workday(one_day) {
+                return Some(one_day);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len();
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for WorkdayIter {
+    fn next_back(&mut self) -> Option<NaiveDate> {
+        while !self.is_finished {
+            let one_day = self.back_date;
+            if one_day <= self.front_date {  // 範囲の最初の日
+                self.is_finished = true;
+            } else {
+                self.back_date = one_day.pred();
+            }
+
+            if self.workday_index.check_workday(one_day) {
+                return Some(one_day);
+            }
+        }
+        None
+    }
+}
+
+impl ExactSizeIterator for WorkdayIter {
+    fn len(&self) -> usize {
+        if self.is_finished {
+            return 0;
+        }
+        let mut remaining = self.workday_index.count_workdays(self.front_date, self.back_date) as usize;
+        if self.workday_index.check_workday(self.back_date) {
+            remaining += 1;
+        }
+        remaining
+    }
+}
+
+impl FusedIterator for WorkdayIter {}
+
+
+impl WorkCalendar {
+    /// start_dateからの営業日のイテレーターを取得(start_dateを含み，終了日を指定しない)
+    /// Argment
+    /// - start_date: 開始日
+    ///
+    /// Return
+    /// 営業日のイテレーター
+    pub fn iter_workdays(&self, start_date: NaiveDate) -> WorkdayIter {
+        WorkdayIter::new(Arc::clone(&self.workday_index), Some(start_date), Some(NaiveDate::MAX))
+    }
+
+    /// start_dateからend_dateまでの営業日のイテレーターを取得
+    /// Argments
+    /// - start_date: 開始日
+    /// - end_date: 終了日
+    /// - closed: 境界を含めるかどうか
+    ///
+    /// Return
+    /// 営業日のイテレーター(get_workdaysと同じ営業日を返す)
+    pub fn iter_workdays_range(&self, start_date: NaiveDate, end_date: NaiveDate, closed: Closed) -> WorkdayIter {
+        let (front_date, back_date) = match closed {
+            Closed::Left => (Some(start_date), end_date.pred_opt()),
+            Closed::Right => (start_date.succ_opt(), Some(end_date)),
+            Closed::Both => (Some(start_date), Some(end_date)),
+            Closed::Not => (start_date.succ_opt(), end_date.pred_opt())
+        };
+        WorkdayIter::new(Arc::clone(&self.workday_index), front_date, back_date)
+    }
+}
+
+
+/// start_dateからの営業日のイテレーターを取得(start_dateを含み，終了日を指定しない)
+/// Argment
+/// - start_date: 開始日
+///
+/// Return
+/// 営業日のイテレーター
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::workday_iter::*;
+/// let start_date = NaiveDate::from_ymd(2021, 1, 1);
+/// let workdays: Vec<NaiveDate> = iter_workdays(start_date).take(3).collect();
+/// assert_eq!(workdays, vec![NaiveDate::from_ymd(2021, 1, 4), NaiveDate::from_ymd(2021, 1, 5), NaiveDate::from_ymd(2021, 1, 6)]);
+/// ~~~~
+pub fn iter_workdays(start_date: NaiveDate) -> WorkdayIter {
+    read_work_calendar().iter_workdays(start_date)
+}
+
+/// start_dateからend_dateまでの営業日のイテレーターを取得
+/// Argments
+/// - start_date: 開始日
+/// - end_date: 終了日
+/// - closed: 境界を含めるかどうか
+///
+/// Return
+/// 営業日のイテレーター(get_workdaysと同じ営業日を返す)
+///
+/// # Examples
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use rs_workdays::workday_iter::*;
+/// use rs_workdays::workdays::Closed;
+/// let start_date = NaiveDate::from_ymd(2021, 1, 1);
+/// let end_date = NaiveDate::from_ymd(2021, 1, 29);
+/// let workdays: Vec<NaiveDate> = iter_workdays_range(start_date, end_date, Closed::Both).rev().take(2).collect();
+/// assert_eq!(workdays, vec![NaiveDate::from_ymd(2021, 1, 29), NaiveDate::from_ymd(2021, 1, 28)]);
+/// ~~~~
+pub fn iter_workdays_range(start_date: NaiveDate, end_date: NaiveDate, closed: Closed) -> WorkdayIter {
+    read_work_calendar().iter_workdays_range(start_date, end_date, closed)
+}
@@ -1,7 +1,8 @@
-use std::collections::HashSet;
-use chrono::{NaiveDate, Datelike};
+use chrono::NaiveDate;
 
-use crate::global::{RANGE_HOLIDAYS, HOLIDAY_WEEKDAYS, IMPOSSIBLE_DATE_1};
+use crate::calendar::{WorkCalendar, CoveragePolicy};
+use crate::error::Error;
+use crate::global::{read_work_calendar, try_read_work_calendar, IMPOSSIBLE_DATE_1};
 
 /// 期間の端を含む(閉じる)かどうかを指定する
 #[derive(Debug, Clone, Copy)]
@@ -17,7 +18,386 @@ pub enum Closed {
 }
 
 
-/// start_dateからend_dateまでの営業日を取得  
+impl WorkCalendar {
+    /// start_dateからend_dateまでの営業日を取得
+    /// Argments
+    /// - start_date: 開始日
+    /// - end_date: 終了日
+    /// - closed: 境界を含めるかどうか
+    ///
+    /// Return
+    /// workdays_vec: 営業日のべクター
+    pub fn get_workdays(&self, start_date: NaiveDate, end_date: NaiveDate, closed: Closed) -> Vec<NaiveDate> {
+        let mut workdays_vec: Vec<NaiveDate> = start_date.iter_days()
+        .take_while(|x| {x<=&end_date}).filter(|x|{self.check_workday(*x)}).collect();
+
+        // 開始日と終了日の処理
+        match closed {
+            Closed::Left => {  // 開始日を許容
+                if workdays_vec.last().unwrap_or(&IMPOSSIBLE_DATE_1)==&end_date {workdays_vec.pop();}
+            },
+            Closed::Right => {  // 終了日を許容
+                if workdays_vec.first().unwrap_or(&IMPOSSIBLE_DATE_1)==&start_date {workdays_vec.remove(0);}
+            },
+            Closed::Both => {  // どちらも許容
+            },
+            Closed::Not => {  // どちらも削除
+                if workdays_vec.last().unwrap_or(&IMPOSSIBLE_DATE_1)==&end_date {workdays_vec.pop();}
+                if workdays_vec.first().unwrap_or(&IMPOSSIBLE_DATE_1)==&start_date {workdays_vec.remove(0);}
+            }
+        }
+
+        workdays_vec
+    }
+
+    /// start_dateからend_dateまでの営業日数を取得する．営業日のベクターを作成しない
+    /// Argments
+    /// - start_date: 開始日
+    /// - end_date: 終了日
+    /// - closed: 境界を含めるかどうか
+    ///
+    /// Return
+    /// 営業日数(get_workdaysの長さと等しい)
+    pub fn count_workdays(&self, start_date: NaiveDate, end_date: NaiveDate, closed: Closed) -> usize {
+        if start_date > end_date {
+            return 0;
+        }
+
+        let is_start_workday = self.check_workday(start_date);
+        let is_end_workday = self.check_workday(end_date);
+
+        // 終了日を含む営業日数
+        let mut workdays_number = self.workday_index.count_workdays(start_date, end_date) as usize;
+        if is_end_workday {workdays_number += 1;}
+
+        // 開始日と終了日の処理
+        match closed {
+            Closed::Left => {  // 開始日を許容
+                if is_end_workday {workdays_number -= 1;}
+            },
+            Closed::Right => {  // 終了日を許容
+                if is_start_workday {workdays_number -= 1;}
+            },
+            Closed::Both => {  // どちらも許容
+            },
+            Closed::Not => {  // どちらも削除
+                if is_end_workday {workdays_number -= 1;}
+                if is_start_workday {workdays_number = workdays_number.saturating_sub(1);}
+            }
+        }
+
+        workdays_number
+    }
+
+    /// select_dateが営業日であるか判定
+    /// Argment
+    /// - select_date: 指定する日
+    ///
+    /// Return
+    /// 営業日であるかどうか
+    pub fn check_workday(&self, select_date: NaiveDate) -> bool {
+        self.workday_index.check_workday(select_date)
+    }
+
+    /// 営業日が存在するか確認する
+    /// Argment
+    /// - select_date: 探索を始める日
+    fn check_workday_reachable(&self, select_date: NaiveDate) -> Result<(), Error> {
+        if self.workday_index.is_all_holiday_weekdays() {
+            Err(Error::NoWorkdayReachable{date_str: select_date.to_string()})
+        } else {
+            Ok(())
+        }
+    }
+
+    /// select_dateの次の営業日を祝日データの範囲を確認せずに取得(select_dateは含まない)
+    pub(crate) fn try_get_next_workday_unchecked(&self, select_date: NaiveDate) -> Result<NaiveDate, Error> {
+        self.check_workday_reachable(select_date)?;
+        let mut one_day = select_date;
+        loop {
+            one_day = one_day.succ_opt().ok_or_else(||{Error::OutOfRange{date_str: select_date.to_string()}})?;
+            if self.check_workday(one_day) {
+                return Ok(one_day);
+            }
+        }
+    }
+
+    /// select_dateの前の営業日を祝日データの範囲を確認せずに取得(select_dateは含まない)
+    pub(crate) fn try_get_previous_workday_unchecked(&self, select_date: NaiveDate) -> Result<NaiveDate, Error> {
+        self.check_workday_reachable(select_date)?;
+        let mut one_day = select_date;
+        loop {
+            one_day = one_day.pred_opt().ok_or_else(||{Error::OutOfRange{date_str: select_date.to_string()}})?;
+            if self.check_workday(one_day) {
+                return Ok(one_day);
+            }
+        }
+    }
+
+    /// select_dateからdays分の次の営業日をcoverage_policyに従って取得
+    pub(crate) fn _get_next_workday(&self, select_date: NaiveDate, days: i32, coverage_policy: CoveragePolicy) -> Result<NaiveDate, Error> {
+        self.check_workday_reachable(select_date)?;
+        self._check_coverage(select_date, coverage_policy)?;
+
+        // daysを計算するカウンター
+        let mut counter:i32 = 0;
+        let mut one_day = select_date;
+
+        // 初日はworkdaysでもカウントしない
+        loop {
+            one_day = one_day.succ_opt().ok_or_else(||{Error::OutOfRange{date_str: select_date.to_string()}})?;
+            if self.check_workday(one_day) { // その日が営業日である
+                counter += 1; // カウンターをインクリメント
+            }
+
+            if counter >= days {
+                break;
+            }
+        }
+
+        self._check_coverage(one_day, coverage_policy)?;
+        Ok(one_day)
+    }
+
+    /// select_dateからdays分の次の営業日を取得
+    /// Argments
+    /// - select_date: 指定する日
+    /// - days: 進める日数
+    ///
+    /// Return
+    /// one_day: 次の営業日．営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラー
+    pub fn try_get_next_workday(&self, select_date: NaiveDate, days: i32) -> Result<NaiveDate, Error> {
+        self._get_next_workday(select_date, days, self.coverage_policy)
+    }
+
+    /// select_dateからdays分の次の営業日を取得
+    /// Argments
+    /// - select_date: 指定する日
+    /// - days: 進める日数
+    ///
+    /// Return
+    /// one_day: 次の営業日(営業日が存在しない場合はパニックする)
+    pub fn get_next_workday(&self, select_date: NaiveDate, days: i32) -> NaiveDate {
+        self._get_next_workday(select_date, days, CoveragePolicy::Ignore).unwrap()
+    }
+
+    /// select_dateからdays分の前の営業日をcoverage_policyに従って取得
+    pub(crate) fn _get_previous_workday(&self, select_date: NaiveDate, days: i32, coverage_policy: CoveragePolicy) -> Result<NaiveDate, Error> {
+        self.check_workday_reachable(select_date)?;
+        self._check_coverage(select_date, coverage_policy)?;
+
+        // daysを計算するカウンター
+        let mut counter:i32 = 0;
+        let mut one_day = select_date;
+
+        // 初日はworkdaysでもカウントしない
+        loop {
+            one_day = one_day.pred_opt().ok_or_else(||{Error::OutOfRange{date_str: select_date.to_string()}})?;
+            if self.check_workday(one_day) { // その日が営業日である
+                counter += 1; // カウンターをインクリメント
+            }
+
+            if counter >= days {
+                break;
+            }
+        }
+
+        self._check_coverage(one_day, coverage_policy)?;
+        Ok(one_day)
+    }
+
+    /// select_dateからdays分の前の営業日を取得
+    /// Argment
+    /// - select_date: 指定する日
+    /// - days: 減らす日数
+    ///
+    /// Return
+    /// one_day: 前の営業日．営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラー
+    pub fn try_get_previous_workday(&self, select_date: NaiveDate, days: i32) -> Result<NaiveDate, Error> {
+        self._get_previous_workday(select_date, days, self.coverage_policy)
+    }
+
+    /// select_dateからdays分の前の営業日を取得
+    /// Argment
+    /// - select_date: 指定する日
+    /// - days: 減らす日数
+    ///
+    /// Return
+    /// one_day: 前の営業日(営業日が存在しない場合はパニックする)
+    pub fn get_previous_workday(&self, select_date: NaiveDate, days: i32) -> NaiveDate {
+        self._get_previous_workday(select_date, days, CoveragePolicy::Ignore).unwrap()
+    }
+
+    /// 最近の営業日をcoverage_policyに従って取得
+    pub(crate) fn _get_near_workday(&self, select_date: NaiveDate, is_after: bool, coverage_policy: CoveragePolicy) -> Result<NaiveDate, Error> {
+        if self.check_workday(select_date) { // 指定日が営業日である場合
+            self._check_coverage(select_date, coverage_policy)?;
+            Ok(select_date)
+        } else if is_after {
+            self._get_next_workday(select_date, 1, coverage_policy)
+        } else {
+            self._get_previous_workday(select_date, 1, coverage_policy)
+        }
+    }
+
+    /// 最近の営業日を取得
+    /// Argments
+    /// - select_date: 指定する日
+    /// - is_after: 後の営業日を所得するかどうか
+    ///
+    /// Return
+    /// 最近の営業日．営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラー
+    pub fn try_get_near_workday(&self, select_date: NaiveDate, is_after: bool) -> Result<NaiveDate, Error> {
+        self._get_near_workday(select_date, is_after, self.coverage_policy)
+    }
+
+    /// 最近の営業日を取得
+    /// Argments
+    /// - select_date: 指定する日
+    /// - is_after: 後の営業日を所得するかどうか
+    ///
+    /// Return
+    /// 最近の営業日(営業日が存在しない場合はパニックする)
+    pub fn get_near_workday(&self, select_date: NaiveDate, is_after: bool) -> NaiveDate{
+        self._get_near_workday(select_date, is_after, CoveragePolicy::Ignore).unwrap()
+    }
+
+    /// start_dateからdays分だけ後ろの営業日のベクターをcoverage_policyに従って取得
+    fn _get_next_workdays_number(&self, start_date: NaiveDate, days: i32, coverage_policy: CoveragePolicy) -> Result<Vec<NaiveDate>, Error>{
+        self.check_workday_reachable(start_date)?;
+        self._check_coverage(start_date, coverage_policy)?;
+
+        // daysを計算するカウンター
+        let mut counter:i32 = 0;
+        let mut one_day = start_date;
+
+        let mut workdays_vec: Vec<NaiveDate> = Vec::new();
+
+        // 初日もカウントする
+        loop {
+            if self.check_workday(one_day) { // その日が営業日である
+                counter += 1; // カウンターをインクリメント
+                workdays_vec.push(one_day)  // workdays_vecに追加
+            }
+
+            if counter >= days {
+                break;
+            }
+
+            one_day = one_day.succ_opt().ok_or_else(||{Error::OutOfRange{date_str: start_date.to_string()}})?;
+        }
+
+        self._check_coverage(one_day, coverage_policy)?;
+        Ok(workdays_vec)
+    }
+
+    /// start_dateからdays分だけ後ろの営業日のベクターを取得
+    /// Argments
+    /// - start_date: 開始日
+    /// - days: 日数
+    ///
+    /// Return
+    /// workdays_vec: 営業日のベクター．営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラー
+    pub fn try_get_next_workdays_number(&self, start_date: NaiveDate, days: i32) -> Result<Vec<NaiveDate>, Error>{
+        self._get_next_workdays_number(start_date, days, self.coverage_policy)
+    }
+
+    /// start_dateからdays分だけ後ろの営業日のベクターを取得
+    /// Argments
+    /// - start_date: 開始日
+    /// - days: 日数
+    ///
+    /// Return
+    /// workdays_vec: 営業日のベクター(営業日が存在しない場合はパニックする)
+    pub fn get_next_workdays_number(&self, start_date: NaiveDate, days: i32) -> Vec<NaiveDate>{
+        self._get_next_workdays_number(start_date, days, CoveragePolicy::Ignore).unwrap()
+    }
+
+    /// start_dateからdays分だけ前の営業日のベクターをcoverage_policyに従って取得
+    fn _get_previous_workdays_number(&self, start_date: NaiveDate, days: i32, coverage_policy: CoveragePolicy) -> Result<Vec<NaiveDate>, Error>{
+        self.check_workday_reachable(start_date)?;
+        self._check_coverage(start_date, coverage_policy)?;
+
+        // daysを計算するカウンター
+        let mut counter:i32 = 0;
+        let mut one_day = start_date;
+
+        let mut workdays_vec: Vec<NaiveDate> = Vec::new();
+
+        // 初日もカウントする
+        loop {
+            if self.check_workday(one_day) { // その日が営業日である
+                counter += 1; // カウンターをインクリメント
+                workdays_vec.push(one_day)  // workdays_vecに追加
+            }
+
+            if counter >= days {
+                break;
+            }
+
+            one_day = one_day.pred_opt().ok_or_else(||{Error::OutOfRange{date_str: start_date.to_string()}})?;
+        }
+
+        self._check_coverage(one_day, coverage_policy)?;
+        Ok(workdays_vec)
+    }
+
+    /// start_dateからdays分だけ前の営業日のベクターを取得
+    /// Argments
+    /// - start_date: 開始日
+    /// - days: 日数
+    ///
+    /// Return
+    /// workdays_vec: 営業日のベクター．営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラー
+    pub fn try_get_previous_workdays_number(&self, start_date: NaiveDate, days: i32) -> Result<Vec<NaiveDate>, Error>{
+        self._get_previous_workdays_number(start_date, days, self.coverage_policy)
+    }
+
+    /// start_dateからdays分だけ前の営業日のベクターを取得
+    /// Argments
+    /// - start_date: 開始日
+    /// - days: 日数
+    ///
+    /// Return
+    /// workdays_vec: 営業日のベクター(営業日が存在しない場合はパニックする)
+    pub fn get_previous_workdays_number(&self, start_date: NaiveDate, days: i32) -> Vec<NaiveDate>{
+        self._get_previous_workdays_number(start_date, days, CoveragePolicy::Ignore).unwrap()
+    }
+
+    /// start_dateからdays分だけの営業日のベクターをcoverage_policyに従って取得
+    fn _get_workdays_number(&self, start_date: NaiveDate, days: i32, coverage_policy: CoveragePolicy) -> Result<Vec<NaiveDate>, Error> {
+        match days {
+            days if days > 0 => self._get_next_workdays_number(start_date, days, coverage_policy),
+            days if days < 0 => self._get_previous_workdays_number(start_date, days.abs(), coverage_policy),
+            _ => Ok(Vec::new())  // 0 の場合
+        }
+    }
+
+    /// start_dateからdays分だけの営業日のベクターを取得
+    /// Argments
+    /// - start_date: 開始日
+    /// - days: 日数
+    ///
+    /// Return
+    /// workdays_vec: 営業日のベクター．営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラー
+    pub fn try_get_workdays_number(&self, start_date: NaiveDate, days: i32) -> Result<Vec<NaiveDate>, Error> {
+        self._get_workdays_number(start_date, days, self.coverage_policy)
+    }
+
+    /// start_dateからdays分だけの営業日のベクターを取得
+    /// Argments
+    /// - start_date: 開始日
+    /// - days: 日数
+    ///
+    /// Return
+    /// workdays_vec: 営業日のベクター(営業日が存在しない場合はパニックする)
+    pub fn get_workdays_number(&self, start_date: NaiveDate, days: i32) -> Vec<NaiveDate> {
+        self._get_workdays_number(start_date, days, CoveragePolicy::Ignore).unwrap()
+    }
+}
+
+
+/// start_dateからend_dateまでの営業日を取得
 /// Argments
 /// - start_date: 開始日
 /// - end_date: 終了日
@@ -26,10 +406,10 @@ pub enum Closed {
 ///     - right: 開始境界を含めない
 ///     - not: どちらの境界も含める
 ///     - both: どちらの境界も含めない
-/// 
-/// Return  
+///
+/// Return
 /// workdays_vec: 営業日のべクター
-/// 
+///
 /// # Example
 /// ~~~~
 /// use chrono::{NaiveDate};
@@ -39,55 +419,44 @@ pub enum Closed {
 /// let workdays_vec = get_workdays(workday_start_date, workday_end_date, Closed::Left);
 /// println!("workdays_vec: {:?}", workdays_vec);
 /// ~~~~
-/// 
+///
 /// workdays_vec: [2021-01-04, 2021-01-05, 2021-01-06, 2021-01-07, 2021-01-08, 2021-01-12, 2021-01-13,
 ///  2021-01-14, 2021-01-15, 2021-01-18, 2021-01-19, 2021-01-20, 2021-01-21, 2021-01-22, 2021-01-25,
 ///  2021-01-26, 2021-01-27, 2021-01-28, 2021-01-29]
 ///
 pub fn get_workdays(start_date: NaiveDate, end_date: NaiveDate, closed: Closed) -> Vec<NaiveDate> {
+    read_work_calendar().get_workdays(start_date, end_date, closed)
+}
 
-    let holidays_vec = RANGE_HOLIDAYS.read().unwrap();
-    let one_holiday_weekday_set = HOLIDAY_WEEKDAYS.read().unwrap();
-
-    let all_day_set: HashSet<NaiveDate> = start_date.iter_days()
-    .take_while(|x| {x<=&end_date}).collect();  // 全ての日
-
-    let holiday_weekdays_set: HashSet<NaiveDate> = start_date.iter_days()
-    .take_while(|x| {x<=&end_date}).filter(|x|{one_holiday_weekday_set.contains(&x.weekday())}).collect();
-
-    // setによる演算
-    let holidays_set: HashSet<NaiveDate> = holidays_vec.iter().cloned().collect();
-    let all_holidays_set: HashSet<NaiveDate> = holidays_set.union(&holiday_weekdays_set).cloned().collect();
-
-    let mut workdays_vec: Vec<NaiveDate> = all_day_set.difference(&all_holidays_set).cloned().collect();
-    workdays_vec.sort();
-
-    // 開始日と終了日の処理
-    match closed {
-        Closed::Left => {  // 開始日を許容
-            if workdays_vec.last().unwrap_or(&IMPOSSIBLE_DATE_1)==&end_date {workdays_vec.remove(workdays_vec.len()-1);}
-        },
-        Closed::Right => {  // 終了日を許容
-            if workdays_vec.first().unwrap_or(&IMPOSSIBLE_DATE_1)==&start_date {workdays_vec.remove(0);}            
-        },
-        Closed::Both => {  // どちらも許容
-        },
-        Closed::Not => {  // どちらも削除
-            if workdays_vec.last().unwrap_or(&IMPOSSIBLE_DATE_1)==&end_date {workdays_vec.remove(workdays_vec.len()-1);}
-            if workdays_vec.first().unwrap_or(&IMPOSSIBLE_DATE_1)==&start_date {workdays_vec.remove(0);}
-        }
-    }
-
-    return workdays_vec;
+/// start_dateからend_dateまでの営業日数を取得する．営業日のベクターを作成しない
+/// Argments
+/// - start_date: 開始日
+/// - end_date: 終了日
+/// - closed: 境界を含めるかどうか
+///
+/// Return
+/// 営業日数(get_workdaysの長さと等しい)
+///
+/// # Example
+/// ~~~~
+/// use chrono::{NaiveDate};
+/// use rs_workdays::workdays::*;
+/// let workday_start_date = NaiveDate::from_ymd(2021,1,1);
+/// let workday_end_date = NaiveDate::from_ymd(2021,2,1);
+/// let workdays_number = count_workdays(workday_start_date, workday_end_date, Closed::Left);
+/// assert_eq!(workdays_number, 19);
+/// ~~~~
+pub fn count_workdays(start_date: NaiveDate, end_date: NaiveDate, closed: Closed) -> usize {
+    read_work_calendar().count_workdays(start_date, end_date, closed)
 }
 
 /// select_dateが営業日であるか判定
 /// Argment
 /// - select_date: 指定する日
-/// 
+///
 /// Return
 /// 営業日であるかどうか
-/// 
+///
 /// # Example
 /// ~~~~
 /// use chrono::{NaiveDate};
@@ -97,23 +466,17 @@ pub fn get_workdays(start_date: NaiveDate, end_date: NaiveDate, closed: Closed)
 /// assert!(!is_workday);
 /// ~~~~
 pub fn check_workday(select_date: NaiveDate) -> bool {
-    let holidays_vec = RANGE_HOLIDAYS.read().unwrap();
-    let one_holiday_weekday_set = HOLIDAY_WEEKDAYS.read().unwrap();
-
-    let holidays_set: HashSet<NaiveDate> = holidays_vec.iter().cloned().collect();
-    let is_holiday: bool = holidays_set.contains(&select_date);
-    let is_holiday_weekday: bool = one_holiday_weekday_set.contains(&select_date.weekday());
-    (!is_holiday) & (!is_holiday_weekday)
+    read_work_calendar().check_workday(select_date)
 }
 
 /// select_dateからdays分の次の営業日を取得
 /// Argments
 /// - select_date: 指定する日
 /// - days: 進める日数
-/// 
+///
 /// Return
 /// one_day: 次の営業日
-/// 
+///
 /// # Example
 /// ~~~~
 /// use chrono::{NaiveDate};
@@ -123,55 +486,44 @@ pub fn check_workday(select_date: NaiveDate) -> bool {
 /// assert_eq!(next_workday, NaiveDate::from_ymd(2021,01,12));
 /// ~~~~
 pub fn get_next_workday(select_date: NaiveDate, days: i32) -> NaiveDate {
-    let holidays_vec = RANGE_HOLIDAYS.read().unwrap();
-    let one_holiday_weekday_set = HOLIDAY_WEEKDAYS.read().unwrap();
-    
-    let holidays_bigger_select: Vec<NaiveDate> = holidays_vec.iter().cloned().filter(|x| {x >= &select_date}).collect();
-
-    // daysを計算するカウンター
-    let mut counter:i32 = 0;
-
-    // イテレーターの作成
-    let mut holiday_iter = holidays_bigger_select.iter();
-    let mut day_iter = select_date.iter_days(); 
-
-    let mut one_holiday = holiday_iter.next().unwrap_or(&IMPOSSIBLE_DATE_1);
-    let mut one_day = day_iter.next().unwrap();
-
-    // 最初はloopの外で，さらに初日がworkdaysでもカウントしない
-    if one_day==*one_holiday {
-        one_holiday = holiday_iter.next().unwrap_or(&IMPOSSIBLE_DATE_1);
-    }
-
-    one_day = day_iter.next().unwrap();
-
-    loop {
-        if one_day==*one_holiday { // その日が祝日である
-            one_holiday = holiday_iter.next().unwrap_or(&IMPOSSIBLE_DATE_1);
-        } else { // その日が祝日でない
-            if !one_holiday_weekday_set.contains(&one_day.weekday()) { // その日が休日曜日でない
-                counter += 1; // カウンターをインクリメント
-            }
-        }
-
-        if counter >= days {
-            break;
-        }
-
-        one_day = day_iter.next().unwrap();
-    }
+    read_work_calendar().get_next_workday(select_date, days)
+}
 
-    return one_day;
+/// select_dateからdays分の次の営業日を取得する．営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラーを返す
+/// Argments
+/// - select_date: 指定する日
+/// - days: 進める日数
+///
+/// Return
+/// 営業日．デフォルトのカレンダーのロックがポイズンされている場合もエラー
+///
+/// # Examples
+/// ~~~~
+/// use chrono::{NaiveDate};
+/// use rs_workdays::workdays::*;
+/// use std::collections::HashSet;
+/// use chrono::Weekday;
+/// use rs_workdays::{WorkCalendar, Error};
+/// let select_date = NaiveDate::from_ymd(2021,1,1);
+/// assert_eq!(try_get_next_workday(select_date, 6).unwrap(), NaiveDate::from_ymd(2021,01,12));
+///
+/// let mut calendar = WorkCalendar::default();
+/// let all_weekdays: HashSet<Weekday> = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun].iter().cloned().collect();
+/// calendar.set_holiday_weekdays(&all_weekdays);
+/// assert!(matches!(calendar.try_get_next_workday(select_date, 1), Err(Error::NoWorkdayReachable{..})));
+/// ~~~~
+pub fn try_get_next_workday(select_date: NaiveDate, days: i32) -> Result<NaiveDate, Error> {
+    try_read_work_calendar()?.try_get_next_workday(select_date, days)
 }
 
 /// select_dateからdays分の前の営業日を取得
 /// Argment
 /// - select_date: 指定する日
 /// - days: 減らす日数
-/// 
+///
 /// Return
 /// one_day: 前の営業日
-/// 
+///
 /// # Example
 /// ~~~~
 /// use chrono::{NaiveDate};
@@ -181,55 +533,38 @@ pub fn get_next_workday(select_date: NaiveDate, days: i32) -> NaiveDate {
 /// assert_eq!(previous_workday, NaiveDate::from_ymd(2020,12,31));
 /// ~~~~
 pub fn get_previous_workday(select_date: NaiveDate, days: i32) -> NaiveDate {
-    let holidays_vec = RANGE_HOLIDAYS.read().unwrap();
-    let one_holiday_weekday_set = HOLIDAY_WEEKDAYS.read().unwrap();
-
-    let mut holidays_smaller_select: Vec<NaiveDate> = holidays_vec.iter().cloned().filter(|x| {x <= &select_date}).collect();
-    holidays_smaller_select.reverse();
-
-    // daysを計算するカウンター
-    let mut counter:i32 = 0;
-
-    // イテレーターの作成
-    let mut holiday_iter = holidays_smaller_select.iter();
-
-    let mut one_holiday = holiday_iter.next().unwrap_or(&IMPOSSIBLE_DATE_1);
-    let mut one_day = select_date;
-
-    // 最初はloopの外で，さらに初日がworkdaysでもカウントしない
-    if one_day==*one_holiday {
-        one_holiday = holiday_iter.next().unwrap_or(&IMPOSSIBLE_DATE_1);
-    }
-
-    one_day = one_day.pred_opt().unwrap();
-
-    loop {
-        if one_day==*one_holiday { // その日が祝日である
-            one_holiday = holiday_iter.next().unwrap_or(&IMPOSSIBLE_DATE_1);
-        } else { // その日が祝日でない
-            if !one_holiday_weekday_set.contains(&one_day.weekday()) { // その日が休日曜日でない
-                counter += 1; // カウンターをインクリメント
-            }
-        }
-
-        if counter >= days {
-            break;
-        }
-
-        one_day = one_day.pred_opt().unwrap();
-    }
+    read_work_calendar().get_previous_workday(select_date, days)
+}
 
-    return one_day;
+/// select_dateからdays分の前の営業日を取得する．営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラーを返す
+/// Argments
+/// - select_date: 指定する日
+/// - days: 減らす日数
+///
+/// Return
+/// 営業日．デフォルトのカレンダーのロックがポイズンされている場合もエラー
+///
+/// # Examples
+/// ~~~~
+/// use chrono::{NaiveDate};
+/// use rs_workdays::workdays::*;
+/// use rs_workdays::Error;
+/// let select_date = NaiveDate::from_ymd(2021,1,12);
+/// assert_eq!(try_get_previous_workday(select_date, 6).unwrap(), NaiveDate::from_ymd(2020,12,31));
+/// assert!(matches!(try_get_previous_workday(NaiveDate::MIN, 1), Err(Error::OutOfRange{..})));
+/// ~~~~
+pub fn try_get_previous_workday(select_date: NaiveDate, days: i32) -> Result<NaiveDate, Error> {
+    try_read_work_calendar()?.try_get_previous_workday(select_date, days)
 }
 
 /// 最近の営業日を取得
 /// Argments
 /// - select_date: 指定する日
 /// - is_after: 後の営業日を所得するかどうか
-/// 
+///
 /// Return
 /// 最近の営業日
-/// 
+///
 /// # Examples
 /// ~~~~
 /// use chrono::{NaiveDate};
@@ -237,31 +572,42 @@ pub fn get_previous_workday(select_date: NaiveDate, days: i32) -> NaiveDate {
 /// let select_date = NaiveDate::from_ymd(2021, 1, 1);
 /// let near_workday = get_near_workday(select_date, true);
 /// assert_eq!(near_workday, NaiveDate::from_ymd(2021,01,04));
-/// 
+///
 /// let select_date = NaiveDate::from_ymd(2021, 1, 1);
 /// let near_workday = get_near_workday(select_date, false);
 /// assert_eq!(near_workday, NaiveDate::from_ymd(2020,12,31))
 /// ~~~~
 pub fn get_near_workday(select_date: NaiveDate, is_after: bool) -> NaiveDate{
-    if check_workday(select_date) { // 指定日が営業日である場合
-        select_date
-    } else {
-        if is_after {
-            get_next_workday(select_date, 1)
-        } else {
-            get_previous_workday(select_date, 1)
-        }
-    }
+    read_work_calendar().get_near_workday(select_date, is_after)
+}
+
+/// 最近の営業日を取得する．営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラーを返す
+/// Argments
+/// - select_date: 指定する日
+/// - is_after: 後の営業日を所得するかどうか
+///
+/// Return
+/// 営業日．デフォルトのカレンダーのロックがポイズンされている場合もエラー
+///
+/// # Examples
+/// ~~~~
+/// use chrono::{NaiveDate};
+/// use rs_workdays::workdays::*;
+/// let select_date = NaiveDate::from_ymd(2021, 1, 1);
+/// assert_eq!(try_get_near_workday(select_date, true).unwrap(), NaiveDate::from_ymd(2021,01,04));
+/// ~~~~
+pub fn try_get_near_workday(select_date: NaiveDate, is_after: bool) -> Result<NaiveDate, Error> {
+    try_read_work_calendar()?.try_get_near_workday(select_date, is_after)
 }
 
 /// start_dateからdays分だけ後ろの営業日のベクターを取得
 /// Argments
 /// - start_date: 開始日
 /// - days: 日数
-/// 
+///
 /// Return
 /// workdays_vec: 営業日のベクター
-/// 
+///
 /// # Examples
 /// ~~~~
 /// use chrono::{NaiveDate};
@@ -270,57 +616,41 @@ pub fn get_near_workday(select_date: NaiveDate, is_after: bool) -> NaiveDate{
 /// let workdays_vec = get_next_workdays_number(start_date, 19);
 /// println!("workdays_vec: {:?}", workdays_vec);
 /// ~~~~
-/// 
+///
 /// workdays_vec: [2021-01-04, 2021-01-05, 2021-01-06, 2021-01-07, 2021-01-08, 2021-01-12, 2021-01-13,
 ///  2021-01-14, 2021-01-15, 2021-01-18, 2021-01-19, 2021-01-20, 2021-01-21, 2021-01-22, 2021-01-25,
 ///  2021-01-26, 2021-01-27, 2021-01-28, 2021-01-29]
 pub fn get_next_workdays_number(start_date: NaiveDate, days: i32) -> Vec<NaiveDate>{
-    let holidays_vec = RANGE_HOLIDAYS.read().unwrap();
-    let one_holiday_weekday_set = HOLIDAY_WEEKDAYS.read().unwrap();
-    
-    let holidays_bigger_select: Vec<NaiveDate> = holidays_vec.iter().cloned().filter(|x| {x >= &start_date}).collect();
-
-    // daysを計算するカウンター
-    let mut counter:i32 = 0;
-
-    // イテレーターの作成
-    let mut holiday_iter = holidays_bigger_select.iter();
-    let mut day_iter = start_date.iter_days(); 
-
-    let mut one_holiday = holiday_iter.next().unwrap_or(&IMPOSSIBLE_DATE_1);
-    let mut one_day = day_iter.next().unwrap();
-
-    let mut workdays_vec: Vec<NaiveDate> = Vec::new();
-
-    // 初日もカウントする
-    loop {
-        if one_day==*one_holiday { // その日が祝日である
-            one_holiday = holiday_iter.next().unwrap_or(&IMPOSSIBLE_DATE_1);
-        } else { // その日が祝日でない
-            if !one_holiday_weekday_set.contains(&one_day.weekday()) { // その日が休日曜日でない
-                counter += 1; // カウンターをインクリメント
-                workdays_vec.push(one_day)  // workdays_vecに追加
-            }
-        }
-
-        if counter >= days {
-            break;
-        }
-
-        one_day = day_iter.next().unwrap();
-    }
+    read_work_calendar().get_next_workdays_number(start_date, days)
+}
 
-    return workdays_vec;
+/// start_dateからdays分だけ後ろの営業日のベクターを取得する．営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラーを返す
+/// Argments
+/// - start_date: 開始日
+/// - days: 日数
+///
+/// Return
+/// 営業日のベクター．デフォルトのカレンダーのロックがポイズンされている場合もエラー
+///
+/// # Examples
+/// ~~~~
+/// use chrono::{NaiveDate};
+/// use rs_workdays::workdays::*;
+/// let start_date = NaiveDate::from_ymd(2021, 1, 1);
+/// assert_eq!(try_get_next_workdays_number(start_date, 19).unwrap().len(), 19);
+/// ~~~~
+pub fn try_get_next_workdays_number(start_date: NaiveDate, days: i32) -> Result<Vec<NaiveDate>, Error> {
+    try_read_work_calendar()?.try_get_next_workdays_number(start_date, days)
 }
 
 /// start_dateからdays分だけ前の営業日のベクターを取得
 /// Argments
 /// - start_date: 開始日
 /// - days: 日数
-/// 
+///
 /// Return
 /// workdays_vec: 営業日のベクター
-/// 
+///
 /// # Examples
 /// ~~~~
 /// use chrono::{NaiveDate};
@@ -330,57 +660,41 @@ pub fn get_next_workdays_number(start_date: NaiveDate, days: i32) -> Vec<NaiveDa
 /// workdays_vec.sort();
 /// println!("workdays_vec: {:?}", workdays_vec);
 /// ~~~~
-/// 
+///
 /// workdays_vec: [2021-01-04, 2021-01-05, 2021-01-06, 2021-01-07, 2021-01-08, 2021-01-12, 2021-01-13,
 ///  2021-01-14, 2021-01-15, 2021-01-18, 2021-01-19, 2021-01-20, 2021-01-21, 2021-01-22, 2021-01-25,
 ///  2021-01-26, 2021-01-27, 2021-01-28, 2021-01-29]
 pub fn get_previous_workdays_number(start_date: NaiveDate, days: i32) -> Vec<NaiveDate>{
-    let holidays_vec = RANGE_HOLIDAYS.read().unwrap();
-    let one_holiday_weekday_set = HOLIDAY_WEEKDAYS.read().unwrap();
-
-    let mut holidays_smaller_select: Vec<NaiveDate> = holidays_vec.iter().cloned().filter(|x| {x <= &start_date}).collect();
-    holidays_smaller_select.reverse();
-
-    // daysを計算するカウンター
-    let mut counter:i32 = 0;
-
-    // イテレーターの作成
-    let mut holiday_iter = holidays_smaller_select.iter();
-
-    let mut one_holiday = holiday_iter.next().unwrap_or(&IMPOSSIBLE_DATE_1);
-    let mut one_day = start_date;
-
-    let mut workdays_vec: Vec<NaiveDate> = Vec::new();
-
-    // 初日もカウントする
-    loop {
-        if one_day==*one_holiday { // その日が祝日である
-            one_holiday = holiday_iter.next().unwrap_or(&IMPOSSIBLE_DATE_1);
-        } else { // その日が祝日でない
-            if !one_holiday_weekday_set.contains(&one_day.weekday()) { // その日が休日曜日でない
-                counter += 1; // カウンターをインクリメント
-                workdays_vec.push(one_day)  // workdays_vecに追加
-            }
-        }
-
-        if counter >= days {
-            break;
-        }
-
-        one_day = one_day.pred_opt().unwrap();
-    }
+    read_work_calendar().get_previous_workdays_number(start_date, days)
+}
 
-    return workdays_vec;
+/// start_dateからdays分だけ前の営業日のベクターを取得する．営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラーを返す
+/// Argments
+/// - start_date: 開始日
+/// - days: 日数
+///
+/// Return
+/// 営業日のベクター．デフォルトのカレンダーのロックがポイズンされている場合もエラー
+///
+/// # Examples
+/// ~~~~
+/// use chrono::{NaiveDate};
+/// use rs_workdays::workdays::*;
+/// let start_date = NaiveDate::from_ymd(2021,1,29);
+/// assert_eq!(try_get_previous_workdays_number(start_date, 19).unwrap().len(), 19);
+/// ~~~~
+pub fn try_get_previous_workdays_number(start_date: NaiveDate, days: i32) -> Result<Vec<NaiveDate>, Error> {
+    try_read_work_calendar()?.try_get_previous_workdays_number(start_date, days)
 }
 
 /// start_dateからdays分だけの営業日のベクターを取得
 /// Argments
 /// - start_date: 開始日
 /// - days: 日数
-/// 
+///
 /// Return
 /// workdays_vec: 営業日のベクター
-/// 
+///
 /// # Examples
 /// ~~~~
 /// use chrono::{NaiveDate};
@@ -389,18 +703,29 @@ pub fn get_previous_workdays_number(start_date: NaiveDate, days: i32) -> Vec<Nai
 /// let workdays_vec = get_workdays_number(start_date, 19);
 /// println!("workdays_vec: {:?}", workdays_vec);
 /// ~~~~
-/// 
+///
 /// workdays_vec: [2021-01-04, 2021-01-05, 2021-01-06, 2021-01-07, 2021-01-08, 2021-01-12, 2021-01-13,
 ///  2021-01-14, 2021-01-15, 2021-01-18, 2021-01-19, 2021-01-20, 2021-01-21, 2021-01-22, 2021-01-25,
 ///  2021-01-26, 2021-01-27, 2021-01-28, 2021-01-29]
 pub fn get_workdays_number(start_date: NaiveDate, days: i32) -> Vec<NaiveDate> {
-    if days > 0 {
-        get_next_workdays_number(start_date, days)
-    } else if days < 0 {
-        get_previous_workdays_number(start_date, days.abs())
-    } else { // 0 の場合
-        let nan_vec: Vec<NaiveDate> = Vec::new();
-        return nan_vec;
-    }
+    read_work_calendar().get_workdays_number(start_date, days)
 }
 
+/// start_dateからdays分だけの営業日のベクターを取得する．営業日が存在しない場合・日付の範囲外となる場合・方針により祝日データの範囲外となる場合はエラーを返す
+/// Argments
+/// - start_date: 開始日
+/// - days: 日数
+///
+/// Return
+/// 営業日のベクター．デフォルトのカレンダーのロックがポイズンされている場合もエラー
+///
+/// # Examples
+/// ~~~~
+/// use chrono::{NaiveDate};
+/// use rs_workdays::workdays::*;
+/// let start_date = NaiveDate::from_ymd(2021, 1, 1);
+/// assert_eq!(try_get_workdays_number(start_date, -19).unwrap().len(), 19);
+/// ~~~~
+pub fn try_get_workdays_number(start_date: NaiveDate, days: i32) -> Result<Vec<NaiveDate>, Error> {
+    try_read_work_calendar()?.try_get_workdays_number(start_date, days)
+}
@@ -0,0 +1,30 @@
+#![allow(dead_code)]  // テストごとに利用する関数が異なる
+use std::collections::HashSet;
+use chrono::{NaiveDate, NaiveTime, Weekday};
+
+use rs_workdays::{WorkCalendar, TimeBorder};
+
+/// 時・分の組から営業時間境界を作成
+pub fn border(start: (u32, u32), end: (u32, u32)) -> TimeBorder {
+    TimeBorder {start: NaiveTime::from_hms(start.0, start.1, 0), end: NaiveTime::from_hms(end.0, end.1, 0)}
+}
+
+/// 土日の休日曜日
+pub fn weekend() -> HashSet<Weekday> {
+    [Weekday::Sat, Weekday::Sun].iter().cloned().collect()
+}
+
+/// 日中取引(8時45分～15時15分)と夜間取引(16時30分～翌6時)をもつカレンダー
+pub fn overnight_calendar() -> WorkCalendar {
+    WorkCalendar::new(
+        vec![
+            NaiveDate::from_ymd(1969, 12, 31),
+            NaiveDate::from_ymd(2020, 12, 31),
+            NaiveDate::from_ymd(2021, 1, 1),
+            NaiveDate::from_ymd(2021, 1, 11),
+            NaiveDate::from_ymd(2021, 2, 11)
+        ],
+        weekend(),
+        vec![border((8, 45), (15, 15)), border((16, 30), (6, 0))]
+    )
+}
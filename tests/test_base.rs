@@ -1,3 +1,5 @@
+#![allow(deprecated)]  // 従来の文字列を返す関数の互換性も確認する
+#![allow(clippy::clone_on_copy, clippy::assign_op_pattern)]
 use std::collections::HashSet;
 use chrono::{NaiveDate, Datelike, NaiveDateTime, Duration};
 
@@ -6,7 +8,7 @@ extern crate  rs_workdays;
 use rs_workdays::workdays::*;
 use rs_workdays::intraday::*;
 use rs_workdays::extract::*;
-use rs_workdays::global::{HOLIDAY_WEEKDAYS, INTRADAY_BORDERS};
+use rs_workdays::global::{get_holiday_weekdays, get_intraday_borders};
 
 mod test_global;
 use test_global::{true_holidays_2021};
@@ -14,7 +16,7 @@ use test_global::{true_holidays_2021};
 #[test]
 fn related_workdays() {
     // get_workdays
-    let one_holiday_weekday_set = HOLIDAY_WEEKDAYS.read().unwrap();
+    let one_holiday_weekday_set = get_holiday_weekdays();
     let start_date = NaiveDate::from_ymd(2021, 1, 1);
     let end_date = NaiveDate::from_ymd(2021, 12, 31);
     let all_day_set: HashSet<NaiveDate> = start_date.iter_days()
@@ -87,7 +89,7 @@ fn related_extract() {
     assert_eq!(extracted_workdays_datetime, true_workdays_datetime);
 
     // extract_intraday_bool_vec
-    let intraday_borders = INTRADAY_BORDERS.read().unwrap();
+    let intraday_borders = get_intraday_borders();
     let true_intraday_datetime: Vec<NaiveDateTime> = all_datetime_vec.iter().cloned()
     .filter(|x|{
         intraday_borders.iter().map(|y|{(y.start<=x.time()) & (x.time()<y.end)})
@@ -0,0 +1,47 @@
+#![allow(deprecated)]
+use std::collections::HashSet;
+use chrono::{NaiveDate, NaiveDateTime, Duration, Weekday};
+
+extern crate rs_workdays;
+
+use rs_workdays::{WorkCalendar, BorderKind};
+
+mod common;
+use common::border;
+
+#[test]
+fn border_kind_index() {
+    // 前場9:00～11:30・後場12:30～15:00
+    let weekend: HashSet<Weekday> = [Weekday::Sat, Weekday::Sun].iter().cloned().collect();
+    let calendar = WorkCalendar::new(vec![], weekend, vec![border((12, 30), (15, 0)), border((9, 0), (11, 30))]);
+    let monday = NaiveDate::from_ymd(2021, 1, 4);
+
+    assert_eq!(calendar.get_next_border(monday.and_hms(8, 0, 0)), (monday.and_hms(9, 0, 0), BorderKind::Start(0)));
+    assert_eq!(calendar.get_next_border(monday.and_hms(9, 0, 0)), (monday.and_hms(11, 30, 0), BorderKind::End(0)));
+    assert_eq!(calendar.get_next_border(monday.and_hms(11, 30, 0)), (monday.and_hms(12, 30, 0), BorderKind::Start(1)));
+    assert_eq!(calendar.get_previous_border(monday.and_hms(13, 0, 0), false), (monday.and_hms(12, 30, 0), BorderKind::Start(1)));
+    assert_eq!(calendar.get_previous_border(monday.and_hms(12, 0, 0), false), (monday.and_hms(11, 30, 0), BorderKind::End(0)));
+    assert_eq!(calendar.get_near_border(monday.and_hms(10, 0, 0), true), (monday.and_hms(10, 0, 0), BorderKind::Intra(0)));
+    assert_eq!(calendar.get_near_border(monday.and_hms(14, 0, 0), false), (monday.and_hms(14, 0, 0), BorderKind::Intra(1)));
+
+    assert_eq!(BorderKind::End(1).index(), 1);
+    assert_eq!(BorderKind::Start(0).as_str(), "border_start");
+    assert_eq!(BorderKind::End(0).as_str(), "border_end");
+    assert_eq!(BorderKind::Intra(0).as_str(), "border_intra");
+}
+
+#[test]
+fn deprecated_string_shims() {
+    // 文字列を返す関数はBorderKindを返す関数と同じ日時・種類を返す
+    let calendar = WorkCalendar::default();
+    let start_datetime = NaiveDate::from_ymd(2021, 1, 1).and_hms(0, 0, 0);
+    let datetime_vec: Vec<NaiveDateTime> = (0..(10 * 24 * 4)).map(|i|{start_datetime + Duration::minutes(i * 15)}).collect();
+    for datetime in datetime_vec {
+        let (next_datetime, next_kind) = calendar.get_next_border(datetime);
+        assert_eq!(calendar.get_next_border_workday_intraday(datetime), (next_datetime, next_kind.as_str()));
+        let (previous_datetime, previous_kind) = calendar.get_previous_border(datetime, true);
+        assert_eq!(calendar.get_previous_border_workday_intraday(datetime, true), (previous_datetime, previous_kind.as_str()));
+        let (near_datetime, near_kind) = calendar.get_near_border(datetime, false);
+        assert_eq!(calendar.get_near_workday_intraday(datetime, false), (near_datetime, near_kind.as_str()));
+    }
+}
@@ -0,0 +1,83 @@
+use chrono::{NaiveDate, NaiveDateTime, Duration};
+
+extern crate rs_workdays;
+
+use rs_workdays::{WorkCalendar, SnapPolicy, Error};
+use rs_workdays::business_ordinal::Resolution;
+
+mod common;
+use common::{border, weekend};
+
+/// 序数の差が営業時間のDurationと一致し，営業時間内の日時は序数から復元できることを確認する
+fn check_round_trip(calendar: &WorkCalendar, start_datetime: NaiveDateTime, step_minutes: i64, count: i64) {
+    let start_ordinal = calendar.to_business_ordinal(start_datetime, Resolution::Millisecond);
+    let mut previous_ordinal = start_ordinal;
+    for i in 0..count {
+        let datetime = start_datetime + Duration::minutes(i * step_minutes);
+        let business_ordinal = calendar.to_business_ordinal(datetime, Resolution::Millisecond);
+        assert!(business_ordinal >= previous_ordinal);
+        assert_eq!(business_ordinal - start_ordinal, calendar.get_timedelta_workdays_intraday(start_datetime, datetime).num_milliseconds());
+        if calendar.check_workday_intraday(datetime) {
+            assert_eq!(calendar.from_business_ordinal(business_ordinal, Resolution::Millisecond), datetime);
+        }
+        previous_ordinal = business_ordinal;
+    }
+}
+
+#[test]
+fn business_ordinal_round_trip() {
+    let mut calendar = WorkCalendar::default();
+    // 索引の範囲内・範囲より後・範囲より前
+    check_round_trip(&calendar, NaiveDate::from_ymd(2020, 12, 1).and_hms(0, 0, 0), 13, 8000);
+    check_round_trip(&calendar, NaiveDate::from_ymd(2035, 3, 1).and_hms(0, 0, 0), 17, 4000);
+    check_round_trip(&calendar, NaiveDate::from_ymd(1960, 3, 1).and_hms(0, 0, 0), 17, 4000);
+
+    // 適用期間・日付ごとの営業時間
+    calendar.add_range_intraday_borders(NaiveDate::from_ymd(2021, 1, 12), NaiveDate::MAX, &[border((9, 0), (11, 30)), border((12, 30), (15, 30))]);
+    calendar.set_date_intraday_borders(NaiveDate::from_ymd(2021, 1, 8), &[border((9, 0), (11, 30))]);
+    check_round_trip(&calendar, NaiveDate::from_ymd(2021, 1, 1).and_hms(0, 0, 0), 11, 5000);
+    check_round_trip(&calendar, NaiveDate::from_ymd(2040, 1, 1).and_hms(0, 0, 0), 11, 5000);
+
+    // 夜間取引
+    let calendar = WorkCalendar::new(vec![NaiveDate::from_ymd(2021, 1, 11)], weekend(), vec![border((8, 45), (15, 15)), border((16, 30), (6, 0))]);
+    check_round_trip(&calendar, NaiveDate::from_ymd(2021, 1, 1).and_hms(0, 0, 0), 7, 6000);
+}
+
+#[test]
+fn business_ordinal_resolution_and_snap() {
+    let mut calendar = WorkCalendar::new(vec![], weekend(), vec![border((9, 0), (11, 30)), border((12, 30), (15, 0))]);
+    let friday = NaiveDate::from_ymd(2021, 1, 8);
+    let monday = NaiveDate::from_ymd(2021, 1, 11);
+
+    // 1970年1月1日(木)9時が序数0
+    assert_eq!(calendar.to_business_ordinal(NaiveDate::from_ymd(1970, 1, 1).and_hms(9, 0, 0), Resolution::Second), 0);
+    assert_eq!(calendar.to_business_ordinal(NaiveDate::from_ymd(1970, 1, 2).and_hms(9, 0, 0), Resolution::Minute), 300);
+    assert_eq!(calendar.to_business_ordinal(NaiveDate::from_ymd(1969, 12, 31).and_hms(14, 59, 30), Resolution::Minute), -1);
+    assert_eq!(calendar.from_business_ordinal(-1, Resolution::Minute), NaiveDate::from_ymd(1969, 12, 31).and_hms(14, 59, 0));
+    assert_eq!(calendar.to_business_ordinal(friday.and_hms(10, 0, 59), Resolution::Minute), calendar.to_business_ordinal(friday.and_hms(10, 0, 0), Resolution::Minute));
+
+    // 営業時間外の日時は境界の序数となり，境界の序数は方針に従って戻す
+    let closed_ordinal = calendar.to_business_ordinal(friday.and_hms(18, 0, 0), Resolution::Second);
+    assert_eq!(closed_ordinal, calendar.to_business_ordinal(friday.and_hms(15, 0, 0), Resolution::Second));
+    assert_eq!(closed_ordinal, calendar.to_business_ordinal(monday.and_hms(9, 0, 0), Resolution::Second));
+    assert_eq!(calendar.from_business_ordinal(closed_ordinal, Resolution::Second), monday.and_hms(9, 0, 0));
+    let lunch_ordinal = calendar.to_business_ordinal(friday.and_hms(12, 0, 0), Resolution::Second);
+    assert_eq!(calendar.from_business_ordinal(lunch_ordinal, Resolution::Second), friday.and_hms(12, 30, 0));
+
+    calendar.set_snap_policy(SnapPolicy::Previous);
+    assert_eq!(calendar.snap_policy(), SnapPolicy::Previous);
+    assert_eq!(calendar.to_business_ordinal(friday.and_hms(18, 0, 0), Resolution::Second), closed_ordinal);
+    assert_eq!(calendar.from_business_ordinal(closed_ordinal, Resolution::Second), friday.and_hms(15, 0, 0));
+    assert_eq!(calendar.from_business_ordinal(lunch_ordinal, Resolution::Second), friday.and_hms(11, 30, 0));
+    assert_eq!(calendar.from_business_ordinal(lunch_ordinal + 1, Resolution::Second), friday.and_hms(12, 30, 1));
+
+    calendar.set_snap_policy(SnapPolicy::Error);
+    assert!(matches!(calendar.try_to_business_ordinal(friday.and_hms(18, 0, 0), Resolution::Second), Err(Error::OutsideSession{..})));
+    assert_eq!(calendar.try_to_business_ordinal(friday.and_hms(15, 0, 0), Resolution::Second).ok(), None);
+    assert_eq!(calendar.try_to_business_ordinal(friday.and_hms(14, 0, 0), Resolution::Second).ok(), Some(closed_ordinal - 3600));
+    assert_eq!(calendar.from_business_ordinal(closed_ordinal, Resolution::Second), monday.and_hms(9, 0, 0));
+
+    // 営業時間が無い場合
+    calendar.set_intraday_borders(&[]);
+    assert!(matches!(calendar.try_to_business_ordinal(friday.and_hms(10, 0, 0), Resolution::Second), Err(Error::EmptyIntradayBorders)));
+}
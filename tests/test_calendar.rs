@@ -0,0 +1,63 @@
+use std::collections::HashSet;
+use chrono::{NaiveDate, NaiveTime, Weekday, Duration};
+
+extern crate rs_workdays;
+
+use rs_workdays::{WorkCalendar, TimeBorder, Closed};
+use rs_workdays::global::get_work_calendar;
+use rs_workdays::workdays::*;
+use rs_workdays::intraday::*;
+use rs_workdays::extract::*;
+
+#[test]
+fn default_calendar_wrappers() {
+    // フリー関数とデフォルトのカレンダーのメソッドが一致する
+    let calendar = get_work_calendar();
+    let start_date = NaiveDate::from_ymd(2021, 1, 1);
+    let end_date = NaiveDate::from_ymd(2021, 12, 31);
+    assert_eq!(calendar.get_workdays(start_date, end_date, Closed::Both), get_workdays(start_date, end_date, Closed::Both));
+    assert_eq!(calendar.get_next_workday(start_date, 10), get_next_workday(start_date, 10));
+    assert_eq!(calendar.get_previous_workday(end_date, 10), get_previous_workday(end_date, 10));
+
+    let select_datetime = start_date.and_hms(0, 0, 0);
+    assert_eq!(calendar.get_next_border(select_datetime), get_next_border(select_datetime));
+    assert_eq!(
+        calendar.add_workday_intraday_datetime(select_datetime, Duration::hours(30)),
+        add_workday_intraday_datetime(select_datetime, Duration::hours(30))
+    );
+
+    let datetime_vec: Vec<_> = (0..500).map(|i|{select_datetime + Duration::minutes(i * 37)}).collect();
+    assert_eq!(calendar.extract_workdays_intraday_bool(&datetime_vec), extract_workdays_intraday_bool(&datetime_vec));
+}
+
+#[test]
+fn independent_calendars() {
+    // 東証のカレンダーと会社のカレンダーを同時に扱う
+    let tse_calendar = WorkCalendar::default();
+
+    let company_holidays = vec![NaiveDate::from_ymd(2021, 1, 4), NaiveDate::from_ymd(2021, 1, 5)];
+    let company_weekdays: HashSet<Weekday> = [Weekday::Sun].iter().cloned().collect();
+    let company_borders = vec![TimeBorder {start: NaiveTime::from_hms(10, 0, 0), end: NaiveTime::from_hms(18, 0, 0)}];
+    let company_calendar = WorkCalendar::new(company_holidays, company_weekdays, company_borders);
+
+    let select_date = NaiveDate::from_ymd(2021, 1, 2);  // 土曜日
+    assert!(!tse_calendar.check_workday(select_date));
+    assert!(company_calendar.check_workday(select_date));
+
+    assert_eq!(tse_calendar.get_next_workday(select_date, 1), NaiveDate::from_ymd(2021, 1, 4));
+    assert_eq!(company_calendar.get_next_workday(select_date, 1), NaiveDate::from_ymd(2021, 1, 6));
+
+    let select_datetime = NaiveDate::from_ymd(2021, 1, 6).and_hms(9, 30, 0);
+    assert!(tse_calendar.check_workday_intraday(select_datetime));
+    assert!(!company_calendar.check_workday_intraday(select_datetime));
+    assert_eq!(
+        company_calendar.get_timedelta_workdays_intraday(NaiveDate::from_ymd(2021, 1, 2).and_hms(0, 0, 0), NaiveDate::from_ymd(2021, 1, 7).and_hms(0, 0, 0)),
+        Duration::hours(16)
+    );
+
+    // 変更は他のカレンダーに影響しない
+    let mut changed_calendar = tse_calendar.clone();
+    changed_calendar.set_holiday_weekdays(&[Weekday::Mon].iter().cloned().collect());
+    assert!(changed_calendar.check_workday(select_date));
+    assert!(!tse_calendar.check_workday(select_date));
+}
@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+use chrono::{NaiveDate, NaiveDateTime, Duration, Weekday};
+
+extern crate rs_workdays;
+
+use rs_workdays::WorkCalendar;
+
+mod common;
+use common::border;
+
+/// 東京の取引所を模したカレンダー(大納会は前場のみ・金曜は前場のみ)
+fn jp_calendar() -> WorkCalendar {
+    let holiday_weekdays: HashSet<Weekday> = [Weekday::Sat, Weekday::Sun].iter().cloned().collect();
+    let mut calendar = WorkCalendar::new(
+        vec![NaiveDate::from_ymd(2021, 1, 1), NaiveDate::from_ymd(2021, 1, 11), NaiveDate::from_ymd(2021, 2, 11)],
+        holiday_weekdays,
+        vec![border((9, 0), (11, 30)), border((12, 30), (15, 0))]
+    );
+    calendar.set_date_intraday_borders(NaiveDate::from_ymd(2020, 12, 30), &[border((9, 0), (11, 30))]);
+    calendar.set_weekday_intraday_borders(Weekday::Fri, &[border((9, 0), (11, 30))]);
+    calendar
+}
+
+/// 別の取引所を模したカレンダー(土曜も営業・2月から取引時間を変更)
+fn other_calendar() -> WorkCalendar {
+    let holiday_weekdays: HashSet<Weekday> = [Weekday::Sun].iter().cloned().collect();
+    let mut calendar = WorkCalendar::new(
+        vec![NaiveDate::from_ymd(2021, 1, 1), NaiveDate::from_ymd(2021, 1, 18), NaiveDate::from_ymd(2021, 2, 15)],
+        holiday_weekdays,
+        vec![border((10, 0), (16, 0))]
+    );
+    calendar.add_range_intraday_borders(NaiveDate::from_ymd(2021, 2, 1), NaiveDate::MAX, &[border((8, 0), (10, 0)), border((14, 0), (17, 0))]);
+    calendar
+}
+
+fn datetime_vec() -> Vec<NaiveDateTime> {
+    let start_datetime = NaiveDate::from_ymd(2020, 12, 20).and_hms(0, 0, 0);
+    (0..(80 * 24 * 6)).map(|i|{start_datetime + Duration::minutes(i * 10)}).collect()
+}
+
+#[test]
+fn union_and_intersection_match_elementwise() {
+    let jp = jp_calendar();
+    let other = other_calendar();
+    let union_calendar = jp.union(&other);
+    let intersection_calendar = jp.intersection(&other);
+
+    for datetime in datetime_vec().into_iter() {
+        let (is_jp_open, is_other_open) = (jp.check_workday_intraday(datetime), other.check_workday_intraday(datetime));
+        assert_eq!(union_calendar.check_workday_intraday(datetime), is_jp_open & is_other_open, "union at {}", datetime);
+        assert_eq!(intersection_calendar.check_workday_intraday(datetime), is_jp_open | is_other_open, "intersection at {}", datetime);
+
+        let date = datetime.date();
+        assert_eq!(union_calendar.check_workday(date), jp.check_workday(date) & other.check_workday(date));
+        assert_eq!(intersection_calendar.check_workday(date), jp.check_workday(date) | other.check_workday(date));
+    }
+
+    // 合成したカレンダーは通常のカレンダーと同様に使える
+    let start_datetime = NaiveDate::from_ymd(2021, 2, 5).and_hms(9, 0, 0);
+    // 金曜は9:00～10:00，月曜は9:00～10:00・14:00～15:00
+    assert_eq!(union_calendar.add_workday_intraday_datetime(start_datetime, Duration::minutes(90)), NaiveDate::from_ymd(2021, 2, 8).and_hms(9, 30, 0));
+    assert_eq!(union_calendar.get_next_workday(NaiveDate::from_ymd(2021, 1, 8), 1), NaiveDate::from_ymd(2021, 1, 12));
+}
+
+#[test]
+fn except_holidays() {
+    let jp = jp_calendar();
+    let other = other_calendar();
+    let except_calendar = jp.except(&other);
+    assert_eq!(except_calendar.range_holidays(), &[NaiveDate::from_ymd(2021, 1, 11), NaiveDate::from_ymd(2021, 2, 11)]);
+    let expected_weekdays: HashSet<Weekday> = [Weekday::Sat].iter().cloned().collect();
+    assert_eq!(except_calendar.holiday_weekdays(), &expected_weekdays);
+    assert_eq!(except_calendar.intraday_borders_on(NaiveDate::from_ymd(2020, 12, 30)), jp.intraday_borders_on(NaiveDate::from_ymd(2020, 12, 30)));
+    assert!(except_calendar.check_workday(NaiveDate::from_ymd(2021, 1, 1)));
+    assert!(except_calendar.check_workday(NaiveDate::from_ymd(2021, 1, 3)));
+    assert!(!except_calendar.check_workday(NaiveDate::from_ymd(2021, 1, 2)));
+}
+
+#[test]
+fn compose_overnight_borders() {
+    // 日付をまたぐ営業時間も開始日ごとの区間として合成する
+    let holiday_weekdays: HashSet<Weekday> = [Weekday::Sat, Weekday::Sun].iter().cloned().collect();
+    let calendar = WorkCalendar::new(vec![], holiday_weekdays.clone(), vec![border((8, 45), (15, 15)), border((16, 30), (6, 0))]);
+    let other = WorkCalendar::new(vec![], holiday_weekdays, vec![border((9, 0), (15, 0)), border((15, 0), (16, 0)), border((20, 0), (7, 0))]);
+    assert_eq!(calendar.union(&other).intraday_borders(), &[border((9, 0), (15, 15)), border((20, 0), (6, 0))]);
+    assert_eq!(calendar.intersection(&other).intraday_borders(), &[border((8, 45), (16, 0)), border((16, 30), (7, 0))]);
+}
+
+#[test]
+fn overnight_spill_is_not_composed() {
+    // 前日に開始した営業時間の翌日の部分は，翌日に開始する営業時間とは合成しない
+    let holiday_weekdays: HashSet<Weekday> = HashSet::new();
+    let calendar = WorkCalendar::new(vec![], holiday_weekdays.clone(), vec![border((22, 0), (2, 0))]);
+    let other = WorkCalendar::new(vec![], holiday_weekdays, vec![border((0, 0), (3, 0))]);
+    assert!(calendar.union(&other).intraday_borders().is_empty());
+    assert_eq!(calendar.intersection(&other).intraday_borders(), &[border((0, 0), (3, 0)), border((22, 0), (2, 0))]);
+}
+
+#[cfg(feature="tz")]
+#[test]
+fn compose_different_time_zones() {
+    use chrono_tz::America::New_York;
+    use rs_workdays::Error;
+
+    let calendar = jp_calendar();
+    let mut other = other_calendar();
+    other.set_time_zone(New_York);
+    assert!(matches!(calendar.try_union(&other), Err(Error::TimeZoneMismatch{..})));
+    assert!(matches!(calendar.try_intersection(&other), Err(Error::TimeZoneMismatch{..})));
+    assert!(matches!(calendar.try_except(&other), Err(Error::TimeZoneMismatch{..})));
+
+    // タイムゾーンを揃えれば合成できる
+    other.set_time_zone(calendar.time_zone());
+    assert_eq!(calendar.try_union(&other).unwrap(), calendar.union(&other));
+}
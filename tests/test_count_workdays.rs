@@ -0,0 +1,28 @@
+use chrono::{NaiveDate, Duration};
+
+extern crate rs_workdays;
+
+use rs_workdays::{WorkCalendar, Closed};
+use rs_workdays::jp_holidays::get_jp_holiday_infos;
+
+#[test]
+fn count_workdays_closed() {
+    // 全てのClosedについて営業日のベクターの長さと一致する
+    let mut calendar = WorkCalendar::default();
+    calendar.set_range_holiday_infos(&get_jp_holiday_infos(2000, 2030), 2000, 2030);
+
+    let start_date = NaiveDate::from_ymd(1998, 12, 25);
+    for i in 0..200_i64 {
+        let one_start_date = start_date + Duration::days(i * 61);
+        for span_days in [-1_i64, 0, 1, 2, 3, 6, 30, 400].iter() {
+            let one_end_date = one_start_date + Duration::days(*span_days);
+            for closed in [Closed::Left, Closed::Right, Closed::Not, Closed::Both].iter() {
+                assert_eq!(
+                    calendar.count_workdays(one_start_date, one_end_date, *closed),
+                    calendar.get_workdays(one_start_date, one_end_date, *closed).len(),
+                    "{} {} {:?}", one_start_date, one_end_date, closed
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::{Arc, Mutex};
+use chrono::NaiveDate;
+
+extern crate rs_workdays;
+
+use rs_workdays::{WorkCalendar, HolidayCoverage, CoveragePolicy, RollConvention, Error};
+
+#[test]
+fn coverage_of_calendar() {
+    // デフォルトのカレンダーは2016年から2025年のうちcsvに祝日が存在する範囲
+    let mut calendar = WorkCalendar::default();
+    assert_eq!(calendar.coverage(), Some(HolidayCoverage {start_year: 2016, end_year: 2022}));
+
+    // 指定した範囲のうち実際に祝日が存在する年の範囲となる
+    let holidays = vec![NaiveDate::from_ymd(2021, 1, 1), NaiveDate::from_ymd(2022, 1, 1), NaiveDate::from_ymd(2030, 1, 1)];
+    calendar.set_range_holidays(&holidays, 2000, 2025);
+    assert_eq!(calendar.coverage(), Some(HolidayCoverage {start_year: 2021, end_year: 2022}));
+
+    // 追加した場合は両方の範囲を含む
+    calendar.add_range_holidays(&[NaiveDate::from_ymd(2030, 1, 1)], 2030, 2030);
+    assert_eq!(calendar.coverage(), Some(HolidayCoverage {start_year: 2021, end_year: 2030}));
+
+    // 祝日が無い場合はNone
+    calendar.set_range_holidays(&[], 2000, 2025);
+    assert_eq!(calendar.coverage(), None);
+
+    let calendar = WorkCalendar::new(holidays, calendar.holiday_weekdays().clone(), Vec::new());
+    assert_eq!(calendar.coverage(), Some(HolidayCoverage {start_year: 2021, end_year: 2030}));
+}
+
+#[test]
+fn coverage_policy() {
+    let mut calendar = WorkCalendar::default();
+    let inside_date = NaiveDate::from_ymd(2021, 1, 1);
+    let outside_date = NaiveDate::from_ymd(2024, 1, 1);  // 祝日データが無いため営業日となる
+
+    // デフォルトは無視する
+    assert_eq!(calendar.coverage_policy(), CoveragePolicy::Ignore);
+    assert_eq!(calendar.try_get_next_workday(outside_date, 1).unwrap(), outside_date.succ());
+
+    calendar.set_coverage_policy(CoveragePolicy::Error);
+    assert!(calendar.check_coverage(inside_date).is_ok());
+    assert!(matches!(calendar.check_coverage(outside_date), Err(Error::OutsideHolidayCoverage{..})));
+    assert!(matches!(calendar.try_get_next_workday(outside_date, 1), Err(Error::OutsideHolidayCoverage{..})));
+    assert!(matches!(calendar.try_get_near_workday(outside_date, true), Err(Error::OutsideHolidayCoverage{..})));
+    assert!(matches!(calendar.try_get_workdays_number(NaiveDate::from_ymd(2022, 12, 29), 3), Err(Error::OutsideHolidayCoverage{..})));
+    assert!(matches!(calendar.try_add_workday_intraday_datetime(outside_date.and_hms(10, 0, 0), chrono::Duration::hours(1)), Err(Error::OutsideHolidayCoverage{..})));
+    assert!(calendar.try_get_next_workday(inside_date, 1).is_ok());
+
+    // try_から始まらないメソッドは確認しない
+    assert_eq!(calendar.get_next_workday(outside_date, 1), outside_date.succ());
+    assert_eq!(calendar.get_near_workday(outside_date, true), outside_date);
+    assert_eq!(calendar.get_workdays_number(NaiveDate::from_ymd(2022, 12, 29), 3).len(), 3);
+    assert_eq!(calendar.adjust(outside_date, RollConvention::Following), outside_date);
+    assert_eq!(calendar.add_workday_intraday_datetime(outside_date.and_hms(10, 0, 0), chrono::Duration::hours(1)), outside_date.and_hms(11, 0, 0));
+    assert_eq!(calendar.get_next_border(outside_date.and_hms(10, 0, 0)).0, outside_date.and_hms(11, 30, 0));
+
+    // 警告の場合はコールバックを呼んで結果を返す
+    let warnings: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let handler_warnings = warnings.clone();
+    calendar.set_coverage_policy(CoveragePolicy::Warn);
+    calendar.set_coverage_warning_handler(move |err|{handler_warnings.lock().unwrap().push(err.to_string())});
+    assert_eq!(calendar.try_get_next_workday(outside_date, 1).unwrap(), outside_date.succ());
+    assert_eq!(warnings.lock().unwrap().len(), 2);  // 指定した日と結果の日
+    assert!(calendar.check_coverage(outside_date).is_ok());
+    assert_eq!(warnings.lock().unwrap().len(), 3);
+    calendar.get_next_workday(outside_date, 1);
+    calendar.try_get_next_workday(inside_date, 1).unwrap();
+    assert_eq!(warnings.lock().unwrap().len(), 3);
+}
@@ -0,0 +1,69 @@
+use chrono::{NaiveDate, Duration};
+
+extern crate rs_workdays;
+
+use rs_workdays::{WorkCalendar, Closed};
+use rs_workdays::day_count::*;
+use rs_workdays::jp_holidays::get_jp_holiday_infos;
+
+fn assert_close(left: f64, right: f64) {
+    assert!((left - right).abs() < 1e-12, "left: {}, right: {}", left, right);
+}
+
+#[test]
+fn actual_day_counts() {
+    let calendar = WorkCalendar::default();
+    let start_date = NaiveDate::from_ymd(2019, 11, 15);
+    let end_date = NaiveDate::from_ymd(2020, 3, 15);  // 121日
+    assert_close(calendar.year_fraction(start_date, end_date, DayCount::Act365Fixed), 121.0 / 365.0);
+    assert_close(calendar.year_fraction(start_date, end_date, DayCount::Act360), 121.0 / 360.0);
+    assert_close(calendar.year_fraction(start_date, end_date, DayCount::ActActIsda), 47.0 / 365.0 + 74.0 / 366.0);
+    assert_close(calendar.year_fraction(end_date, start_date, DayCount::ActActIsda), -(47.0 / 365.0 + 74.0 / 366.0));
+    assert_close(calendar.year_fraction(start_date, start_date, DayCount::ActActIsda), 0.0);
+}
+
+#[test]
+fn thirty_360_day_counts() {
+    let calendar = WorkCalendar::default();
+
+    // 開始日が31日
+    let start_date = NaiveDate::from_ymd(2021, 1, 31);
+    let end_date = NaiveDate::from_ymd(2021, 3, 31);
+    assert_close(calendar.year_fraction(start_date, end_date, DayCount::Thirty360BondBasis), 60.0 / 360.0);
+    assert_close(calendar.year_fraction(start_date, end_date, DayCount::Thirty360European), 60.0 / 360.0);
+    assert_close(calendar.year_fraction(start_date, end_date, DayCount::Thirty360EuropeanIsda), 60.0 / 360.0);
+
+    // 終了日のみ31日
+    let start_date = NaiveDate::from_ymd(2021, 1, 15);
+    let end_date = NaiveDate::from_ymd(2021, 3, 31);
+    assert_close(calendar.year_fraction(start_date, end_date, DayCount::Thirty360BondBasis), 76.0 / 360.0);
+    assert_close(calendar.year_fraction(start_date, end_date, DayCount::Thirty360European), 75.0 / 360.0);
+
+    // 2月末
+    let start_date = NaiveDate::from_ymd(2021, 2, 28);
+    let end_date = NaiveDate::from_ymd(2021, 8, 31);
+    assert_close(calendar.year_fraction(start_date, end_date, DayCount::Thirty360BondBasis), 183.0 / 360.0);
+    assert_close(calendar.year_fraction(start_date, end_date, DayCount::Thirty360European), 182.0 / 360.0);
+    assert_close(calendar.year_fraction(start_date, end_date, DayCount::Thirty360EuropeanIsda), 180.0 / 360.0);
+}
+
+#[test]
+fn bus_252_day_count() {
+    // 祝日法から計算した長期間の祝日で，営業日のベクターの長さと一致する
+    let mut calendar = WorkCalendar::default();
+    calendar.set_range_holiday_infos(&get_jp_holiday_infos(1955, 2100), 1955, 2100);
+
+    let start_date = NaiveDate::from_ymd(1955, 1, 1);
+    for (i, end_date) in start_date.iter_days().step_by(1301).take(41).enumerate() {
+        let one_start_date = start_date + Duration::days(i as i64 * 37);
+        if end_date < one_start_date {
+            continue;
+        }
+        let workdays_number = calendar.get_workdays(one_start_date, end_date, Closed::Left).len();
+        assert_close(calendar.year_fraction(one_start_date, end_date, DayCount::Bus252), workdays_number as f64 / 252.0);
+    }
+
+    let fraction = calendar.year_fraction(NaiveDate::from_ymd(1955, 1, 1), NaiveDate::from_ymd(2100, 12, 31), DayCount::Bus252);
+    let workdays_number = calendar.get_workdays(NaiveDate::from_ymd(1955, 1, 1), NaiveDate::from_ymd(2100, 12, 31), Closed::Left).len();
+    assert_close(fraction, workdays_number as f64 / 252.0);
+}
@@ -0,0 +1,31 @@
+use chrono::{NaiveDate, NaiveDateTime};
+
+extern crate rs_workdays;
+
+use rs_workdays::equinox::*;
+
+fn assert_near(computed: NaiveDateTime, expected: NaiveDateTime) {
+    let diff_seconds = (computed - expected).num_seconds().abs();
+    assert!(diff_seconds <= 120, "computed: {:?}, expected: {:?}", computed, expected);
+}
+
+#[test]
+fn equinox_datetime() {
+    // 国立天文台の暦要項(日本標準時)
+    assert_near(get_vernal_equinox(2021), NaiveDate::from_ymd(2021, 3, 20).and_hms(18, 37, 0));
+    assert_near(get_autumnal_equinox(2021), NaiveDate::from_ymd(2021, 9, 23).and_hms(4, 21, 0));
+    assert_near(get_vernal_equinox(2025), NaiveDate::from_ymd(2025, 3, 20).and_hms(18, 1, 0));
+    assert_near(get_autumnal_equinox(2025), NaiveDate::from_ymd(2025, 9, 23).and_hms(3, 19, 0));
+    assert_near(get_vernal_equinox(2026), NaiveDate::from_ymd(2026, 3, 20).and_hms(23, 46, 0));
+}
+
+#[test]
+fn equinox_date_range() {
+    // 1900年から2150年まで3月19日～22日・9月21日～24日に収まる
+    for year in 1900..=2150 {
+        let vernal_equinox = get_vernal_equinox(year).date();
+        assert!((NaiveDate::from_ymd(year, 3, 19)..=NaiveDate::from_ymd(year, 3, 22)).contains(&vernal_equinox));
+        let autumnal_equinox = get_autumnal_equinox(year).date();
+        assert!((NaiveDate::from_ymd(year, 9, 21)..=NaiveDate::from_ymd(year, 9, 24)).contains(&autumnal_equinox));
+    }
+}
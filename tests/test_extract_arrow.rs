@@ -0,0 +1,31 @@
+#![cfg(feature="arrow")]
+use chrono::{NaiveDate, NaiveDateTime, Duration};
+use arrow2::array::{BooleanArray, PrimitiveArray};
+use arrow2::datatypes::{DataType, TimeUnit};
+
+extern crate rs_workdays;
+
+use rs_workdays::{WorkCalendar, Error};
+
+#[test]
+fn extract_arrow_matches_slice() {
+    let calendar = WorkCalendar::default();
+    let start_datetime = NaiveDate::from_ymd(2021, 1, 1).and_hms(0, 0, 0);
+    let datetime_vec: Vec<NaiveDateTime> = (0..(7 * 24 * 4)).map(|i|{start_datetime + Duration::minutes(i * 15)}).collect();
+    let array = PrimitiveArray::<i64>::from_vec(datetime_vec.iter().map(|x|{x.timestamp_millis()}).collect())
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+
+    let expected: BooleanArray = calendar.extract_workdays_intraday_bool(&datetime_vec).into_iter().map(Some).collect();
+    assert_eq!(calendar.extract_workdays_intraday_bool_arrow(&array).unwrap(), expected);
+    let expected: BooleanArray = calendar.extract_workdays_bool(&datetime_vec).into_iter().map(Some).collect();
+    assert_eq!(calendar.extract_workdays_bool_arrow(&array).unwrap(), expected);
+}
+
+#[test]
+fn extract_arrow_unsupported() {
+    let calendar = WorkCalendar::default();
+    let array = PrimitiveArray::<i64>::from_vec(vec![0, 1]);  // タイムスタンプでない
+    assert!(matches!(calendar.extract_intraday_bool_arrow(&array), Err(Error::UnsupportedDataType{..})));
+    let array = array.to(DataType::Timestamp(TimeUnit::Second, Some("Asia/Tokyo".to_string())));  // タイムゾーンのある
+    assert!(matches!(calendar.extract_intraday_bool_arrow(&array), Err(Error::UnsupportedDataType{..})));
+}
@@ -0,0 +1,48 @@
+use chrono::{NaiveDate, NaiveDateTime, Duration};
+
+extern crate rs_workdays;
+
+use rs_workdays::extract::EpochUnit;
+
+mod common;
+use common::overnight_calendar;
+
+fn datetime_vec(start_datetime: NaiveDateTime) -> Vec<NaiveDateTime> {
+    (0..(10 * 24 * 4)).map(|i|{start_datetime + Duration::minutes(i * 15) + Duration::milliseconds(i)}).collect()
+}
+
+#[test]
+fn extract_iter_matches_slice() {
+    let calendar = overnight_calendar();
+    let datetime_vec = datetime_vec(NaiveDate::from_ymd(2021, 1, 6).and_hms(0, 0, 0));
+    assert_eq!(calendar.extract_workdays_bool_iter(datetime_vec.iter().cloned()), calendar.extract_workdays_bool(&datetime_vec));
+    assert_eq!(calendar.extract_intraday_bool_iter(datetime_vec.iter().cloned()), calendar.extract_intraday_bool(&datetime_vec));
+    assert_eq!(calendar.extract_workdays_intraday_bool_iter(datetime_vec.clone()), calendar.extract_workdays_intraday_bool(&datetime_vec));
+    assert!(calendar.extract_workdays_bool_iter(Vec::new()).is_empty());
+}
+
+#[test]
+fn extract_epoch_matches_slice() {
+    let calendar = overnight_calendar();
+    // エポックの前後を含む
+    for start_datetime in [NaiveDate::from_ymd(2021, 1, 6).and_hms(0, 0, 0), NaiveDate::from_ymd(1969, 12, 27).and_hms(0, 0, 0)].iter() {
+        let datetime_vec = datetime_vec(*start_datetime);
+        let expected_workdays = calendar.extract_workdays_bool(&datetime_vec);
+        let expected_intraday = calendar.extract_intraday_bool(&datetime_vec);
+        let expected_workdays_intraday = calendar.extract_workdays_intraday_bool(&datetime_vec);
+        let seconds_vec: Vec<i64> = datetime_vec.iter().map(|x|{x.timestamp()}).collect();
+        let millis_vec: Vec<i64> = datetime_vec.iter().map(|x|{x.timestamp_millis()}).collect();
+        let nanos_vec: Vec<i64> = datetime_vec.iter().map(|x|{x.timestamp_nanos()}).collect();
+        let micros_vec: Vec<i64> = nanos_vec.iter().map(|x|{x.div_euclid(1_000)}).collect();
+        for (timestamps, unit) in [(&seconds_vec, EpochUnit::Second), (&millis_vec, EpochUnit::Millisecond), (&micros_vec, EpochUnit::Microsecond), (&nanos_vec, EpochUnit::Nanosecond)].iter() {
+            assert_eq!(calendar.extract_workdays_bool_epoch(timestamps, *unit), expected_workdays);
+            assert_eq!(calendar.extract_intraday_bool_epoch(timestamps, *unit), expected_intraday);
+            assert_eq!(calendar.extract_workdays_intraday_bool_epoch(timestamps, *unit), expected_workdays_intraday);
+        }
+    }
+
+    // 秒の境界(営業時間の終了の直前・直後)
+    let end_datetime = NaiveDate::from_ymd(2021, 1, 8).and_hms(15, 15, 0);
+    let nanos_vec = vec![end_datetime.timestamp_nanos() - 1, end_datetime.timestamp_nanos()];
+    assert_eq!(calendar.extract_workdays_intraday_bool_epoch(&nanos_vec, EpochUnit::Nanosecond), vec![true, false]);
+}
@@ -0,0 +1,44 @@
+#![cfg(feature="rayon")]
+use std::collections::HashSet;
+use chrono::{NaiveDate, NaiveDateTime, Duration};
+
+extern crate rs_workdays;
+
+use rs_workdays::WorkCalendar;
+
+mod common;
+use common::overnight_calendar;
+
+fn assert_same_as_sequential(calendar: &WorkCalendar, datetime_vec: &[NaiveDateTime]) {
+    assert_eq!(calendar.par_extract_workdays_bool(datetime_vec), calendar.extract_workdays_bool(datetime_vec));
+    assert_eq!(calendar.par_extract_intraday_bool(datetime_vec), calendar.extract_intraday_bool(datetime_vec));
+    assert_eq!(calendar.par_extract_workdays_intraday_bool(datetime_vec), calendar.extract_workdays_intraday_bool(datetime_vec));
+}
+
+#[test]
+fn par_extract_matches_sequential() {
+    let calendar = overnight_calendar();
+    let start_datetime = NaiveDate::from_ymd(2021, 1, 1).and_hms(0, 0, 0);
+
+    // 一日に多くの日時があり，チャンクが日付の途中で終わる場合
+    let datetime_vec: Vec<NaiveDateTime> = (0..(60 * 24 * 60)).map(|i|{start_datetime + Duration::seconds(i * 7)}).collect();
+    assert_same_as_sequential(&calendar, &datetime_vec);
+
+    // 同じ日時が続く場合
+    let datetime_vec: Vec<NaiveDateTime> = (0..200_000).map(|i|{start_datetime + Duration::hours(i / 20_000)}).collect();
+    assert_same_as_sequential(&calendar, &datetime_vec);
+
+    assert_same_as_sequential(&calendar, &[]);
+}
+
+#[test]
+fn par_extract_unsorted_matches_sequential() {
+    let calendar = overnight_calendar();
+    let start_datetime = NaiveDate::from_ymd(2021, 1, 1).and_hms(0, 0, 0);
+    let datetime_vec: Vec<NaiveDateTime> = (0..100_000_i64).map(|i|{start_datetime + Duration::minutes((i * 7919) % 100_000)}).collect();
+    assert_same_as_sequential(&calendar, &datetime_vec);
+
+    // 営業時間の境界が空の場合
+    let calendar = WorkCalendar::new(vec![], HashSet::new(), vec![]);
+    assert_same_as_sequential(&calendar, &datetime_vec);
+}
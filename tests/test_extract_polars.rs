@@ -0,0 +1,32 @@
+#![cfg(feature="polars")]
+use chrono::{NaiveDate, NaiveDateTime, Duration};
+use polars_core::prelude::*;
+
+extern crate rs_workdays;
+
+use rs_workdays::{WorkCalendar, Error};
+
+#[test]
+fn extract_series_matches_slice() {
+    let calendar = WorkCalendar::default();
+    let start_datetime = NaiveDate::from_ymd(2021, 1, 1).and_hms(0, 0, 0);
+    let datetime_vec: Vec<NaiveDateTime> = (0..(7 * 24 * 4)).map(|i|{start_datetime + Duration::minutes(i * 15)}).collect();
+    let timestamps: Vec<Option<i64>> = datetime_vec.iter().map(|x|{Some(x.timestamp_nanos())}).chain(Some(None)).collect();
+    let series = Int64Chunked::from_slice_options("tick", &timestamps).into_datetime(TimeUnit::Nanoseconds, None).into_series();
+
+    let mask = calendar.extract_workdays_intraday_bool_series(&series).unwrap();
+    assert_eq!(mask.name(), "tick");
+    let expected: Vec<Option<bool>> = calendar.extract_workdays_intraday_bool(&datetime_vec).into_iter().map(Some).chain(Some(None)).collect();
+    assert_eq!(mask.bool().unwrap().into_iter().collect::<Vec<Option<bool>>>(), expected);
+
+    let mask = calendar.extract_intraday_bool_series(&series).unwrap();
+    let expected: Vec<Option<bool>> = calendar.extract_intraday_bool(&datetime_vec).into_iter().map(Some).chain(Some(None)).collect();
+    assert_eq!(mask.bool().unwrap().into_iter().collect::<Vec<Option<bool>>>(), expected);
+}
+
+#[test]
+fn extract_series_unsupported() {
+    let calendar = WorkCalendar::default();
+    let series = Int64Chunked::from_slice("tick", &[0, 1]).into_series();
+    assert!(matches!(calendar.extract_workdays_bool_series(&series), Err(Error::UnsupportedDataType{..})));
+}
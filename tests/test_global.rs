@@ -1,3 +1,4 @@
+#![allow(clippy::needless_return)]
 use chrono::{NaiveDate};
 
 pub fn true_holidays_2021() -> Vec<NaiveDate> {
@@ -0,0 +1,34 @@
+use chrono::NaiveDate;
+
+extern crate rs_workdays;
+
+use rs_workdays::WorkCalendar;
+use rs_workdays::holiday_info::*;
+use rs_workdays::jp_holidays::get_jp_holiday_infos;
+
+#[test]
+fn holiday_infos_from_csv() {
+    // csvから推定した休日の種類が祝日法の規則によるものと一致する
+    let mut calendar = WorkCalendar::default();
+    calendar.set_holidays_csvs(&["source/holidays.csv".to_string()], 1955, 2022).unwrap();
+    let csv_holiday_infos = calendar.get_holidays_with_names(NaiveDate::from_ymd(1955, 1, 1), NaiveDate::from_ymd(2022, 12, 31));
+    assert_eq!(csv_holiday_infos, get_jp_holiday_infos(1955, 2022));
+
+    assert_eq!(calendar.get_holiday_name(NaiveDate::from_ymd(2019, 5, 1)), Some("休日（祝日扱い）"));
+    assert_eq!(calendar.get_holiday_info(NaiveDate::from_ymd(2019, 4, 30)).unwrap().kind, HolidayKind::Citizens);
+    assert_eq!(calendar.get_holiday_info(NaiveDate::from_ymd(2019, 5, 6)).unwrap().kind, HolidayKind::Substitute);
+    assert_eq!(calendar.get_holiday_info(NaiveDate::from_ymd(2019, 5, 7)), None);
+}
+
+#[test]
+fn unnamed_holidays() {
+    // 名前の無い休日を追加しても既存の名前は残る
+    let mut calendar = WorkCalendar::default();
+    let company_holidays = vec![NaiveDate::from_ymd(2021, 1, 1), NaiveDate::from_ymd(2021, 12, 30)];
+    calendar.add_range_holidays(&company_holidays, 2021, 2021);
+
+    assert_eq!(calendar.get_holiday_name(NaiveDate::from_ymd(2021, 1, 1)), Some("元日"));
+    assert_eq!(calendar.get_holiday_name(NaiveDate::from_ymd(2021, 12, 30)), None);
+    assert_eq!(calendar.get_holiday_info(NaiveDate::from_ymd(2021, 12, 30)), Some(&HolidayInfo::unnamed(NaiveDate::from_ymd(2021, 12, 30))));
+    assert!(!calendar.check_workday(NaiveDate::from_ymd(2021, 12, 30)));
+}
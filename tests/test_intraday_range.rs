@@ -0,0 +1,65 @@
+use chrono::{NaiveDate, NaiveDateTime, Duration};
+
+extern crate rs_workdays;
+
+use rs_workdays::{WorkCalendar, Closed};
+use rs_workdays::intraday_range::Label;
+
+mod common;
+use common::{border, weekend};
+
+#[test]
+fn intraday_range_matches_extract() {
+    // 候補の日時を抽出する従来の方法と一致する
+    let calendar = WorkCalendar::default();
+    let start_datetime = NaiveDate::from_ymd(2020, 12, 28).and_hms(0, 0, 0);
+    let end_datetime = NaiveDate::from_ymd(2021, 1, 15).and_hms(0, 0, 0);
+    for step_minutes in [1_i64, 5, 15].iter() {
+        let candidate_vec: Vec<NaiveDateTime> = (0..((end_datetime - start_datetime).num_minutes() / step_minutes))
+            .map(|i|{start_datetime + Duration::minutes(i * step_minutes)}).collect();
+        let expected: Vec<NaiveDateTime> = candidate_vec.iter().zip(calendar.extract_workdays_intraday_bool(&candidate_vec))
+            .filter(|(_, is_intraday)|{*is_intraday}).map(|(datetime, _)|{*datetime}).collect();
+        let bars: Vec<NaiveDateTime> = calendar.generate_intraday_range(start_datetime, end_datetime, Duration::minutes(*step_minutes), Label::Left, Closed::Left).collect();
+        assert_eq!(bars, expected);
+    }
+}
+
+#[test]
+fn intraday_range_alignment_and_closed() {
+    // 前場9:00～11:30・後場12:35～15:00(昼休み明けが足の間隔と揃わない)
+    let calendar = WorkCalendar::new(vec![], weekend(), vec![border((9, 0), (11, 30)), border((12, 35), (15, 0))]);
+    let monday = NaiveDate::from_ymd(2021, 1, 4);
+
+    let bars: Vec<NaiveDateTime> = calendar.generate_intraday_range(monday.and_hms(10, 50, 0), monday.and_hms(13, 35, 0), Duration::minutes(30), Label::Left, Closed::Both).collect();
+    assert_eq!(bars, vec![monday.and_hms(11, 0, 0), monday.and_hms(12, 35, 0), monday.and_hms(13, 5, 0), monday.and_hms(13, 35, 0)]);
+    let bars: Vec<NaiveDateTime> = calendar.generate_intraday_range(monday.and_hms(11, 0, 0), monday.and_hms(13, 35, 0), Duration::minutes(30), Label::Left, Closed::Not).collect();
+    assert_eq!(bars, vec![monday.and_hms(12, 35, 0), monday.and_hms(13, 5, 0)]);
+
+    // 右ラベルでは営業時間の最後の足は営業時間の終了
+    let bars: Vec<NaiveDateTime> = calendar.generate_intraday_range(monday.and_hms(11, 0, 0), monday.and_hms(15, 0, 0), Duration::minutes(40), Label::Right, Closed::Both).collect();
+    assert_eq!(bars, vec![
+        monday.and_hms(11, 0, 0), monday.and_hms(11, 30, 0),
+        monday.and_hms(13, 15, 0), monday.and_hms(13, 55, 0), monday.and_hms(14, 35, 0), monday.and_hms(15, 0, 0)
+    ]);
+    let bars: Vec<NaiveDateTime> = calendar.generate_intraday_range(monday.and_hms(11, 30, 0), monday.and_hms(13, 15, 0), Duration::minutes(40), Label::Right, Closed::Left).collect();
+    assert_eq!(bars, vec![monday.and_hms(11, 30, 0)]);
+
+    // 間隔が正でない場合・範囲が逆の場合は空
+    assert_eq!(calendar.generate_intraday_range(monday.and_hms(9, 0, 0), monday.and_hms(15, 0, 0), Duration::zero(), Label::Left, Closed::Both).count(), 0);
+    assert_eq!(calendar.generate_intraday_range(monday.and_hms(15, 0, 0), monday.and_hms(9, 0, 0), Duration::minutes(1), Label::Left, Closed::Both).count(), 0);
+}
+
+#[test]
+fn intraday_range_overnight_and_lazy() {
+    let calendar = WorkCalendar::new(vec![NaiveDate::from_ymd(2021, 1, 11)], weekend(), vec![border((8, 45), (15, 15)), border((16, 30), (6, 0))]);
+    let friday = NaiveDate::from_ymd(2021, 1, 8);
+    let saturday = NaiveDate::from_ymd(2021, 1, 9);
+
+    let bars: Vec<NaiveDateTime> = calendar.generate_intraday_range(saturday.and_hms(4, 0, 0), NaiveDate::from_ymd(2021, 1, 12).and_hms(10, 0, 0), Duration::hours(1), Label::Left, Closed::Left).collect();
+    assert_eq!(bars, vec![saturday.and_hms(4, 30, 0), saturday.and_hms(5, 30, 0), NaiveDate::from_ymd(2021, 1, 12).and_hms(8, 45, 0), NaiveDate::from_ymd(2021, 1, 12).and_hms(9, 45, 0)]);
+
+    // 終了日時が遠くても必要な分だけ生成する
+    let mut bars = calendar.generate_intraday_range(friday.and_hms(7, 0, 0), NaiveDate::from_ymd(2200, 1, 1).and_hms(0, 0, 0), Duration::seconds(1), Label::Left, Closed::Left);
+    assert_eq!(bars.next(), Some(friday.and_hms(8, 45, 0)));
+    assert_eq!(bars.nth(6 * 3600 + 30 * 60 - 1), Some(friday.and_hms(16, 30, 0)));
+}
@@ -0,0 +1,165 @@
+use chrono::{NaiveDate, NaiveDateTime, Duration, Weekday};
+
+extern crate rs_workdays;
+
+use rs_workdays::{WorkCalendar, BorderKind};
+
+mod common;
+use common::{border, weekend};
+
+#[test]
+fn range_intraday_borders() {
+    // 2024年11月5日から東証の後場の終了が15時30分に延長
+    let mut calendar = WorkCalendar::default();
+    calendar.add_range_intraday_borders(NaiveDate::from_ymd(2024, 11, 5), NaiveDate::MAX, &[border((9, 0), (11, 30)), border((12, 30), (15, 30))]);
+
+    assert!(!calendar.check_workday_intraday(NaiveDate::from_ymd(2024, 11, 1).and_hms(15, 15, 0)));
+    assert!(calendar.check_workday_intraday(NaiveDate::from_ymd(2024, 11, 5).and_hms(15, 15, 0)));
+    assert_eq!(calendar.intraday_borders_on(NaiveDate::from_ymd(2024, 11, 1)), calendar.intraday_borders());
+
+    assert_eq!(
+        calendar.get_next_border(NaiveDate::from_ymd(2024, 11, 5).and_hms(14, 0, 0)),
+        (NaiveDate::from_ymd(2024, 11, 5).and_hms(15, 30, 0), BorderKind::End(1))
+    );
+    assert_eq!(
+        calendar.get_previous_border(NaiveDate::from_ymd(2024, 11, 6).and_hms(8, 0, 0), false),
+        (NaiveDate::from_ymd(2024, 11, 5).and_hms(15, 30, 0), BorderKind::End(1))
+    );
+    assert_eq!(
+        calendar.add_workday_intraday_datetime(NaiveDate::from_ymd(2024, 11, 5).and_hms(15, 0, 0), Duration::hours(1)),
+        NaiveDate::from_ymd(2024, 11, 6).and_hms(9, 30, 0)
+    );
+    assert_eq!(
+        calendar.add_workday_intraday_datetime(NaiveDate::from_ymd(2024, 11, 6).and_hms(9, 30, 0), Duration::hours(-1)),
+        NaiveDate::from_ymd(2024, 11, 5).and_hms(15, 0, 0)
+    );
+    // 11月1日(5時間)・11月4日(5時間)・11月5日(5時間30分)
+    assert_eq!(
+        calendar.get_timedelta_workdays_intraday(NaiveDate::from_ymd(2024, 11, 1).and_hms(9, 0, 0), NaiveDate::from_ymd(2024, 11, 6).and_hms(9, 0, 0)),
+        Duration::minutes(15 * 60 + 30)
+    );
+}
+
+#[test]
+fn weekday_intraday_borders() {
+    // 金曜日のみ短縮取引
+    let mut calendar = WorkCalendar::new(vec![], weekend(), vec![border((9, 0), (17, 0))]);
+    calendar.set_weekday_intraday_borders(Weekday::Fri, &[border((9, 0), (12, 0))]);
+
+    let thursday = NaiveDate::from_ymd(2021, 1, 7);
+    let friday = NaiveDate::from_ymd(2021, 1, 8);
+    let monday = NaiveDate::from_ymd(2021, 1, 11);
+
+    assert!(calendar.check_workday_intraday(thursday.and_hms(13, 0, 0)));
+    assert!(!calendar.check_workday_intraday(friday.and_hms(13, 0, 0)));
+    assert_eq!(calendar.get_next_border(friday.and_hms(11, 0, 0)), (friday.and_hms(12, 0, 0), BorderKind::End(0)));
+    assert_eq!(calendar.get_previous_border(monday.and_hms(8, 0, 0), false), (friday.and_hms(12, 0, 0), BorderKind::End(0)));
+
+    assert_eq!(calendar.add_workday_intraday_datetime(thursday.and_hms(16, 0, 0), Duration::hours(2)), friday.and_hms(10, 0, 0));
+    assert_eq!(calendar.add_workday_intraday_datetime(thursday.and_hms(16, 0, 0), Duration::hours(4)), monday.and_hms(9, 0, 0));
+    assert_eq!(calendar.add_workday_intraday_datetime(thursday.and_hms(16, 0, 0), Duration::hours(5)), monday.and_hms(10, 0, 0));
+    assert_eq!(calendar.add_workday_intraday_datetime(monday.and_hms(10, 0, 0), Duration::hours(-5)), thursday.and_hms(16, 0, 0));
+    assert_eq!(calendar.get_timedelta_workdays_intraday(thursday.and_hms(16, 0, 0), monday.and_hms(10, 0, 0)), Duration::hours(5));
+    assert_eq!(calendar.get_timedelta_workdays_intraday(thursday.and_hms(16, 0, 0), NaiveDate::from_ymd(2021, 1, 19).and_hms(10, 0, 0)), Duration::hours(1 + 3 + 8 * 4 + 3 + 8 + 1));
+
+    // 標準の営業時間が空でも曜日の営業時間があれば利用できる
+    let mut monday_only_calendar = WorkCalendar::new(vec![], weekend(), vec![]);
+    monday_only_calendar.set_weekday_intraday_borders(Weekday::Mon, &[border((9, 0), (10, 0))]);
+    assert_eq!(monday_only_calendar.get_next_border(thursday.and_hms(9, 0, 0)), (monday.and_hms(9, 0, 0), BorderKind::Start(0)));
+    assert_eq!(monday_only_calendar.add_workday_intraday_datetime(monday.and_hms(9, 30, 0), Duration::hours(1)), NaiveDate::from_ymd(2021, 1, 18).and_hms(9, 30, 0));
+}
+
+#[test]
+fn range_weekday_intraday_borders() {
+    // 金曜日は短縮取引で，2024年11月5日から後場の終了を15時30分に延長し，金曜日の前場の終了も12時に変更
+    let mut calendar = WorkCalendar::default();
+    calendar.set_weekday_intraday_borders(Weekday::Fri, &[border((9, 0), (11, 30))]);
+    calendar.add_range_intraday_borders(NaiveDate::from_ymd(2024, 11, 5), NaiveDate::MAX, &[border((9, 0), (11, 30)), border((12, 30), (15, 30))]);
+    calendar.add_range_weekday_intraday_borders(NaiveDate::from_ymd(2024, 11, 5), NaiveDate::MAX, Weekday::Fri, &[border((9, 0), (12, 0))]);
+
+    let old_friday = NaiveDate::from_ymd(2024, 11, 1);
+    let new_thursday = NaiveDate::from_ymd(2024, 11, 7);
+    let new_friday = NaiveDate::from_ymd(2024, 11, 8);
+    assert_eq!(calendar.intraday_borders_on(old_friday), &[border((9, 0), (11, 30))]);
+    assert_eq!(calendar.intraday_borders_on(NaiveDate::from_ymd(2024, 10, 31)), calendar.intraday_borders());
+    assert_eq!(calendar.intraday_borders_on(new_thursday), &[border((9, 0), (11, 30)), border((12, 30), (15, 30))]);
+    assert_eq!(calendar.intraday_borders_on(new_friday), &[border((9, 0), (12, 0))]);
+
+    assert!(!calendar.check_workday_intraday(old_friday.and_hms(11, 45, 0)));
+    assert!(calendar.check_workday_intraday(new_friday.and_hms(11, 45, 0)));
+    assert!(!calendar.check_workday_intraday(new_friday.and_hms(13, 0, 0)));
+    assert_eq!(calendar.get_next_border(new_thursday.and_hms(15, 40, 0)), (new_friday.and_hms(9, 0, 0), BorderKind::Start(0)));
+    assert_eq!(calendar.get_next_border(new_friday.and_hms(11, 40, 0)), (new_friday.and_hms(12, 0, 0), BorderKind::End(0)));
+    // 11月7日(5時間30分)・11月8日(3時間)
+    assert_eq!(
+        calendar.get_timedelta_workdays_intraday(new_thursday.and_hms(9, 0, 0), NaiveDate::from_ymd(2024, 11, 11).and_hms(9, 0, 0)),
+        Duration::minutes(8 * 60 + 30)
+    );
+
+    // 後から追加した曜日を指定しない適用期間の指定より曜日を指定した適用期間の指定を優先する
+    calendar.add_range_intraday_borders(NaiveDate::from_ymd(2025, 1, 1), NaiveDate::MAX, &[border((9, 0), (15, 30))]);
+    assert_eq!(calendar.intraday_borders_on(NaiveDate::from_ymd(2025, 1, 10)), &[border((9, 0), (12, 0))]);
+    assert_eq!(calendar.intraday_borders_on(NaiveDate::from_ymd(2025, 1, 9)), &[border((9, 0), (15, 30))]);
+}
+
+#[test]
+fn date_intraday_borders() {
+    // 日付の指定は曜日・適用期間の指定より優先する
+    let mut calendar = WorkCalendar::default();
+    let last_day = NaiveDate::from_ymd(2020, 12, 30);
+    calendar.add_range_intraday_borders(NaiveDate::from_ymd(2020, 12, 1), NaiveDate::from_ymd(2020, 12, 31), &[border((9, 0), (16, 0))]);
+    calendar.set_weekday_intraday_borders(Weekday::Wed, &[border((9, 0), (15, 0))]);
+    calendar.set_date_intraday_borders(last_day, &[border((9, 0), (11, 30))]);
+
+    assert_eq!(calendar.intraday_borders_on(NaiveDate::from_ymd(2020, 12, 29)), &[border((9, 0), (16, 0))]);
+    assert_eq!(calendar.intraday_borders_on(NaiveDate::from_ymd(2020, 12, 23)), &[border((9, 0), (15, 0))]);
+    assert_eq!(calendar.intraday_borders_on(last_day), &[border((9, 0), (11, 30))]);
+    assert_eq!(calendar.get_next_border(last_day.and_hms(11, 40, 0)), (NaiveDate::from_ymd(2020, 12, 31).and_hms(9, 0, 0), BorderKind::Start(0)));
+
+    // 営業時間の無い営業日は飛ばす
+    calendar.set_date_intraday_borders(NaiveDate::from_ymd(2020, 12, 31), &[]);
+    assert_eq!(calendar.get_next_border(last_day.and_hms(11, 40, 0)), (NaiveDate::from_ymd(2021, 1, 4).and_hms(9, 0, 0), BorderKind::Start(0)));
+    assert_eq!(calendar.get_previous_border(NaiveDate::from_ymd(2021, 1, 4).and_hms(8, 0, 0), false), (last_day.and_hms(11, 30, 0), BorderKind::End(0)));
+    assert!(!calendar.check_workday_intraday(NaiveDate::from_ymd(2020, 12, 31).and_hms(10, 0, 0)));
+    assert_eq!(
+        calendar.add_workday_intraday_datetime(last_day.and_hms(11, 0, 0), Duration::hours(1)),
+        NaiveDate::from_ymd(2021, 1, 4).and_hms(9, 30, 0)
+    );
+    assert_eq!(
+        calendar.get_timedelta_workdays_intraday(last_day.and_hms(11, 0, 0), NaiveDate::from_ymd(2021, 1, 4).and_hms(9, 30, 0)),
+        Duration::hours(1)
+    );
+
+    calendar.clear_intraday_schedule();
+    assert_eq!(calendar.intraday_borders_on(last_day), calendar.intraday_borders());
+}
+
+#[test]
+fn extract_with_intraday_schedule() {
+    // 抽出の結果がcheck_workday_intradayと一致する
+    let mut calendar = WorkCalendar::default();
+    calendar.set_weekday_intraday_borders(Weekday::Fri, &[border((9, 0), (11, 30))]);
+    calendar.set_date_intraday_borders(NaiveDate::from_ymd(2021, 1, 12), &[]);
+    calendar.add_range_intraday_borders(NaiveDate::from_ymd(2021, 1, 13), NaiveDate::from_ymd(2021, 1, 14), &[border((8, 0), (10, 0)), border((11, 0), (12, 0)), border((13, 0), (15, 30))]);
+
+    let start_datetime = NaiveDate::from_ymd(2021, 1, 1).and_hms(0, 0, 0);
+    // 日によって終わりの時刻が異なるよう7分間隔とする
+    let datetime_vec: Vec<NaiveDateTime> = (0..(20 * 24 * 60 / 7)).map(|i|{start_datetime + Duration::minutes(i * 7)}).collect();
+
+    let expected: Vec<bool> = datetime_vec.iter().map(|x|{calendar.check_workday_intraday(*x)}).collect();
+    assert_eq!(calendar.extract_workdays_intraday_bool(&datetime_vec), expected);
+
+    let expected: Vec<bool> = datetime_vec.iter().map(|x|{
+        calendar.intraday_borders_on(x.date()).iter().any(|border|{(border.start <= x.time()) & (x.time() < border.end)})
+    }).collect();
+    assert_eq!(calendar.extract_intraday_bool(&datetime_vec), expected);
+
+    // 一日の途中で終わる場合も次の日の判定に影響しない
+    let sparse_datetime_vec = vec![
+        NaiveDate::from_ymd(2021, 1, 13).and_hms(14, 0, 0),
+        NaiveDate::from_ymd(2021, 1, 14).and_hms(8, 30, 0),
+        NaiveDate::from_ymd(2021, 1, 18).and_hms(13, 0, 0),
+        NaiveDate::from_ymd(2021, 1, 19).and_hms(9, 30, 0),
+    ];
+    assert_eq!(calendar.extract_workdays_intraday_bool(&sparse_datetime_vec), vec![true, true, true, true]);
+}
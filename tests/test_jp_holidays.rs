@@ -0,0 +1,33 @@
+use std::fs::read_to_string;
+use chrono::NaiveDate;
+
+extern crate rs_workdays;
+
+use rs_workdays::jp_holidays::*;
+
+#[test]
+fn match_holidays_csv() {
+    // 1955年から2022年までのsource/holidays.csvと一致する
+    let csv_holidays: Vec<(NaiveDate, String)> = read_to_string("source/holidays.csv").unwrap().lines()
+    .map(|line|{
+        let mut record = line.split(',');
+        let date = NaiveDate::parse_from_str(record.next().unwrap(), "%Y-%m-%d").unwrap();
+        let name = record.next().unwrap().to_string();
+        (date, name)
+    }).collect();
+
+    let rule_holidays: Vec<(NaiveDate, String)> = get_jp_holidays_with_names(1955, 2022).into_iter()
+    .map(|(date, name)|{(date, name.to_string())}).collect();
+
+    assert_eq!(rule_holidays, csv_holidays);
+}
+
+#[test]
+fn future_holidays() {
+    let holidays = get_jp_holidays(2026, 2026);
+    // 国民の休日(敬老の日と秋分の日の間)
+    assert!(holidays.contains(&NaiveDate::from_ymd(2026, 9, 22)));
+    // 振替休日(5月3日が日曜日)
+    assert!(holidays.contains(&NaiveDate::from_ymd(2026, 5, 6)));
+    assert_eq!(holidays.len(), 18);
+}
@@ -0,0 +1,55 @@
+use chrono::{NaiveDate, NaiveDateTime, Duration};
+
+extern crate rs_workdays;
+
+use rs_workdays::{WorkCalendar, SessionAttribution};
+use rs_workdays::extract::IntradayLabel;
+
+mod common;
+use common::{border, weekend};
+
+#[test]
+fn label_matches_extract() {
+    // ラベルのある日時は抽出するものと一致する
+    let calendar = WorkCalendar::default();
+    let start_datetime = NaiveDate::from_ymd(2020, 12, 28).and_hms(0, 0, 0);
+    let datetime_vec: Vec<NaiveDateTime> = (0..(20 * 24 * 12)).map(|i|{start_datetime + Duration::minutes(i * 5)}).collect();
+    let bool_vec = calendar.extract_workdays_intraday_bool(&datetime_vec);
+    let label_vec = calendar.label_workdays_intraday(&datetime_vec);
+    assert_eq!(label_vec.iter().map(|x|{x.is_some()}).collect::<Vec<bool>>(), bool_vec);
+
+    // 開始からの秒数と終了までの秒数の和は営業時間の長さ(前場・後場とも2時間30分)
+    for (datetime, label) in datetime_vec.iter().zip(label_vec.iter()) {
+        if let Some(label) = label {
+            assert_eq!(label.trading_date, datetime.date());
+            assert!(label.session_index < 2);
+            assert_eq!(label.seconds_since_open + label.seconds_until_close, 150 * 60);
+        }
+    }
+}
+
+#[test]
+fn label_overnight_trading_date() {
+    let mut calendar = WorkCalendar::new(vec![NaiveDate::from_ymd(2021, 1, 11)], weekend(), vec![border((8, 45), (15, 15)), border((16, 30), (6, 0))]);
+    let friday = NaiveDate::from_ymd(2021, 1, 8);
+    let saturday = NaiveDate::from_ymd(2021, 1, 9);
+    let datetime_vec = vec![friday.and_hms(8, 45, 0), friday.and_hms(15, 15, 0), friday.and_hms(23, 0, 0), saturday.and_hms(5, 59, 30), saturday.and_hms(9, 0, 0)];
+
+    let night_label = |trading_date|{
+        Some(IntradayLabel {trading_date, session_index: 1, seconds_since_open: 6 * 3600 + 30 * 60, seconds_until_close: 7 * 3600})
+    };
+    let label_vec = calendar.label_workdays_intraday(&datetime_vec);
+    assert_eq!(label_vec, vec![
+        Some(IntradayLabel {trading_date: friday, session_index: 0, seconds_since_open: 0, seconds_until_close: 6 * 3600 + 30 * 60}),
+        None,
+        night_label(friday),
+        Some(IntradayLabel {trading_date: friday, session_index: 1, seconds_since_open: 13 * 3600 + 29 * 60 + 30, seconds_until_close: 30}),
+        None
+    ]);
+
+    // 夜間取引は祝日を越えた次の営業日に属する
+    calendar.set_session_attribution(SessionAttribution::NextWorkday);
+    let label_vec = calendar.label_workdays_intraday(&datetime_vec);
+    assert_eq!(label_vec[2], night_label(NaiveDate::from_ymd(2021, 1, 12)));
+    assert_eq!(label_vec[3].map(|x|{x.trading_date}), Some(NaiveDate::from_ymd(2021, 1, 12)));
+}
@@ -0,0 +1,107 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Duration};
+
+extern crate rs_workdays;
+
+use rs_workdays::{WorkCalendar, BorderKind, SessionAttribution};
+
+mod common;
+use common::{border, weekend};
+
+/// 日中取引8:45～15:15，夜間取引16:30～翌6:00(2021年1月11日は祝日)
+fn night_session_calendar() -> WorkCalendar {
+    WorkCalendar::new(vec![NaiveDate::from_ymd(2021, 1, 11)], weekend(), vec![border((8, 45), (15, 15)), border((16, 30), (6, 0))])
+}
+
+#[test]
+fn night_session_borders() {
+    let calendar = night_session_calendar();
+    let friday = NaiveDate::from_ymd(2021, 1, 8);
+    let saturday = NaiveDate::from_ymd(2021, 1, 9);
+    let tuesday = NaiveDate::from_ymd(2021, 1, 12);
+
+    assert!(border((16, 30), (6, 0)).is_overnight());
+    assert_eq!(border((16, 30), (6, 0)).duration(), Duration::minutes(13 * 60 + 30));
+
+    assert!(calendar.check_workday_intraday(saturday.and_hms(3, 0, 0)));
+    assert!(!calendar.check_workday_intraday(saturday.and_hms(7, 0, 0)));
+    assert!(!calendar.check_workday_intraday(tuesday.and_hms(3, 0, 0)));  // 祝日の夜間取引は無い
+
+    assert_eq!(calendar.get_next_border(friday.and_hms(15, 30, 0)), (friday.and_hms(16, 30, 0), BorderKind::Start(1)));
+    assert_eq!(calendar.get_next_border(saturday.and_hms(3, 0, 0)), (saturday.and_hms(6, 0, 0), BorderKind::End(1)));
+    assert_eq!(calendar.get_next_border(saturday.and_hms(7, 0, 0)), (tuesday.and_hms(8, 45, 0), BorderKind::Start(0)));
+
+    assert_eq!(calendar.get_previous_border(tuesday.and_hms(8, 0, 0), false), (saturday.and_hms(6, 0, 0), BorderKind::End(1)));
+    assert_eq!(calendar.get_previous_border(saturday.and_hms(3, 0, 0), false), (friday.and_hms(16, 30, 0), BorderKind::Start(1)));
+    assert_eq!(calendar.get_previous_border(saturday.and_hms(6, 0, 0), true), (friday.and_hms(16, 30, 0), BorderKind::Start(1)));
+}
+
+#[test]
+fn night_session_add_and_timedelta() {
+    let calendar = night_session_calendar();
+    let friday = NaiveDate::from_ymd(2021, 1, 8);
+    let saturday = NaiveDate::from_ymd(2021, 1, 9);
+    let tuesday = NaiveDate::from_ymd(2021, 1, 12);
+
+    assert_eq!(calendar.add_workday_intraday_datetime(friday.and_hms(15, 0, 0), Duration::hours(1)), friday.and_hms(17, 15, 0));
+    assert_eq!(calendar.add_workday_intraday_datetime(saturday.and_hms(5, 0, 0), Duration::hours(2)), tuesday.and_hms(9, 45, 0));
+    assert_eq!(calendar.add_workday_intraday_datetime(friday.and_hms(16, 30, 0), Duration::minutes(13 * 60 + 30)), tuesday.and_hms(8, 45, 0));
+    assert_eq!(calendar.add_workday_intraday_datetime(tuesday.and_hms(9, 45, 0), Duration::hours(-2)), saturday.and_hms(5, 0, 0));
+
+    assert_eq!(calendar.get_timedelta_workdays_intraday(friday.and_hms(15, 0, 0), tuesday.and_hms(9, 45, 0)), Duration::minutes(14 * 60 + 45));
+    assert_eq!(calendar.get_timedelta_workdays_intraday(tuesday.and_hms(9, 45, 0), friday.and_hms(15, 0, 0)), - Duration::minutes(14 * 60 + 45));
+    assert_eq!(calendar.get_timedelta_workdays_intraday(saturday.and_hms(5, 0, 0), saturday.and_hms(5, 30, 0)), Duration::minutes(30));
+
+    // 営業時間内の日時は開始日時からの営業時間で復元できる
+    let start_datetime = NaiveDate::from_ymd(2021, 1, 4).and_hms(8, 45, 0);
+    let datetime_vec: Vec<NaiveDateTime> = (0..(20 * 24 * 4)).map(|i|{start_datetime + Duration::minutes(i * 15)}).collect();
+    for datetime in datetime_vec.iter().filter(|x|{calendar.check_workday_intraday(**x)}) {
+        let delta_time = calendar.get_timedelta_workdays_intraday(start_datetime, *datetime);
+        assert_eq!(calendar.add_workday_intraday_datetime(start_datetime, delta_time), *datetime);
+    }
+}
+
+#[test]
+fn night_session_extract_and_trading_date() {
+    let mut calendar = night_session_calendar();
+    let friday = NaiveDate::from_ymd(2021, 1, 8);
+    let saturday = NaiveDate::from_ymd(2021, 1, 9);
+
+    let start_datetime = NaiveDate::from_ymd(2021, 1, 1).and_hms(0, 0, 0);
+    let datetime_vec: Vec<NaiveDateTime> = (0..(20 * 24 * 6)).map(|i|{start_datetime + Duration::minutes(i * 10)}).collect();
+    let expected: Vec<bool> = datetime_vec.iter().map(|x|{calendar.check_workday_intraday(*x)}).collect();
+    assert_eq!(calendar.extract_workdays_intraday_bool(&datetime_vec), expected);
+
+    // 営業日を考慮しない場合は時刻のみで判定する
+    let expected: Vec<bool> = datetime_vec.iter().map(|x|{
+        let time = x.time();
+        (border((8, 45), (15, 15)).start <= time) & (time < border((8, 45), (15, 15)).end)
+        | (time >= NaiveTime::from_hms(16, 30, 0)) | (time < NaiveTime::from_hms(6, 0, 0))
+    }).collect();
+    assert_eq!(calendar.extract_intraday_bool(&datetime_vec), expected);
+
+    // 取引日
+    assert_eq!(calendar.session_attribution(), SessionAttribution::StartDate);
+    assert_eq!(calendar.get_trading_date(saturday.and_hms(3, 0, 0)), Some(friday));
+    calendar.set_session_attribution(SessionAttribution::NextWorkday);
+    assert_eq!(calendar.get_trading_date(saturday.and_hms(3, 0, 0)), Some(NaiveDate::from_ymd(2021, 1, 12)));
+    assert_eq!(calendar.get_trading_date(friday.and_hms(10, 0, 0)), Some(friday));
+    assert_eq!(calendar.get_trading_date(saturday.and_hms(7, 0, 0)), None);
+}
+
+#[test]
+fn twenty_four_hour_session() {
+    // 平日7時から翌日7時までの24時間取引(土曜日7時に週末の休止)
+    let calendar = WorkCalendar::new(vec![], weekend(), vec![border((7, 0), (7, 0))]);
+    let monday = NaiveDate::from_ymd(2021, 1, 4);
+    let friday = NaiveDate::from_ymd(2021, 1, 8);
+    let saturday = NaiveDate::from_ymd(2021, 1, 9);
+
+    assert!(calendar.check_workday_intraday(saturday.and_hms(6, 0, 0)));
+    assert!(!calendar.check_workday_intraday(saturday.and_hms(8, 0, 0)));
+    assert!(!calendar.check_workday_intraday(monday.and_hms(6, 0, 0)));
+    assert!(calendar.check_workday_intraday(monday.and_hms(7, 0, 0)));
+
+    assert_eq!(calendar.get_next_border(NaiveDate::from_ymd(2021, 1, 5).and_hms(7, 0, 0)), (NaiveDate::from_ymd(2021, 1, 6).and_hms(7, 0, 0), BorderKind::End(0)));
+    assert_eq!(calendar.add_workday_intraday_datetime(friday.and_hms(7, 0, 0), Duration::hours(24)), NaiveDate::from_ymd(2021, 1, 11).and_hms(7, 0, 0));
+    assert_eq!(calendar.get_timedelta_workdays_intraday(monday.and_hms(7, 0, 0), NaiveDate::from_ymd(2021, 1, 11).and_hms(7, 0, 0)), Duration::hours(5 * 24));
+}
@@ -0,0 +1,98 @@
+use std::collections::HashSet;
+use chrono::{NaiveDate, NaiveTime, Weekday};
+
+extern crate rs_workdays;
+
+use rs_workdays::{WorkCalendar, TimeBorder, HolidayKind, Error};
+use rs_workdays::{calendar, register_calendar, remove_calendar, calendar_names};
+#[cfg(feature="config")]
+use rs_workdays::{use_calendar, get_work_calendar, load_calendars_config};
+
+#[test]
+fn builtin_calendars() {
+    let jp_calendar = calendar("JP").unwrap();
+    let tse_calendar = calendar("TSE").unwrap();
+    assert_eq!(jp_calendar, WorkCalendar::jp());
+    assert_eq!(tse_calendar, WorkCalendar::tse());
+
+    // 年末年始の休業日はTSEのみ
+    for one_date in [NaiveDate::from_ymd(2020, 12, 31), NaiveDate::from_ymd(2021, 12, 31), NaiveDate::from_ymd(2019, 1, 2), NaiveDate::from_ymd(2019, 1, 3)].iter() {
+        assert!(jp_calendar.check_workday(*one_date));
+        assert!(!tse_calendar.check_workday(*one_date));
+        assert_eq!(tse_calendar.get_holiday_info(*one_date).unwrap().kind, HolidayKind::Other);
+    }
+    assert_eq!(tse_calendar.get_next_workday(NaiveDate::from_ymd(2019, 12, 30), 1), NaiveDate::from_ymd(2020, 1, 6));
+    assert_eq!(jp_calendar.get_holiday_name(NaiveDate::from_ymd(2021, 1, 1)), tse_calendar.get_holiday_name(NaiveDate::from_ymd(2021, 1, 1)));
+    assert_eq!(jp_calendar.coverage(), tse_calendar.coverage());
+
+    assert!(matches!(calendar("NYSE"), Err(Error::UnknownCalendar{..})));
+}
+
+#[test]
+fn register_and_remove_calendar() {
+    let holiday_weekdays: HashSet<Weekday> = [Weekday::Sun].iter().cloned().collect();
+    let intraday_borders = vec![TimeBorder {start: NaiveTime::from_hms(10, 0, 0), end: NaiveTime::from_hms(18, 0, 0)}];
+    let shop_calendar = WorkCalendar::new(vec![NaiveDate::from_ymd(2021, 1, 4)], holiday_weekdays, intraday_borders);
+    register_calendar("shop", shop_calendar.clone());
+    assert_eq!(calendar("shop").unwrap(), shop_calendar);
+    assert!(calendar_names().contains(&"shop".to_string()));
+
+    assert_eq!(remove_calendar("shop"), Some(shop_calendar));
+    assert!(calendar("shop").is_err());
+    assert_eq!(remove_calendar("shop"), None);
+}
+
+#[cfg(feature="config")]
+#[test]
+fn load_config_and_use_default() {
+    load_calendars_config(r#"
+default = "ACME-corp"
+
+[calendars.ACME-corp]
+base = "ACME-base"
+holidays = ["2021-08-16", 2021-08-17]
+intraday_borders = [["09:00", "12:00"], ["13:00", "17:30:00"]]
+
+[calendars.ACME-base]
+base = "TSE"
+holiday_weekdays = ["Sat", "Sun", "Wed"]
+"#).unwrap();
+
+    let acme_calendar = calendar("ACME-corp").unwrap();
+    assert!(!acme_calendar.check_workday(NaiveDate::from_ymd(2021, 8, 16)));
+    assert!(!acme_calendar.check_workday(NaiveDate::from_ymd(2021, 8, 17)));
+    assert!(!acme_calendar.check_workday(NaiveDate::from_ymd(2021, 8, 18)));  // 水曜日
+    assert!(!acme_calendar.check_workday(NaiveDate::from_ymd(2020, 12, 31)));  // TSEの休業日
+    assert!(acme_calendar.check_workday_intraday(NaiveDate::from_ymd(2021, 8, 19).and_hms(17, 0, 0)));
+    assert!(!calendar("ACME-base").unwrap().check_workday(NaiveDate::from_ymd(2021, 8, 25)));
+    assert_eq!(get_work_calendar(), acme_calendar);
+
+    use_calendar("TSE").unwrap();
+    assert_eq!(get_work_calendar(), WorkCalendar::tse());
+    assert!(use_calendar("NYSE").is_err());
+    assert_eq!(get_work_calendar(), WorkCalendar::tse());
+}
+
+#[cfg(feature="config")]
+#[test]
+fn invalid_config_registers_nothing() {
+    let invalid_configs = [
+        "[calendars.bad-1]\nholiday_weekdays = [\"Holiday\"]",
+        "[calendars.bad-2]\nintraday_borders = [[\"09:00\"]]",
+        "[calendars.bad-3]\nbase = \"bad-4\"\n[calendars.bad-4]\nbase = \"bad-3\"",
+        "[calendars.bad-5]\nbase = \"NYSE\"",
+        "[calendars.bad-6\n"
+    ];
+    for config_str in invalid_configs.iter() {
+        assert!(load_calendars_config(config_str).is_err());
+    }
+    assert!(matches!(load_calendars_config("[calendars.bad-7]\nholidays = [\"2021-13-01\"]"), Err(Error::ParseDateError{..})));
+    assert!(!calendar_names().iter().any(|x|{x.starts_with("bad-")}));
+
+    // 同じ名前を元にすると登録済みのカレンダーを上書きする
+    register_calendar("JP-company", WorkCalendar::jp());
+    load_calendars_config("[calendars.JP-company]\nbase = \"JP-company\"\nholidays = [\"2021-06-01\"]").unwrap();
+    let company_calendar = calendar("JP-company").unwrap();
+    assert!(!company_calendar.check_workday(NaiveDate::from_ymd(2021, 6, 1)));
+    assert!(!company_calendar.check_workday(NaiveDate::from_ymd(2021, 1, 11)));
+}
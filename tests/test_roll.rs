@@ -0,0 +1,87 @@
+use chrono::NaiveDate;
+
+extern crate rs_workdays;
+
+use rs_workdays::WorkCalendar;
+use rs_workdays::roll::*;
+
+/// 年末年始(12月31日・1月2日・1月3日)を休業日とする東証のカレンダー
+fn tse_calendar() -> WorkCalendar {
+    let mut calendar = WorkCalendar::default();
+    let year_end_holidays = vec![
+        NaiveDate::from_ymd(2019, 12, 31),
+        NaiveDate::from_ymd(2020, 1, 2),
+        NaiveDate::from_ymd(2020, 1, 3),
+        NaiveDate::from_ymd(2020, 12, 31),
+        NaiveDate::from_ymd(2021, 1, 2),
+        NaiveDate::from_ymd(2021, 1, 3)
+    ];
+    calendar.add_range_holidays(&year_end_holidays, 2019, 2021);
+    calendar
+}
+
+#[test]
+fn adjust_workday() {
+    // 営業日はどの規約でも変わらない
+    let calendar = tse_calendar();
+    let select_date = NaiveDate::from_ymd(2021, 4, 30);
+    for convention in [
+        RollConvention::Following,
+        RollConvention::ModifiedFollowing,
+        RollConvention::Preceding,
+        RollConvention::ModifiedPreceding,
+        RollConvention::EndOfMonth,
+        RollConvention::Nearest
+    ].iter() {
+        assert_eq!(calendar.adjust(select_date, *convention), select_date);
+        assert_eq!(calendar.adjust(NaiveDate::from_ymd(2021, 4, 14), *convention), NaiveDate::from_ymd(2021, 4, 14));  // 月の途中
+    }
+}
+
+#[test]
+fn adjust_golden_week() {
+    // 2019年4月27日から5月6日までの10連休
+    let calendar = tse_calendar();
+    let end_of_april = NaiveDate::from_ymd(2019, 4, 30);
+    assert_eq!(calendar.adjust(end_of_april, RollConvention::Following), NaiveDate::from_ymd(2019, 5, 7));
+    assert_eq!(calendar.adjust(end_of_april, RollConvention::ModifiedFollowing), NaiveDate::from_ymd(2019, 4, 26));
+    assert_eq!(calendar.adjust(end_of_april, RollConvention::Preceding), NaiveDate::from_ymd(2019, 4, 26));
+    assert_eq!(calendar.adjust(end_of_april, RollConvention::ModifiedPreceding), NaiveDate::from_ymd(2019, 4, 26));
+    assert_eq!(calendar.adjust(end_of_april, RollConvention::Nearest), NaiveDate::from_ymd(2019, 4, 26));
+
+    let start_of_may = NaiveDate::from_ymd(2019, 5, 1);
+    assert_eq!(calendar.adjust(start_of_may, RollConvention::Following), NaiveDate::from_ymd(2019, 5, 7));
+    assert_eq!(calendar.adjust(start_of_may, RollConvention::ModifiedFollowing), NaiveDate::from_ymd(2019, 5, 7));
+    assert_eq!(calendar.adjust(start_of_may, RollConvention::Preceding), NaiveDate::from_ymd(2019, 4, 26));
+    assert_eq!(calendar.adjust(start_of_may, RollConvention::ModifiedPreceding), NaiveDate::from_ymd(2019, 5, 7));
+    assert_eq!(calendar.adjust(start_of_may, RollConvention::Nearest), NaiveDate::from_ymd(2019, 4, 26));
+    assert_eq!(calendar.adjust(NaiveDate::from_ymd(2019, 5, 2), RollConvention::Nearest), NaiveDate::from_ymd(2019, 5, 7));
+
+    // 月末の日のみ月末の営業日とし，それ以外はModifiedFollowing
+    assert_eq!(calendar.adjust(end_of_april, RollConvention::EndOfMonth), NaiveDate::from_ymd(2019, 4, 26));
+    assert_eq!(calendar.adjust(NaiveDate::from_ymd(2019, 4, 26), RollConvention::EndOfMonth), NaiveDate::from_ymd(2019, 4, 26));
+    assert_eq!(calendar.adjust(NaiveDate::from_ymd(2019, 4, 10), RollConvention::EndOfMonth), NaiveDate::from_ymd(2019, 4, 10));
+    assert_eq!(calendar.adjust(NaiveDate::from_ymd(2019, 4, 28), RollConvention::EndOfMonth), NaiveDate::from_ymd(2019, 4, 26));
+    assert_eq!(calendar.adjust(start_of_may, RollConvention::EndOfMonth), NaiveDate::from_ymd(2019, 5, 7));
+    assert_eq!(calendar.adjust(NaiveDate::from_ymd(2021, 5, 1), RollConvention::EndOfMonth), NaiveDate::from_ymd(2021, 5, 6));
+    assert_eq!(calendar.adjust(NaiveDate::from_ymd(2021, 10, 31), RollConvention::EndOfMonth), NaiveDate::from_ymd(2021, 10, 29));
+}
+
+#[test]
+fn adjust_year_end() {
+    // 2020年12月31日から2021年1月3日までの年末年始
+    let calendar = tse_calendar();
+    let new_years_eve = NaiveDate::from_ymd(2020, 12, 31);
+    assert_eq!(calendar.adjust(new_years_eve, RollConvention::Following), NaiveDate::from_ymd(2021, 1, 4));
+    assert_eq!(calendar.adjust(new_years_eve, RollConvention::ModifiedFollowing), NaiveDate::from_ymd(2020, 12, 30));
+    assert_eq!(calendar.adjust(new_years_eve, RollConvention::EndOfMonth), NaiveDate::from_ymd(2020, 12, 30));
+
+    let new_years_day = NaiveDate::from_ymd(2021, 1, 1);
+    assert_eq!(calendar.adjust(new_years_day, RollConvention::Preceding), NaiveDate::from_ymd(2020, 12, 30));
+    assert_eq!(calendar.adjust(new_years_day, RollConvention::ModifiedPreceding), NaiveDate::from_ymd(2021, 1, 4));
+    assert_eq!(calendar.adjust(new_years_day, RollConvention::Nearest), NaiveDate::from_ymd(2020, 12, 30));
+    assert_eq!(calendar.adjust(NaiveDate::from_ymd(2021, 1, 2), RollConvention::Nearest), NaiveDate::from_ymd(2021, 1, 4));
+
+    // 等距離の場合は次の営業日(2021年1月11日は成人の日)
+    assert_eq!(calendar.adjust(NaiveDate::from_ymd(2021, 1, 10), RollConvention::Nearest), NaiveDate::from_ymd(2021, 1, 12));
+}
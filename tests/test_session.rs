@@ -0,0 +1,96 @@
+use chrono::{NaiveDate, NaiveTime};
+
+extern crate rs_workdays;
+
+use rs_workdays::{WorkCalendar, TimeBorder, Session, SessionKind, Error};
+
+mod common;
+use common::weekend;
+
+fn time(hour: u32, minute: u32) -> NaiveTime {
+    NaiveTime::from_hms(hour, minute, 0)
+}
+
+/// 東京証券取引所(2024年11月5日以降)の取引セッション
+fn tse_sessions() -> Vec<Session> {
+    vec![
+        Session::new("プレオープン", time(8, 0), time(9, 0), SessionKind::PreOpen),
+        Session::new("前場", time(9, 0), time(11, 30), SessionKind::Continuous),
+        Session::new("後場", time(12, 30), time(15, 25), SessionKind::Continuous),
+        Session::new("クロージング・オークション", time(15, 25), time(15, 30), SessionKind::ClosingAuction),
+    ]
+}
+
+#[test]
+fn named_sessions() {
+    let mut calendar = WorkCalendar::new(vec![NaiveDate::from_ymd(2025, 1, 13)], weekend(), vec![
+        TimeBorder {start: time(9, 0), end: time(11, 30)},
+        TimeBorder {start: time(12, 30), end: time(15, 30)}
+    ]);
+    calendar.set_sessions(&tse_sessions()[..]);
+    let friday = NaiveDate::from_ymd(2025, 1, 10);
+    let tuesday = NaiveDate::from_ymd(2025, 1, 14);
+
+    let names: Vec<String> = calendar.sessions_on(friday).into_iter().map(|x|{x.session.name}).collect();
+    assert_eq!(names, vec!["プレオープン", "前場", "後場", "クロージング・オークション"]);
+    assert!(calendar.sessions_on(NaiveDate::from_ymd(2025, 1, 13)).is_empty());  // 祝日
+
+    let dated_session = calendar.current_session(friday.and_hms(15, 27, 0)).unwrap();
+    assert_eq!(dated_session.session.kind, SessionKind::ClosingAuction);
+    assert_eq!((dated_session.start, dated_session.end), (friday.and_hms(15, 25, 0), friday.and_hms(15, 30, 0)));
+    assert_eq!(calendar.current_session(friday.and_hms(8, 30, 0)).unwrap().session.kind, SessionKind::PreOpen);
+    assert_eq!(calendar.current_session(friday.and_hms(12, 0, 0)), None);
+    assert_eq!(calendar.current_session(friday.and_hms(15, 30, 0)), None);
+
+    assert_eq!(calendar.next_session_start(friday.and_hms(10, 0, 0), "後場"), Some(friday.and_hms(12, 30, 0)));
+    assert_eq!(calendar.next_session_start(friday.and_hms(9, 0, 0), "前場"), Some(tuesday.and_hms(9, 0, 0)));
+    assert_eq!(calendar.next_session_start(friday.and_hms(16, 0, 0), "プレオープン"), Some(tuesday.and_hms(8, 0, 0)));
+    assert!(matches!(calendar.try_next_session_start(friday.and_hms(10, 0, 0), "夕場"), Err(Error::UnknownSession{..})));
+}
+
+#[test]
+fn sessions_from_intraday_borders() {
+    // セッションを設定しない場合は営業時間境界から作る(夜間取引を含む)
+    let mut calendar = WorkCalendar::new(vec![], weekend(), vec![
+        TimeBorder {start: time(8, 45), end: time(15, 15)},
+        TimeBorder {start: time(16, 30), end: time(6, 0)}
+    ]);
+    let friday = NaiveDate::from_ymd(2021, 1, 8);
+    let saturday = NaiveDate::from_ymd(2021, 1, 9);
+
+    let dated_session = calendar.current_session(saturday.and_hms(3, 0, 0)).unwrap();
+    assert_eq!((dated_session.session.name.as_str(), dated_session.session.kind), ("session1", SessionKind::Continuous));
+    assert_eq!((dated_session.date, dated_session.end), (friday, saturday.and_hms(6, 0, 0)));
+    assert_eq!(calendar.next_session_start(saturday.and_hms(3, 0, 0), "session1"), Some(NaiveDate::from_ymd(2021, 1, 11).and_hms(16, 30, 0)));
+    assert_eq!(calendar.next_session_start(saturday.and_hms(3, 0, 0), "session2"), None);
+
+    calendar.set_intraday_borders(&[]);
+    assert!(calendar.sessions_on(friday).is_empty());
+    assert_eq!(calendar.current_session(friday.and_hms(10, 0, 0)), None);
+}
+
+#[test]
+fn named_sessions_follow_date_borders() {
+    let mut calendar = WorkCalendar::new(vec![], weekend(), vec![
+        TimeBorder {start: time(9, 0), end: time(11, 30)},
+        TimeBorder {start: time(12, 30), end: time(15, 30)}
+    ]);
+    calendar.set_sessions(&tse_sessions()[..]);
+    let half_day = NaiveDate::from_ymd(2020, 12, 30);
+    calendar.set_date_intraday_borders(half_day, &[TimeBorder {start: time(9, 0), end: time(11, 0)}]);
+
+    // 半日取引の日は後場・クロージング・オークションが無く，前場は営業時間境界で切り詰める
+    let sessions = calendar.sessions_on(half_day);
+    let names: Vec<&str> = sessions.iter().map(|x|{x.session.name.as_str()}).collect();
+    assert_eq!(names, vec!["プレオープン", "前場"]);
+    assert_eq!((sessions[1].start, sessions[1].end), (half_day.and_hms(9, 0, 0), half_day.and_hms(11, 0, 0)));
+    assert_eq!(calendar.current_session(half_day.and_hms(14, 0, 0)), None);
+    assert_eq!(calendar.current_session(half_day.and_hms(11, 15, 0)), None);
+    assert_eq!(calendar.next_session_start(half_day.and_hms(10, 0, 0), "後場"), Some(NaiveDate::from_ymd(2020, 12, 31).and_hms(12, 30, 0)));
+
+    // 営業時間境界が空の日はセッションも無い
+    let closed_day = NaiveDate::from_ymd(2020, 12, 31);
+    calendar.set_date_intraday_borders(closed_day, &[]);
+    assert!(calendar.sessions_on(closed_day).is_empty());
+    assert_eq!(calendar.next_session_start(half_day.and_hms(10, 0, 0), "プレオープン"), Some(NaiveDate::from_ymd(2021, 1, 1).and_hms(8, 0, 0)));
+}
@@ -0,0 +1,170 @@
+#![cfg(feature="tz")]
+use std::collections::HashSet;
+use chrono::{NaiveDate, NaiveDateTime, Duration, TimeZone, Utc};
+use chrono_tz::America::New_York;
+use chrono_tz::Asia::Tokyo;
+use chrono_tz::Europe::London;
+
+extern crate rs_workdays;
+
+use rs_workdays::{WorkCalendar, BorderKind, Error};
+
+mod common;
+use common::{border, weekend};
+
+/// ニューヨーク証券取引所(9:30～16:00)
+fn nyse_calendar() -> WorkCalendar {
+    let mut calendar = WorkCalendar::new(vec![], weekend(), vec![border((9, 30), (16, 0))]);
+    calendar.set_time_zone(New_York);
+    calendar
+}
+
+#[test]
+fn tse_utc_input() {
+    let calendar = WorkCalendar::new(vec![NaiveDate::from_ymd(2021, 1, 11)], weekend(), vec![border((9, 0), (11, 30)), border((12, 30), (15, 0))]);
+    assert_eq!(calendar.time_zone(), Tokyo);
+
+    assert!(calendar.check_workday_intraday_tz(&Utc.ymd(2021, 1, 4).and_hms(0, 0, 0)));
+    assert!(!calendar.check_workday_intraday_tz(&Utc.ymd(2021, 1, 4).and_hms(6, 30, 0)));
+    assert!(!calendar.check_workday_intraday_tz(&Utc.ymd(2021, 1, 11).and_hms(1, 0, 0)));  // 祝日
+    assert!(calendar.check_workday_intraday_tz(&Utc.ymd(2021, 1, 4).and_hms(0, 0, 0).with_timezone(&New_York)));
+
+    assert_eq!(calendar.get_next_border_tz(&Utc.ymd(2021, 1, 8).and_hms(7, 0, 0)), (Tokyo.ymd(2021, 1, 12).and_hms(9, 0, 0), BorderKind::Start(0)));
+    assert_eq!(calendar.get_previous_border_tz(&Utc.ymd(2021, 1, 4).and_hms(3, 0, 0), false), (Tokyo.ymd(2021, 1, 4).and_hms(11, 30, 0), BorderKind::End(0)));
+    assert_eq!(calendar.add_workday_intraday_datetime_tz(&Utc.ymd(2021, 1, 8).and_hms(5, 0, 0), Duration::hours(2)), Tokyo.ymd(2021, 1, 12).and_hms(10, 0, 0));
+    assert_eq!(calendar.get_trading_date_tz(&Utc.ymd(2021, 1, 4).and_hms(0, 0, 0)), Some(NaiveDate::from_ymd(2021, 1, 4)));
+
+    // UTCの日時の抽出は東京の現地時刻での抽出と一致する
+    let start_datetime = Utc.ymd(2021, 1, 1).and_hms(0, 0, 0);
+    let datetime_vec: Vec<_> = (0..(20 * 24 * 6)).map(|i|{start_datetime + Duration::minutes(i * 10)}).collect();
+    let local_datetime_vec: Vec<NaiveDateTime> = datetime_vec.iter().map(|x|{x.naive_utc() + Duration::hours(9)}).collect();
+    assert_eq!(calendar.extract_workdays_intraday_bool_tz(&datetime_vec), calendar.extract_workdays_intraday_bool(&local_datetime_vec));
+    assert_eq!(calendar.extract_intraday_bool_tz(&datetime_vec), calendar.extract_intraday_bool(&local_datetime_vec));
+    assert_eq!(calendar.extract_workdays_bool_tz(&datetime_vec), calendar.extract_workdays_bool(&local_datetime_vec));
+}
+
+#[test]
+fn nyse_daylight_saving_time() {
+    let calendar = nyse_calendar();
+
+    // 2021年3月14日に夏時間が始まり，開始時刻はUTCの14:30から13:30になる
+    assert!(!calendar.check_workday_intraday_tz(&Utc.ymd(2021, 3, 12).and_hms(13, 45, 0)));
+    assert!(calendar.check_workday_intraday_tz(&Utc.ymd(2021, 3, 15).and_hms(13, 45, 0)));
+    let (next_datetime, border_kind) = calendar.get_next_border_tz(&Utc.ymd(2021, 3, 12).and_hms(22, 0, 0));
+    assert_eq!((next_datetime.with_timezone(&Utc), border_kind), (Utc.ymd(2021, 3, 15).and_hms(13, 30, 0), BorderKind::Start(0)));
+
+    // 2021年11月7日に夏時間が終わり，終了時刻はUTCの20:00から21:00になる
+    assert!(!calendar.check_workday_intraday_tz(&Utc.ymd(2021, 11, 5).and_hms(20, 30, 0)));
+    assert!(calendar.check_workday_intraday_tz(&Utc.ymd(2021, 11, 8).and_hms(20, 30, 0)));
+    let (previous_datetime, _) = calendar.get_previous_border_tz(&Utc.ymd(2021, 11, 8).and_hms(12, 0, 0), false);
+    assert_eq!(previous_datetime.with_timezone(&Utc), Utc.ymd(2021, 11, 5).and_hms(20, 0, 0));
+
+    // ロンドンは3月28日に夏時間が始まるため，その間はニューヨークとの時差が4時間になる
+    let mut lse_calendar = WorkCalendar::new(vec![], weekend(), vec![border((8, 0), (16, 30))]);
+    lse_calendar.set_time_zone(London);
+    let select_datetime = New_York.ymd(2021, 3, 22).and_hms(4, 30, 0);
+    assert!(lse_calendar.check_workday_intraday_tz(&select_datetime));
+    assert!(!calendar.check_workday_intraday_tz(&select_datetime));
+    assert_eq!(lse_calendar.get_near_border_tz(&select_datetime, true), (London.ymd(2021, 3, 22).and_hms(8, 30, 0), BorderKind::Intra(0)));
+
+    // 日をまたぐ営業時間
+    let friday = New_York.ymd(2021, 3, 12).and_hms(15, 0, 0);
+    let monday = New_York.ymd(2021, 3, 15).and_hms(10, 0, 0);
+    assert_eq!(calendar.get_timedelta_workdays_intraday_tz(&friday, &monday), Duration::minutes(90));
+    assert_eq!(calendar.get_timedelta_workdays_intraday_tz(&monday, &friday), Duration::minutes(-90));
+    assert_eq!(calendar.add_workday_intraday_datetime_tz(&friday, Duration::minutes(90)), monday);
+    assert_eq!(calendar.add_workday_intraday_datetime_tz(&monday, Duration::minutes(-90)), friday);
+}
+
+#[test]
+fn twenty_four_hour_session_across_dst() {
+    // 毎日17時から翌日17時までの24時間取引(ニューヨーク)
+    let mut calendar = WorkCalendar::new(vec![], HashSet::new(), vec![border((17, 0), (17, 0))]);
+    calendar.set_time_zone(New_York);
+
+    // 夏時間の開始をまたぐ営業時間は23時間，終了をまたぐ営業時間は25時間
+    let spring_start = New_York.ymd(2021, 3, 13).and_hms(17, 0, 0);
+    let spring_end = New_York.ymd(2021, 3, 14).and_hms(17, 0, 0);
+    assert_eq!(calendar.get_timedelta_workdays_intraday_tz(&spring_start, &spring_end), Duration::hours(23));
+    assert_eq!(calendar.get_next_border_tz(&spring_start), (spring_end, BorderKind::End(0)));
+    assert_eq!(calendar.add_workday_intraday_datetime_tz(&spring_start, Duration::hours(23)), spring_end);
+    assert_eq!(calendar.add_workday_intraday_datetime_tz(&spring_start, Duration::hours(24)), New_York.ymd(2021, 3, 14).and_hms(18, 0, 0));
+
+    let autumn_start = New_York.ymd(2021, 11, 6).and_hms(17, 0, 0);
+    let autumn_end = New_York.ymd(2021, 11, 7).and_hms(17, 0, 0);
+    assert_eq!(calendar.get_timedelta_workdays_intraday_tz(&autumn_start, &autumn_end), Duration::hours(25));
+    assert_eq!(calendar.add_workday_intraday_datetime_tz(&autumn_end, Duration::hours(-25)), autumn_start);
+}
+
+#[test]
+fn nonexistent_and_ambiguous_local_time() {
+    let calendar = nyse_calendar();
+
+    // 存在しない時刻は変化前のオフセットで解釈する(2:30→3:30)
+    let gap_datetime = calendar.from_local_datetime(NaiveDate::from_ymd(2021, 3, 14).and_hms(2, 30, 0));
+    assert_eq!(gap_datetime.with_timezone(&Utc), Utc.ymd(2021, 3, 14).and_hms(7, 30, 0));
+    assert_eq!(gap_datetime.naive_local(), NaiveDate::from_ymd(2021, 3, 14).and_hms(3, 30, 0));
+
+    // 重複する時刻は早い方(夏時間)とする
+    let overlap_datetime = calendar.from_local_datetime(NaiveDate::from_ymd(2021, 11, 7).and_hms(1, 30, 0));
+    assert_eq!(overlap_datetime.with_timezone(&Utc), Utc.ymd(2021, 11, 7).and_hms(5, 30, 0));
+
+    // 存在しない時刻から始まる営業時間
+    let mut calendar = WorkCalendar::new(vec![], HashSet::new(), vec![border((2, 30), (4, 0))]);
+    calendar.set_time_zone(New_York);
+    let (start_datetime, _) = calendar.get_next_border_tz(&Utc.ymd(2021, 3, 14).and_hms(0, 0, 0));
+    assert_eq!(start_datetime.with_timezone(&Utc), Utc.ymd(2021, 3, 14).and_hms(7, 30, 0));
+    assert_eq!(calendar.get_timedelta_workdays_intraday_tz(&Utc.ymd(2021, 3, 14).and_hms(0, 0, 0), &Utc.ymd(2021, 3, 15).and_hms(0, 0, 0)), Duration::minutes(30));
+}
+
+#[test]
+fn check_agrees_with_borders_across_dst() {
+    // 存在しない時刻から始まる営業時間(2:30→3:30)
+    let mut gap_calendar = WorkCalendar::new(vec![], HashSet::new(), vec![border((2, 30), (4, 0))]);
+    gap_calendar.set_time_zone(New_York);
+    let before_start = New_York.ymd(2021, 3, 14).and_hms(3, 10, 0);
+    let session_start = New_York.ymd(2021, 3, 14).and_hms(3, 30, 0);
+    assert!(!gap_calendar.check_workday_intraday_tz(&before_start));
+    assert_eq!(gap_calendar.get_next_border_tz(&before_start), (session_start, BorderKind::Start(0)));
+    assert_eq!(gap_calendar.get_near_border_tz(&before_start, true), (session_start, BorderKind::Start(0)));
+    assert_eq!(gap_calendar.get_trading_date_tz(&before_start), None);
+    assert!(gap_calendar.check_workday_intraday_tz(&session_start));
+    assert_eq!(gap_calendar.get_trading_date_tz(&session_start), Some(NaiveDate::from_ymd(2021, 3, 14)));
+
+    // 重複する時刻で終了する営業時間(1:30は夏時間)
+    let mut overlap_calendar = WorkCalendar::new(vec![], HashSet::new(), vec![border((0, 0), (1, 30))]);
+    overlap_calendar.set_time_zone(New_York);
+    let after_end = Utc.ymd(2021, 11, 7).and_hms(6, 10, 0);  // 1:10(標準時間)
+    let session_end = Utc.ymd(2021, 11, 7).and_hms(5, 30, 0);  // 1:30(夏時間)
+    assert!(!overlap_calendar.check_workday_intraday_tz(&after_end));
+    assert_eq!(overlap_calendar.get_previous_border_tz(&after_end, false), (session_end.with_timezone(&New_York), BorderKind::End(0)));
+    assert_eq!(overlap_calendar.get_timedelta_workdays_intraday_tz(&New_York.ymd(2021, 11, 7).and_hms(0, 0, 0), &after_end), Duration::minutes(90));
+    assert!(overlap_calendar.check_workday_intraday_tz(&Utc.ymd(2021, 11, 7).and_hms(5, 10, 0)));
+
+    // 抽出も開始・終了の日時の判定と一致する
+    let start_datetime = Utc.ymd(2021, 3, 13).and_hms(0, 0, 0);
+    let datetime_vec: Vec<_> = (0..(3 * 24 * 6)).map(|i|{start_datetime + Duration::minutes(i * 10)}).collect();
+    let expected_vec: Vec<bool> = datetime_vec.iter().map(|x|{gap_calendar.check_workday_intraday_tz(x)}).collect();
+    assert_eq!(gap_calendar.extract_workdays_intraday_bool_tz(&datetime_vec), expected_vec);
+    assert_eq!(gap_calendar.extract_intraday_bool_tz(&datetime_vec), expected_vec);
+    assert_eq!(expected_vec.iter().filter(|x|{**x}).count(), 9 + 3 + 9);  // 3月14日は30分のみ
+    let start_datetime = Utc.ymd(2021, 11, 6).and_hms(0, 0, 0);
+    let datetime_vec: Vec<_> = (0..(3 * 24 * 6)).map(|i|{start_datetime + Duration::minutes(i * 10)}).collect();
+    let expected_vec: Vec<bool> = datetime_vec.iter().map(|x|{overlap_calendar.check_workday_intraday_tz(x)}).collect();
+    assert_eq!(overlap_calendar.extract_workdays_intraday_bool_tz(&datetime_vec), expected_vec);
+    assert_eq!(expected_vec.iter().filter(|x|{**x}).count(), 3 * 9);
+}
+
+#[test]
+fn try_near_border_tz() {
+    let mut calendar = nyse_calendar();
+    let intra_datetime = New_York.ymd(2021, 3, 15).and_hms(10, 0, 0);
+    let weekend_datetime = Utc.ymd(2021, 3, 13).and_hms(12, 0, 0);
+    assert_eq!(calendar.try_get_near_border_tz(&intra_datetime, true).unwrap(), (intra_datetime, BorderKind::Intra(0)));
+    assert_eq!(calendar.try_get_near_border_tz(&weekend_datetime, true).unwrap(), calendar.get_near_border_tz(&weekend_datetime, true));
+    assert_eq!(calendar.try_get_near_border_tz(&weekend_datetime, false).unwrap(), (New_York.ymd(2021, 3, 12).and_hms(16, 0, 0), BorderKind::End(0)));
+
+    // 取引時間が空の場合はエラーを返す
+    calendar.set_intraday_borders(&[]);
+    assert!(matches!(calendar.try_get_near_border_tz(&weekend_datetime, true), Err(Error::EmptyIntradayBorders)));
+}
@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+use chrono::{NaiveDate, Duration, Weekday};
+
+extern crate rs_workdays;
+
+use rs_workdays::{WorkCalendar, Error};
+use rs_workdays::roll::RollConvention;
+
+fn all_weekdays() -> HashSet<Weekday> {
+    [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun].iter().cloned().collect()
+}
+
+#[test]
+fn try_workdays_no_workday_reachable() {
+    // 全ての曜日が休日曜日の場合は無限ループせずにエラーを返す
+    let mut calendar = WorkCalendar::default();
+    calendar.set_holiday_weekdays(&all_weekdays());
+    let select_date = NaiveDate::from_ymd(2021, 1, 1);
+
+    assert!(matches!(calendar.try_get_next_workday(select_date, 1), Err(Error::NoWorkdayReachable{..})));
+    assert!(matches!(calendar.try_get_previous_workday(select_date, 1), Err(Error::NoWorkdayReachable{..})));
+    assert!(matches!(calendar.try_get_near_workday(select_date, true), Err(Error::NoWorkdayReachable{..})));
+    assert!(matches!(calendar.try_get_next_workdays_number(select_date, 3), Err(Error::NoWorkdayReachable{..})));
+    assert!(matches!(calendar.try_get_previous_workdays_number(select_date, 3), Err(Error::NoWorkdayReachable{..})));
+    assert!(matches!(calendar.try_get_workdays_number(select_date, -3), Err(Error::NoWorkdayReachable{..})));
+    assert!(matches!(calendar.try_adjust(select_date, RollConvention::Nearest), Err(Error::NoWorkdayReachable{..})));
+}
+
+#[test]
+fn try_workdays_out_of_range() {
+    // 日付の範囲の端ではパニックせずにエラーを返す
+    let calendar = WorkCalendar::default();
+    assert!(matches!(calendar.try_get_previous_workday(NaiveDate::MIN, 1), Err(Error::OutOfRange{..})));
+    assert!(matches!(calendar.try_get_next_workday(NaiveDate::MAX, 1), Err(Error::OutOfRange{..})));
+    assert!(matches!(calendar.try_get_previous_workdays_number(NaiveDate::MIN + Duration::days(10), 20), Err(Error::OutOfRange{..})));
+
+    // 通常の日付では従来の関数と一致する
+    let select_date = NaiveDate::from_ymd(2021, 1, 1);
+    assert_eq!(calendar.try_get_next_workday(select_date, 5).unwrap(), calendar.get_next_workday(select_date, 5));
+    assert_eq!(calendar.try_get_previous_workday(select_date, 5).unwrap(), calendar.get_previous_workday(select_date, 5));
+    assert_eq!(calendar.try_get_workdays_number(select_date, -5).unwrap(), calendar.get_workdays_number(select_date, -5));
+}
+
+#[test]
+fn try_intraday_empty_borders() {
+    // 取引時間が空の場合はエラーを返す
+    let mut calendar = WorkCalendar::default();
+    calendar.set_intraday_borders(&[]);
+    let select_datetime = NaiveDate::from_ymd(2021, 1, 4).and_hms(10, 0, 0);
+    let end_datetime = NaiveDate::from_ymd(2021, 1, 8).and_hms(10, 0, 0);
+
+    assert!(matches!(calendar.try_get_next_border(select_datetime), Err(Error::EmptyIntradayBorders)));
+    assert!(matches!(calendar.try_get_previous_border(select_datetime, false), Err(Error::EmptyIntradayBorders)));
+    assert!(matches!(calendar.try_get_near_border(select_datetime, true), Err(Error::EmptyIntradayBorders)));
+    assert!(matches!(calendar.try_add_workday_intraday_datetime(select_datetime, Duration::hours(1)), Err(Error::EmptyIntradayBorders)));
+    assert!(matches!(calendar.try_get_timedelta_workdays_intraday(select_datetime, end_datetime), Err(Error::EmptyIntradayBorders)));
+
+    // 抽出は全てfalseとなる
+    let datetime_vec = vec![select_datetime, end_datetime];
+    assert_eq!(calendar.extract_intraday_bool(&datetime_vec), vec![false, false]);
+    assert_eq!(calendar.extract_workdays_intraday_bool(&datetime_vec), vec![false, false]);
+}
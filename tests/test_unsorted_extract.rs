@@ -0,0 +1,60 @@
+use chrono::{NaiveDate, NaiveDateTime, Duration};
+use proptest::prelude::*;
+
+extern crate rs_workdays;
+
+use rs_workdays::Error;
+use rs_workdays::extract::check_sorted;
+
+mod common;
+use common::overnight_calendar;
+
+/// 2020年12月25日から約30日間の分単位の日時
+fn datetime_vec_strategy() -> impl Strategy<Value = Vec<NaiveDateTime>> {
+    let start_datetime = NaiveDate::from_ymd(2020, 12, 25).and_hms(0, 0, 0);
+    prop::collection::vec(0_i64..(30 * 24 * 60), 0..200)
+        .prop_map(move |minutes_vec|{minutes_vec.into_iter().map(|x|{start_datetime + Duration::minutes(x)}).collect()})
+}
+
+proptest! {
+    #[test]
+    fn extract_matches_elementwise(mut datetime_vec in datetime_vec_strategy()) {
+        // 昇順でない場合・昇順の場合とも要素ごとの判定と一致する
+        let calendar = overnight_calendar();
+        for _ in 0..2 {
+            let expected_workdays: Vec<bool> = datetime_vec.iter().map(|x|{calendar.check_workday(x.date())}).collect();
+            let expected_workdays_intraday: Vec<bool> = datetime_vec.iter().map(|x|{calendar.check_workday_intraday(*x)}).collect();
+            prop_assert_eq!(calendar.extract_workdays_bool(&datetime_vec), expected_workdays);
+            prop_assert_eq!(calendar.extract_workdays_intraday_bool(&datetime_vec), expected_workdays_intraday.clone());
+            let label_vec = calendar.label_workdays_intraday(&datetime_vec);
+            prop_assert_eq!(label_vec.iter().map(|x|{x.is_some()}).collect::<Vec<bool>>(), expected_workdays_intraday);
+            // 営業日でなくとも営業時間内であれば含まれる
+            for (is_intraday, is_workday_intraday) in calendar.extract_intraday_bool(&datetime_vec).into_iter().zip(calendar.extract_workdays_intraday_bool(&datetime_vec)) {
+                prop_assert!(is_intraday | !is_workday_intraday);
+            }
+            datetime_vec.sort();
+        }
+    }
+
+    #[test]
+    fn validate_series_requires_sorted(datetime_vec in datetime_vec_strategy()) {
+        let calendar = overnight_calendar();
+        let is_sorted = datetime_vec.windows(2).all(|pair|{pair[0] <= pair[1]});
+        prop_assert_eq!(check_sorted(&datetime_vec).is_ok(), is_sorted);
+        prop_assert_eq!(calendar.try_validate_series(&datetime_vec, Duration::minutes(15)).is_ok(), is_sorted);
+    }
+}
+
+#[test]
+fn unsorted_input_error() {
+    let datetime_vec = vec![
+        NaiveDate::from_ymd(2021, 1, 5).and_hms(9, 0, 0),
+        NaiveDate::from_ymd(2021, 1, 5).and_hms(9, 0, 0),
+        NaiveDate::from_ymd(2021, 1, 4).and_hms(9, 0, 0),
+    ];
+    match check_sorted(&datetime_vec) {
+        Err(Error::UnsortedInput{index, ..}) => assert_eq!(index, 2),
+        other => panic!("unexpected result: {:?}", other)
+    }
+    assert!(check_sorted(&datetime_vec[..2]).is_ok());
+}
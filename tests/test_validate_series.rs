@@ -0,0 +1,61 @@
+use chrono::{NaiveDate, NaiveDateTime, Duration};
+
+extern crate rs_workdays;
+
+use rs_workdays::{WorkCalendar, Closed};
+use rs_workdays::intraday_range::Label;
+use rs_workdays::extract::{SeriesGap, SeriesReport};
+
+mod common;
+use common::{border, weekend};
+
+#[test]
+fn validate_series_complete() {
+    // 足の生成と同じ時系列は欠損が無い
+    let calendar = WorkCalendar::default();
+    let start_datetime = NaiveDate::from_ymd(2020, 12, 28).and_hms(9, 0, 0);
+    let end_datetime = NaiveDate::from_ymd(2021, 1, 15).and_hms(15, 0, 0);
+    let datetime_vec: Vec<NaiveDateTime> = calendar.generate_intraday_range(start_datetime, end_datetime, Duration::minutes(5), Label::Left, Closed::Left).collect();
+    let report = calendar.validate_series(&datetime_vec, Duration::minutes(5));
+    assert!(report.is_complete());
+
+    // 足の途中の観測でもその足は埋まる
+    let shifted_vec: Vec<NaiveDateTime> = datetime_vec.iter().map(|x|{*x + Duration::minutes(2)}).collect();
+    assert!(calendar.validate_series(&shifted_vec, Duration::minutes(5)).is_complete());
+
+    assert_eq!(calendar.validate_series(&[], Duration::minutes(5)), SeriesReport::default());
+}
+
+#[test]
+fn validate_series_gaps_outside_and_duplicates() {
+    let calendar = WorkCalendar::new(vec![NaiveDate::from_ymd(2021, 1, 11)], weekend(), vec![border((8, 45), (15, 15)), border((16, 30), (6, 0))]);
+    let friday = NaiveDate::from_ymd(2021, 1, 8);
+    let saturday = NaiveDate::from_ymd(2021, 1, 9);
+    let tuesday = NaiveDate::from_ymd(2021, 1, 12);
+
+    let datetime_vec = vec![
+        friday.and_hms(15, 0, 0),
+        friday.and_hms(15, 30, 0),  // 営業時間外
+        friday.and_hms(17, 30, 0),
+        friday.and_hms(17, 30, 0),  // 重複
+        saturday.and_hms(5, 30, 0),
+        saturday.and_hms(12, 0, 0),  // 休日
+        tuesday.and_hms(9, 45, 0),
+    ];
+    let report = calendar.validate_series(&datetime_vec, Duration::hours(1));
+    assert_eq!(report.outside_session, vec![1, 5]);
+    assert_eq!(report.duplicates, vec![3]);
+    assert_eq!(report.gaps, vec![
+        // 夜間取引の開始の足
+        SeriesGap {start: friday.and_hms(16, 30, 0), end: friday.and_hms(17, 30, 0), missing_count: 1, business_duration: Duration::hours(1)},
+        SeriesGap {start: friday.and_hms(18, 30, 0), end: saturday.and_hms(5, 30, 0), missing_count: 11, business_duration: Duration::hours(11)},
+        // 祝日の月曜を越えて火曜の最初の足
+        SeriesGap {start: tuesday.and_hms(8, 45, 0), end: tuesday.and_hms(9, 45, 0), missing_count: 1, business_duration: Duration::hours(1)},
+    ]);
+    assert!(!report.is_complete());
+
+    // 間隔が正でない場合は欠損を検証しない
+    let report = calendar.validate_series(&datetime_vec, Duration::zero());
+    assert!(report.gaps.is_empty());
+    assert_eq!(report.outside_session, vec![1, 5]);
+}
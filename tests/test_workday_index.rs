@@ -0,0 +1,48 @@
+use std::collections::HashSet;
+use chrono::{NaiveDate, Datelike, Weekday};
+
+extern crate rs_workdays;
+
+use rs_workdays::{WorkCalendar, Closed};
+use rs_workdays::jp_holidays::get_jp_holiday_infos;
+
+/// 祝日のスライスと休日曜日から直接判定する
+fn naive_check_workday(calendar: &WorkCalendar, select_date: NaiveDate) -> bool {
+    !calendar.range_holidays().contains(&select_date) & !calendar.holiday_weekdays().contains(&select_date.weekday())
+}
+
+#[test]
+fn check_workday_index() {
+    // 祝日の範囲の内外で直接の判定と一致する
+    let mut calendar = WorkCalendar::default();
+    calendar.set_range_holiday_infos(&get_jp_holiday_infos(1990, 2030), 1990, 2030);
+    let start_date = NaiveDate::from_ymd(1985, 1, 1);
+    let end_date = NaiveDate::from_ymd(2035, 12, 31);
+    for one_day in start_date.iter_days().take_while(|x|{x<=&end_date}) {
+        assert_eq!(calendar.check_workday(one_day), naive_check_workday(&calendar, one_day), "{}", one_day);
+    }
+
+    // 休日曜日の変更が反映される
+    let holiday_weekdays: HashSet<Weekday> = [Weekday::Wed].iter().cloned().collect();
+    calendar.set_holiday_weekdays(&holiday_weekdays);
+    for one_day in start_date.iter_days().take_while(|x|{x<=&end_date}) {
+        assert_eq!(calendar.check_workday(one_day), naive_check_workday(&calendar, one_day), "{}", one_day);
+    }
+}
+
+#[test]
+fn workdays_index() {
+    // 祝日の追加・空のカレンダーでも営業日のベクターが直接の判定と一致する
+    let mut calendar = WorkCalendar::new(Vec::new(), [Weekday::Sat, Weekday::Sun].iter().cloned().collect(), Vec::new());
+    let start_date = NaiveDate::from_ymd(2020, 12, 1);
+    let end_date = NaiveDate::from_ymd(2022, 1, 31);
+    let naive_workdays = |calendar: &WorkCalendar| -> Vec<NaiveDate> {
+        start_date.iter_days().take_while(|x|{x<=&end_date}).filter(|x|{naive_check_workday(calendar, *x)}).collect()
+    };
+    assert_eq!(calendar.get_workdays(start_date, end_date, Closed::Both), naive_workdays(&calendar));
+
+    calendar.add_range_holidays(&[NaiveDate::from_ymd(2020, 12, 31), NaiveDate::from_ymd(2021, 12, 31)], 2020, 2021);
+    assert_eq!(calendar.get_workdays(start_date, end_date, Closed::Both), naive_workdays(&calendar));
+    assert!(!calendar.check_workday(NaiveDate::from_ymd(2021, 12, 31)));
+    assert!(calendar.check_workday(NaiveDate::from_ymd(2022, 1, 3)));
+}
@@ -0,0 +1,78 @@
+use chrono::{NaiveDate, Duration, Weekday};
+
+extern crate rs_workdays;
+
+use rs_workdays::{WorkCalendar, Closed};
+
+#[test]
+fn iter_workdays_forward() {
+    // 前から取り出した営業日がget_next_workdays_numberと一致する
+    let calendar = WorkCalendar::default();
+    let start_date = NaiveDate::from_ymd(2020, 12, 25);
+    let workdays: Vec<NaiveDate> = calendar.iter_workdays(start_date).take(300).collect();
+    assert_eq!(workdays, calendar.get_next_workdays_number(start_date, 300));
+
+    let skipped_workdays: Vec<NaiveDate> = calendar.iter_workdays(start_date).skip_while(|x|{x < &NaiveDate::from_ymd(2021, 5, 1)}).take(2).collect();
+    assert_eq!(skipped_workdays, vec![NaiveDate::from_ymd(2021, 5, 6), NaiveDate::from_ymd(2021, 5, 7)]);
+
+    let stepped_workdays: Vec<NaiveDate> = calendar.iter_workdays(start_date).step_by(5).take(60).collect();
+    assert_eq!(stepped_workdays, workdays.iter().cloned().step_by(5).take(60).collect::<Vec<NaiveDate>>());
+}
+
+#[test]
+fn iter_workdays_range_double_ended() {
+    // 範囲を指定した場合はget_workdaysと一致し，後ろからも取り出せる
+    let calendar = WorkCalendar::default();
+    let start_date = NaiveDate::from_ymd(2020, 12, 25);
+    for span_days in [-1_i64, 0, 3, 10, 400].iter() {
+        let end_date = start_date + Duration::days(*span_days);
+        for closed in [Closed::Left, Closed::Right, Closed::Not, Closed::Both].iter() {
+            let workdays = calendar.get_workdays(start_date, end_date, *closed);
+            let iter = calendar.iter_workdays_range(start_date, end_date, *closed);
+            assert_eq!(iter.len(), workdays.len());
+            assert_eq!(iter.collect::<Vec<NaiveDate>>(), workdays);
+
+            let mut reversed_workdays = workdays.clone();
+            reversed_workdays.reverse();
+            assert_eq!(calendar.iter_workdays_range(start_date, end_date, *closed).rev().collect::<Vec<NaiveDate>>(), reversed_workdays);
+        }
+    }
+
+    // 前後から交互に取り出しても重複しない
+    let end_date = NaiveDate::from_ymd(2021, 1, 15);
+    let mut iter = calendar.iter_workdays_range(start_date, end_date, Closed::Both);
+    let mut mixed_workdays: Vec<NaiveDate> = Vec::new();
+    loop {
+        match (iter.next(), iter.next_back()) {
+            (Some(front), Some(back)) => {mixed_workdays.push(front); mixed_workdays.push(back);},
+            (Some(front), None) => {mixed_workdays.push(front); break;},
+            _ => {break;}
+        }
+    }
+    mixed_workdays.sort();
+    assert_eq!(mixed_workdays, calendar.get_workdays(start_date, end_date, Closed::Both));
+
+    // 前の営業日をソートせずに取得
+    let previous_workdays: Vec<NaiveDate> = calendar.iter_workdays_range(NaiveDate::from_ymd(2021, 1, 1), NaiveDate::from_ymd(2021, 1, 29), Closed::Both)
+        .rev().take(19).collect();
+    assert_eq!(previous_workdays, calendar.get_previous_workdays_number(NaiveDate::from_ymd(2021, 1, 29), 19));
+}
+
+#[test]
+fn iter_workdays_without_workdays() {
+    // 全ての曜日が休日曜日の場合は日付の限界まで探索せずに空となる
+    let mut calendar = WorkCalendar::default();
+    calendar.set_holiday_weekdays(&[Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun].iter().cloned().collect());
+    let start_date = NaiveDate::from_ymd(2021, 1, 1);
+    let mut workday_iter = calendar.iter_workdays(start_date);
+    assert_eq!(workday_iter.len(), 0);
+    assert_eq!(workday_iter.next(), None);
+    assert_eq!(workday_iter.next_back(), None);
+    assert_eq!(calendar.iter_workdays_range(start_date, start_date + Duration::days(30), Closed::Both).count(), 0);
+
+    // カレンダーを変更しても作成済みのイテレーターは変わらない
+    let mut calendar = WorkCalendar::default();
+    let workday_iter = calendar.iter_workdays_range(start_date, start_date + Duration::days(6), Closed::Both);
+    calendar.set_holiday_weekdays(&[Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri].iter().cloned().collect());
+    assert_eq!(workday_iter.collect::<Vec<NaiveDate>>(), vec![NaiveDate::from_ymd(2021, 1, 4), NaiveDate::from_ymd(2021, 1, 5), NaiveDate::from_ymd(2021, 1, 6), NaiveDate::from_ymd(2021, 1, 7)]);
+}